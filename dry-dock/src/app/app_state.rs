@@ -7,7 +7,7 @@ use crate::app::ActiveScreen;
 use crate::ui::modals::*;
 use crate::ui::screens::ScreenFactory;
 use crate::services::rss_service::refresh_all_feeds;
-use crate::services::log_service;
+use crate::services::{bookmark_service, link_check_service, log_service, note_mirror_service, snapshot_service};
 
 pub struct AppState {
     pub config: Config,
@@ -23,10 +23,16 @@ impl AppState {
     pub fn new(config: Config) -> Self {        
         // Prep
         let screen_factory = Arc::new(Mutex::new(ScreenFactory::new()));
+        if let Err(e) = bookmark_service::normalize_saved_locations() {
+            log_service::add_log_entry("ERROR", &format!("Failed to normalize bookmark addresses: {}", e));
+        }
         
         // Start background services with the context and screen factory reference so I can handle UI updates
         // whenever I so please. Models I dont care about and maybe the access to services.
         BackgroundServiceManager::start_rss_reloader(screen_factory.clone());
+        BackgroundServiceManager::start_note_mirror(screen_factory.clone());
+        BackgroundServiceManager::start_link_checker(screen_factory.clone());
+        BackgroundServiceManager::start_snapshot_refresher();
 
         BackgroundServiceManager::start_llama_server();
 
@@ -50,6 +56,11 @@ impl AppState {
     pub fn close_modal(&mut self) {
         self.modal_factory.close_modal();
     }
+
+    /// Save drafts of any open note modal (called on exit)
+    pub fn save_drafts(&mut self) {
+        self.modal_factory.save_drafts();
+    }
     
     /// Set the active screen
     pub fn set_active_screen(&mut self, screen: ActiveScreen) {
@@ -84,7 +95,7 @@ impl BackgroundServiceManager {
                 let runtime = tokio::runtime::Runtime::new().unwrap();
                 
                 // Refresh feeds using async
-                match runtime.block_on(refresh_all_feeds()) {
+                match runtime.block_on(refresh_all_feeds(false)) {
                     Ok(items_added) => {
                         log_service::add_log_entry("INFO", &format!("RSS Feeds refreshed, {} new items added.", items_added));
                         
@@ -102,6 +113,122 @@ impl BackgroundServiceManager {
         });
     }
 
+    pub fn start_note_mirror(screen_factory: Arc<Mutex<ScreenFactory>>) {
+        use notify::{RecursiveMode, Watcher};
+
+        std::thread::spawn(move || {
+            log_service::add_log_entry("INFO", "Notes mirror background service started.");
+
+            let (tx, rx) = std::sync::mpsc::channel();
+            let mut watcher: Option<notify::RecommendedWatcher> = None;
+            let mut watched_root: Option<std::path::PathBuf> = None;
+            let mut needs_sync = true;
+
+            loop {
+                // Settings can change at any time, so re-check which folder (if any) to watch
+                let root = note_mirror_service::mirror_root();
+                if root != watched_root {
+                    watcher = None;
+                    if let Some(path) = &root {
+                        let _ = std::fs::create_dir_all(path);
+                        let tx = tx.clone();
+                        match notify::recommended_watcher(tx) {
+                            Ok(mut new_watcher) => match new_watcher.watch(path, RecursiveMode::Recursive) {
+                                Ok(_) => {
+                                    log_service::add_log_entry("INFO", &format!("Watching notes mirror folder: {}", path.display()));
+                                    watcher = Some(new_watcher);
+                                }
+                                Err(e) => log_service::add_log_entry("ERROR", &format!("Failed to watch notes mirror folder: {}", e)),
+                            },
+                            Err(e) => log_service::add_log_entry("ERROR", &format!("Failed to create notes mirror watcher: {}", e)),
+                        }
+                    }
+                    watched_root = root;
+                    needs_sync = true;
+                }
+
+                if watcher.is_some() && needs_sync {
+                    needs_sync = false;
+                    match note_mirror_service::sync_all() {
+                        Ok(report) => {
+                            if report.has_changes()
+                                && let Ok(mut factory) = screen_factory.lock()
+                            {
+                                factory.clear_screen(ActiveScreen::Notes);
+                            }
+                        }
+                        Err(e) => log_service::add_log_entry("ERROR", &format!("Notes mirror sync failed: {}", e)),
+                    }
+                    // Our own writes show up as events too, drop them so we don't sync in a loop
+                    std::thread::sleep(std::time::Duration::from_millis(500));
+                    while rx.try_recv().is_ok() {}
+                }
+
+                match rx.recv_timeout(std::time::Duration::from_secs(5)) {
+                    Ok(Ok(event)) => {
+                        let is_markdown = event.paths.iter()
+                            .any(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("md")));
+                        if is_markdown && !event.kind.is_access() {
+                            // Debounce: editors often write a file several times in a row
+                            std::thread::sleep(std::time::Duration::from_millis(500));
+                            while rx.try_recv().is_ok() {}
+                            needs_sync = true;
+                        }
+                    }
+                    Ok(Err(e)) => log_service::add_log_entry("ERROR", &format!("Notes mirror watch error: {}", e)),
+                    Err(_) => {}
+                }
+            }
+        });
+    }
+
+    pub fn start_link_checker(screen_factory: Arc<Mutex<ScreenFactory>>) {
+        std::thread::spawn(move || {
+            log_service::add_log_entry("INFO", "Bookmark link checker background service started. Will check once a day.");
+
+            // Give the app a moment to start before hitting the network
+            std::thread::sleep(std::time::Duration::from_secs(120));
+
+            loop {
+                if link_check_service::is_check_due() {
+                    let runtime = tokio::runtime::Runtime::new().unwrap();
+                    match runtime.block_on(link_check_service::check_all_bookmarks()) {
+                        Ok(_) => {
+                            if let Ok(mut factory) = screen_factory.lock() {
+                                factory.clear_screen(ActiveScreen::Bookmarks);
+                            }
+                        }
+                        Err(e) => log_service::add_log_entry("ERROR", &format!("Bookmark link check failed: {}", e)),
+                    }
+                }
+
+                // Wake up hourly, the last run is kept in settings so restarts don't re-check early
+                std::thread::sleep(std::time::Duration::from_secs(3600));
+            }
+        });
+    }
+
+    pub fn start_snapshot_refresher() {
+        std::thread::spawn(move || {
+            log_service::add_log_entry("INFO", "Bookmark snapshot refresher background service started.");
+
+            // Let the link checker go first
+            std::thread::sleep(std::time::Duration::from_secs(300));
+
+            loop {
+                let runtime = tokio::runtime::Runtime::new().unwrap();
+                match runtime.block_on(snapshot_service::refresh_due_snapshots()) {
+                    Ok(0) => {}
+                    Ok(taken) => log_service::add_log_entry("INFO", &format!("Re-archived {} bookmarked page(s).", taken)),
+                    Err(e) => log_service::add_log_entry("ERROR", &format!("Bookmark snapshot refresh failed: {}", e)),
+                }
+
+                // Snapshot ages are stored, checking every few hours is plenty
+                std::thread::sleep(std::time::Duration::from_secs(6 * 3600));
+            }
+        });
+    }
+
     pub fn start_daily_backup() -> () {
         // Placeholder for future daily backup service
         // Allow a user to configure a backup locatio to ship this to???
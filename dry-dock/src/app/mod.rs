@@ -3,6 +3,7 @@ pub mod active_screen;
 pub mod app_state;
 pub mod modal_renderer;
 pub mod screen_renderer;
+pub mod shortcut_handler;
 
 pub use active_screen::ActiveScreen;
 pub use app_state::AppState;
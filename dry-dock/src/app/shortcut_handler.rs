@@ -0,0 +1,21 @@
+// src/app/shortcut_handler.rs
+use eframe::egui;
+use crate::app::AppState;
+use crate::ui::modals::ActiveModal;
+
+/// Ctrl+K (Cmd+K on macOS) opens the bookmark quick launcher from anywhere
+const QUICK_LAUNCHER_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::K);
+
+impl AppState {
+    /// App wide keyboard shortcuts, handled before screens and modals see the input
+    pub fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        // Don't replace a modal that is in use (and might hold a draft)
+        if self.modal_factory.is_open() {
+            return;
+        }
+
+        if ctx.input_mut(|i| i.consume_shortcut(&QUICK_LAUNCHER_SHORTCUT)) {
+            self.open_modal(ActiveModal::QuickLauncher);
+        }
+    }
+}
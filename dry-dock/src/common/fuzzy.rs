@@ -0,0 +1,45 @@
+// src/common/fuzzy.rs
+
+// Fuzzy matching for type-to-find lists: "gh dd" finds "GitHub - dry-dock". Every query
+// character has to appear in order, runs of matching characters and matches at the start
+// of words score higher, gaps cost a little.
+
+/// Score of `query` against `text`, higher is better. None when the text doesn't contain
+/// every query character in order. Whitespace in the query is ignored, case never matters.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let query: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0;
+    let mut query_index = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for (index, c) in text.iter().enumerate() {
+        if query_index == query.len() {
+            break;
+        }
+        if *c != query[query_index] {
+            continue;
+        }
+
+        score += 1;
+        let word_start = index == 0 || !text[index - 1].is_alphanumeric();
+        if word_start {
+            score += 5;
+        }
+        match previous_match {
+            Some(previous) if previous + 1 == index => score += 8,
+            Some(previous) => score -= (2 * (index - previous - 1) as i64).min(10),
+            None if index == 0 => score += 10,
+            None => {}
+        }
+
+        previous_match = Some(index);
+        query_index += 1;
+    }
+
+    (query_index == query.len()).then_some(score)
+}
@@ -19,6 +19,28 @@ pub fn get_database_path(app_name: &str) -> String {
     path.to_string_lossy().to_string()
 }
 
+// Helper to get a folder next to the database, created if missing
+pub fn get_data_path(app_name: &str, folder: &str) -> std::path::PathBuf {
+    let mut path = dirs::data_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."));
+
+    path.push(app_name);
+    path.push(folder);
+    std::fs::create_dir_all(&path).ok();
+    path
+}
+
+// Helper to get a platform-specific cache folder, created if missing
+pub fn get_cache_path(app_name: &str, folder: &str) -> std::path::PathBuf {
+    let mut path = dirs::cache_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."));
+
+    path.push(app_name);
+    path.push(folder);
+    std::fs::create_dir_all(&path).ok();
+    path
+}
+
 pub fn load_config_file() -> Result<String, std::io::Error> {
     // Try 1: Current directory (for development)
     if let Ok(data) = std::fs::read_to_string("AppConfig.json") {
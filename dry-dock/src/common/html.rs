@@ -0,0 +1,102 @@
+// src/common/html.rs
+
+// Just enough HTML reading for the loose HTML we get from outside: browser bookmark
+// exports and the <head> of web pages. Not a full parser.
+
+use std::collections::HashMap;
+
+/// Index of the '>' closing the tag at the start of `html`, ignoring any inside quoted attributes
+pub fn find_tag_end(html: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (index, c) in html.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split `A HREF="..." ADD_DATE="..."` (or `/A`) into the upper case tag name, whether it
+/// is a closing tag, and its attributes keyed by upper case name
+pub fn parse_tag(tag: &str) -> (String, bool, HashMap<String, String>) {
+    let tag = tag.trim().trim_end_matches('/');
+    let (closing, tag) = match tag.strip_prefix('/') {
+        Some(tag) => (true, tag),
+        None => (false, tag),
+    };
+    let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+    let name = tag[..name_end].to_ascii_uppercase();
+
+    let mut attributes = HashMap::new();
+    let mut rest = tag[name_end..].trim_start();
+    while !rest.is_empty() {
+        let key_end = rest.find(|c: char| c == '=' || c.is_whitespace()).unwrap_or(rest.len());
+        let key = rest[..key_end].to_ascii_uppercase();
+        rest = rest[key_end..].trim_start();
+
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let close = after[1..].find(quote).map_or(after.len(), |index| index + 1);
+                    value = after[1..close].to_string();
+                    rest = after.get(close + 1..).unwrap_or("");
+                }
+                _ => {
+                    let value_end = after.find(char::is_whitespace).unwrap_or(after.len());
+                    value = after[..value_end].to_string();
+                    rest = &after[value_end..];
+                }
+            }
+        }
+        if !key.is_empty() {
+            attributes.insert(key, value);
+        }
+        rest = rest.trim_start();
+    }
+
+    (name, closing, attributes)
+}
+
+/// Decode named (&amp; ...) and numeric (&#39; &#x27;) character references
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').filter(|end| *end <= 10).map(|end| &rest[1..end]);
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|decimal| decimal.parse().ok()))
+                .and_then(char::from_u32),
+        });
+
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
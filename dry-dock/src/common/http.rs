@@ -0,0 +1,35 @@
+// src/common/http.rs
+
+// Downloads of pages and files we don't control: a bookmark can point at a multi-gigabyte file
+// just as easily as at a web page, so bodies are read chunk by chunk under a size limit.
+
+/// Read a response body while holding at most `limit` bytes of it. A body whose Content-Length
+/// is over the limit is refused before anything is read, otherwise reading stops at the limit and
+/// the rest is never downloaded. Returns the bytes and whether the body was cut short.
+pub async fn read_body_limited(mut response: reqwest::Response, limit: usize) -> Result<(Vec<u8>, bool), String> {
+    if response.content_length().is_some_and(|length| length > limit as u64) {
+        return Err(format!("Response is larger than {} KB", limit / 1024));
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| format!("Failed to read response: {}", e))? {
+        let room = limit - body.len();
+        if chunk.len() > room {
+            body.extend_from_slice(&chunk[..room]);
+            return Ok((body, true));
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok((body, false))
+}
+
+/// Media type of a response without its parameters ("text/html; charset=utf-8" -> "text/html")
+pub fn content_type(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.split(';').next().unwrap_or_default().trim().to_ascii_lowercase())
+        .filter(|mime| !mime.is_empty())
+}
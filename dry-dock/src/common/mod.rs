@@ -1 +1,4 @@
-pub mod helper;
\ No newline at end of file
+pub mod fuzzy;
+pub mod helper;
+pub mod html;
+pub mod http;
\ No newline at end of file
@@ -3,6 +3,7 @@ use once_cell::sync::OnceCell;
 use r2d2::Pool;
 use r2d2_sqlite::SqliteConnectionManager;
 use rusqlite::Connection;
+use rusqlite::functions::FunctionFlags;
 
 // Global connection pool
 static DB_POOL: OnceCell<Pool<SqliteConnectionManager>> = OnceCell::new();
@@ -30,6 +31,13 @@ fn create_connection_pool(db_path: &str) -> Result<(), String> {
             conn.execute_batch("PRAGMA foreign_keys = ON;")?;
             // Enable WAL mode for better concurrency
             conn.execute_batch("PRAGMA journal_mode = WAL;")?;
+            // SQLite's lower() and LIKE only fold ASCII, searches use this so "ärger" finds "Ärger"
+            conn.create_scalar_function(
+                "unicode_lower",
+                1,
+                FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+                |ctx| Ok(ctx.get::<Option<String>>(0)?.map(|text| text.to_lowercase())),
+            )?;
             Ok(())
         });
     
@@ -108,6 +116,9 @@ fn run_migrations() -> Result<(), String> {
         );
 
         CREATE INDEX IF NOT EXISTS idx_notes_title ON notes(title);
+        CREATE INDEX IF NOT EXISTS idx_notes_title_nocase ON notes(title COLLATE NOCASE);
+        CREATE INDEX IF NOT EXISTS idx_notes_created_at ON notes(created_at DESC);
+        CREATE INDEX IF NOT EXISTS idx_notes_updated_at ON notes(COALESCE(updated_at, created_at) DESC);
 
         CREATE TABLE IF NOT EXISTS bookmarks (
             id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -133,5 +144,179 @@ fn run_migrations() -> Result<(), String> {
 
     // As we add more you run one after another here.
 
+    // Pinned and archived notes
+    add_column_if_missing(&conn, "notes", "is_pinned", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column_if_missing(&conn, "notes", "is_archived", "INTEGER NOT NULL DEFAULT 0")?;
+    conn.execute_batch(
+        "
+        CREATE INDEX IF NOT EXISTS idx_notes_pinned ON notes(is_pinned);
+        CREATE INDEX IF NOT EXISTS idx_notes_archived ON notes(is_archived);
+        "
+    )
+    .map_err(|e| format!("Failed to run migrations: {}", e))?;
+
+    // Notebooks, app settings and the markdown folder mirror
+    add_column_if_missing(&conn, "notes", "notebook", "TEXT NOT NULL DEFAULT 'Notes'")?;
+    conn.execute_batch(
+        "
+        CREATE INDEX IF NOT EXISTS idx_notes_notebook ON notes(notebook);
+
+        CREATE TABLE IF NOT EXISTS settings (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS note_sync_state (
+            note_id INTEGER PRIMARY KEY,
+            file_path TEXT NOT NULL,
+            content_hash TEXT NOT NULL,
+            file_mtime INTEGER NOT NULL,
+            note_updated_at INTEGER NOT NULL,
+            FOREIGN KEY (note_id) REFERENCES notes(id) ON DELETE CASCADE
+        );
+
+        CREATE TABLE IF NOT EXISTS note_sync_conflicts (
+            note_id INTEGER PRIMARY KEY,
+            file_path TEXT NOT NULL,
+            file_content TEXT NOT NULL,
+            detected_at INTEGER NOT NULL,
+            FOREIGN KEY (note_id) REFERENCES notes(id) ON DELETE CASCADE
+        );
+        "
+    )
+    .map_err(|e| format!("Failed to run migrations: {}", e))?;
+
+    // Locked (encrypted) notes, details holds the ciphertext while locked
+    add_column_if_missing(&conn, "notes", "is_locked", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column_if_missing(&conn, "notes", "lock_salt", "TEXT")?;
+    add_column_if_missing(&conn, "notes", "lock_nonce", "TEXT")?;
+
+    // Autosaved drafts from the create/update note modals (note_id is NULL for new notes)
+    conn.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS drafts (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            note_id INTEGER,
+            title TEXT NOT NULL,
+            details TEXT NOT NULL,
+            notebook TEXT NOT NULL,
+            updated_at INTEGER NOT NULL,
+            FOREIGN KEY (note_id) REFERENCES notes(id) ON DELETE CASCADE
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_drafts_note_id ON drafts(note_id);
+        "
+    )
+    .map_err(|e| format!("Failed to run migrations: {}", e))?;
+
+    // Note tags, stored lowercase and comma separated (",public,rust,") so a LIKE can match one tag
+    add_column_if_missing(&conn, "notes", "tags", "TEXT NOT NULL DEFAULT ''")?;
+    add_column_if_missing(&conn, "drafts", "tags", "TEXT NOT NULL DEFAULT ''")?;
+
+    // Bookmark folders (a tree through parent_id) and bookmark tags, stored like note tags
+    conn.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS bookmark_folders (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            parent_id INTEGER,
+            created_at INTEGER NOT NULL,
+            FOREIGN KEY (parent_id) REFERENCES bookmark_folders(id) ON DELETE CASCADE
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_bookmark_folders_parent_id ON bookmark_folders(parent_id);
+        "
+    )
+    .map_err(|e| format!("Failed to run migrations: {}", e))?;
+    add_column_if_missing(&conn, "bookmarks", "folder_id", "INTEGER REFERENCES bookmark_folders(id) ON DELETE SET NULL")?;
+    add_column_if_missing(&conn, "bookmarks", "tags", "TEXT NOT NULL DEFAULT ''")?;
+    conn.execute_batch("CREATE INDEX IF NOT EXISTS idx_bookmarks_folder_id ON bookmarks(folder_id);")
+        .map_err(|e| format!("Failed to run migrations: {}", e))?;
+
+    // Dead-link checker results, checked_at stays NULL until a bookmark has been checked
+    add_column_if_missing(&conn, "bookmarks", "check_status", "INTEGER")?;
+    add_column_if_missing(&conn, "bookmarks", "check_error", "TEXT")?;
+    add_column_if_missing(&conn, "bookmarks", "redirect_url", "TEXT")?;
+    add_column_if_missing(&conn, "bookmarks", "checked_at", "INTEGER")?;
+
+    // Optional bookmark description, filled from the page's meta description when added
+    add_column_if_missing(&conn, "bookmarks", "description", "TEXT NOT NULL DEFAULT ''")?;
+
+    // Bookmark usage, the count and last open are kept on the bookmark for cheap sorting
+    add_column_if_missing(&conn, "bookmarks", "open_count", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column_if_missing(&conn, "bookmarks", "last_opened_at", "INTEGER")?;
+    conn.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS bookmark_opens (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            bookmark_id INTEGER NOT NULL,
+            opened_at INTEGER NOT NULL,
+            FOREIGN KEY (bookmark_id) REFERENCES bookmarks(id) ON DELETE CASCADE
+        );
+
+        DROP INDEX IF EXISTS idx_bookmark_opens_bookmark_id;
+        CREATE INDEX IF NOT EXISTS idx_bookmark_opens_recent ON bookmark_opens(bookmark_id, opened_at DESC);
+        "
+    )
+    .map_err(|e| format!("Failed to run migrations: {}", e))?;
+
+    // Offline copies of bookmarked pages, the HTML files live in the data dir
+    conn.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS bookmark_snapshots (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            bookmark_id INTEGER NOT NULL,
+            file_path TEXT NOT NULL,
+            size_bytes INTEGER NOT NULL,
+            created_at INTEGER NOT NULL,
+            FOREIGN KEY (bookmark_id) REFERENCES bookmarks(id) ON DELETE CASCADE
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_bookmark_snapshots_bookmark_id ON bookmark_snapshots(bookmark_id);
+        "
+    )
+    .map_err(|e| format!("Failed to run migrations: {}", e))?;
+
+    // Feed item state, new items arrive unread
+    if add_column_if_missing(&conn, "feed_items", "is_read", "INTEGER NOT NULL DEFAULT 0")? {
+        // Items fetched before read state existed were already seen, don't flood the unread counts
+        conn.execute_batch("UPDATE feed_items SET is_read = 1;")
+            .map_err(|e| format!("Failed to run migrations: {}", e))?;
+    }
+    add_column_if_missing(&conn, "feed_items", "is_starred", "INTEGER NOT NULL DEFAULT 0")?;
+    conn.execute_batch("CREATE INDEX IF NOT EXISTS idx_feed_items_unread ON feed_items(feed_id, is_read);")
+        .map_err(|e| format!("Failed to run migrations: {}", e))?;
+
+    // Feed category from OPML outlines, nested ones joined with " / ", empty when uncategorized
+    add_column_if_missing(&conn, "feeds", "category", "TEXT NOT NULL DEFAULT ''")?;
+
+    // HTTP caching of feeds: validators for conditional GETs and when the server lets us ask again
+    add_column_if_missing(&conn, "feeds", "etag", "TEXT")?;
+    add_column_if_missing(&conn, "feeds", "last_modified", "TEXT")?;
+    add_column_if_missing(&conn, "feeds", "next_fetch_at", "INTEGER")?;
+    // Retry-After is kept apart from max-age, a manual refresh skips the one but not the other
+    add_column_if_missing(&conn, "feeds", "retry_at", "INTEGER")?;
+
     Ok(())
+}
+
+/// SQLite has no "ADD COLUMN IF NOT EXISTS", so check table_info before altering.
+/// Returns true when the column was just added, so existing rows can be backfilled.
+fn add_column_if_missing(conn: &Connection, table: &str, column: &str, definition: &str) -> Result<bool, String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA table_info({})", table))
+        .map_err(|e| format!("Failed to read columns of {}: {}", table, e))?;
+
+    let exists = stmt
+        .query_map([], |row| row.get::<_, String>("name"))
+        .map_err(|e| format!("Failed to read columns of {}: {}", table, e))?
+        .filter_map(Result::ok)
+        .any(|name| name == column);
+
+    if !exists {
+        conn.execute_batch(&format!("ALTER TABLE {} ADD COLUMN {} {};", table, column, definition))
+            .map_err(|e| format!("Failed to add column {}.{}: {}", table, column, e))?;
+    }
+
+    Ok(!exists)
 }
\ No newline at end of file
@@ -0,0 +1,95 @@
+// src/dal/repositories/bookmark_folders_repository.rs
+use crate::dal::db_context::get_connection;
+use rusqlite::params;
+
+/// Raw folder row: (id, name, parent_id)
+pub type BookmarkFolderRow = (i32, String, Option<i32>);
+
+/// Bookmark Folders Repository - the folder tree bookmarks are filed into
+pub struct BookmarkFoldersRepository;
+
+impl BookmarkFoldersRepository {
+    /// Create a folder (parent None = top level) and return its ID
+    pub fn create(name: &str, parent_id: Option<i32>) -> Result<i32, String> {
+        let conn = get_connection()?;
+        let now = chrono::Utc::now().timestamp();
+
+        conn.execute(
+            "INSERT INTO bookmark_folders (name, parent_id, created_at) VALUES (?1, ?2, ?3)",
+            params![name, parent_id, now],
+        )
+        .map_err(|e| format!("Failed to create folder: {}", e))?;
+
+        Ok(conn.last_insert_rowid() as i32)
+    }
+
+    /// Get every folder, sorted by name
+    pub fn get_all() -> Result<Vec<BookmarkFolderRow>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare("SELECT id, name, parent_id FROM bookmark_folders ORDER BY name COLLATE NOCASE ASC")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let folders = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i32>("id")?,
+                    row.get::<_, String>("name")?,
+                    row.get::<_, Option<i32>>("parent_id")?,
+                ))
+            })
+            .map_err(|e| format!("Failed to query folders: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect folders: {}", e))?;
+
+        Ok(folders)
+    }
+
+    /// Rename a folder
+    pub fn rename(folder_id: i32, name: &str) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "UPDATE bookmark_folders SET name = ?1 WHERE id = ?2",
+            params![name, folder_id],
+        )
+        .map_err(|e| format!("Failed to rename folder: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Move a folder under another folder (None = top level)
+    pub fn set_parent(folder_id: i32, parent_id: Option<i32>) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "UPDATE bookmark_folders SET parent_id = ?1 WHERE id = ?2",
+            params![parent_id, folder_id],
+        )
+        .map_err(|e| format!("Failed to move folder: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Delete a folder, its bookmarks and subfolders move up to its parent
+    pub fn delete(folder_id: i32) -> Result<(), String> {
+        let mut conn = get_connection()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        let parent_id: Option<i32> = tx
+            .query_row("SELECT parent_id FROM bookmark_folders WHERE id = ?1", params![folder_id], |row| row.get(0))
+            .map_err(|e| format!("Failed to get folder: {}", e))?;
+
+        tx.execute("UPDATE bookmarks SET folder_id = ?1 WHERE folder_id = ?2", params![parent_id, folder_id])
+            .map_err(|e| format!("Failed to move bookmarks: {}", e))?;
+        tx.execute("UPDATE bookmark_folders SET parent_id = ?1 WHERE parent_id = ?2", params![parent_id, folder_id])
+            .map_err(|e| format!("Failed to move folders: {}", e))?;
+        tx.execute("DELETE FROM bookmark_folders WHERE id = ?1", params![folder_id])
+            .map_err(|e| format!("Failed to delete folder: {}", e))?;
+
+        tx.commit().map_err(|e| format!("Failed to delete folder: {}", e))
+    }
+}
@@ -0,0 +1,93 @@
+// src/dal/repositories/bookmark_snapshots_repository.rs
+use crate::dal::db_context::get_connection;
+use rusqlite::params;
+
+/// Raw snapshot row: (id, bookmark_id, file_path, size_bytes, created_at)
+pub type SnapshotRow = (i32, i32, String, i64, i64);
+
+/// Bookmark Snapshots Repository - offline copies of bookmarked pages
+pub struct BookmarkSnapshotsRepository;
+
+impl BookmarkSnapshotsRepository {
+    /// Record a snapshot file and return its ID
+    pub fn create(bookmark_id: i32, file_path: &str, size_bytes: i64, created_at: i64) -> Result<i32, String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "INSERT INTO bookmark_snapshots (bookmark_id, file_path, size_bytes, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![bookmark_id, file_path, size_bytes, created_at],
+        )
+        .map_err(|e| format!("Failed to create snapshot: {}", e))?;
+
+        Ok(conn.last_insert_rowid() as i32)
+    }
+
+    /// Snapshots of a bookmark, newest first
+    pub fn get_for_bookmark(bookmark_id: i32) -> Result<Vec<SnapshotRow>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, bookmark_id, file_path, size_bytes, created_at FROM bookmark_snapshots
+                 WHERE bookmark_id = ?1 ORDER BY created_at DESC, id DESC",
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let snapshots = stmt
+            .query_map(params![bookmark_id], Self::map_row)
+            .map_err(|e| format!("Failed to query snapshots: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect snapshots: {}", e))?;
+
+        Ok(snapshots)
+    }
+
+    /// Get a snapshot by ID
+    pub fn get_by_id(snapshot_id: i32) -> Result<SnapshotRow, String> {
+        let conn = get_connection()?;
+
+        conn.query_row(
+            "SELECT id, bookmark_id, file_path, size_bytes, created_at FROM bookmark_snapshots WHERE id = ?1",
+            params![snapshot_id],
+            Self::map_row,
+        )
+        .map_err(|e| format!("Failed to get snapshot: {}", e))
+    }
+
+    /// Newest snapshot time of every bookmark that has one: (bookmark_id, created_at)
+    pub fn get_latest_per_bookmark() -> Result<Vec<(i32, i64)>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare("SELECT bookmark_id, MAX(created_at) FROM bookmark_snapshots GROUP BY bookmark_id")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let latest = stmt
+            .query_map([], |row| Ok((row.get::<_, i32>(0)?, row.get::<_, i64>(1)?)))
+            .map_err(|e| format!("Failed to query snapshots: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect snapshots: {}", e))?;
+
+        Ok(latest)
+    }
+
+    /// Delete a snapshot record
+    pub fn delete(snapshot_id: i32) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute("DELETE FROM bookmark_snapshots WHERE id = ?1", params![snapshot_id])
+            .map_err(|e| format!("Failed to delete snapshot: {}", e))?;
+
+        Ok(())
+    }
+
+    fn map_row(row: &rusqlite::Row) -> rusqlite::Result<SnapshotRow> {
+        Ok((
+            row.get::<_, i32>("id")?,
+            row.get::<_, i32>("bookmark_id")?,
+            row.get::<_, String>("file_path")?,
+            row.get::<_, i64>("size_bytes")?,
+            row.get::<_, i64>("created_at")?,
+        ))
+    }
+}
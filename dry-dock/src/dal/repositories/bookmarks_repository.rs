@@ -2,57 +2,74 @@
 use crate::dal::db_context::get_connection;
 use rusqlite::params;
 
+/// Raw bookmark row: (id, name, location, created_at, folder_id, tags,
+/// check_status, check_error, redirect_url, checked_at, description, open_count, last_opened_at)
+pub type BookmarkRow = (i32, String, String, i64, Option<i32>, String, Option<i32>, Option<String>, Option<String>, Option<i64>, String, i64, Option<i64>);
+
+/// New bookmark for create_many: (name, location, description, created_at, folder_id, tags)
+pub type NewBookmarkRow = (String, String, String, i64, Option<i32>, String);
+
+/// Link check result to store: (bookmark_id, check_status, check_error, redirect_url, checked_at)
+pub type LinkCheckRow = (i32, Option<i32>, Option<String>, Option<String>, i64);
+
+/// Columns selected for a BookmarkRow, in tuple order
+const BOOKMARK_COLUMNS: &str = "id, name, location, created_at, folder_id, tags, check_status, check_error, redirect_url, checked_at, description, open_count, last_opened_at";
+
 /// Bookmarks Repository - handles all bookmark-related database operations
 pub struct BookmarksRepository;
 
 impl BookmarksRepository {
     /// Create a new bookmark
-    pub fn create(name: &str, location: &str) -> Result<(), String> {
+    pub fn create(name: &str, location: &str, description: &str, folder_id: Option<i32>, tags: &str) -> Result<(), String> {
         let conn = get_connection()?;
         let now = chrono::Utc::now().timestamp();
 
         conn.execute(
-            "INSERT INTO bookmarks (name, location, created_at) VALUES (?1, ?2, ?3)",
-            params![name, location, now],
+            "INSERT INTO bookmarks (name, location, description, created_at, folder_id, tags) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![name, location, description, now, folder_id, tags],
         )
         .map_err(|e| format!("Failed to create bookmark: {}", e))?;
 
         Ok(())
     }
 
+    /// Insert many bookmarks in one transaction, skipping locations that already exist.
+    /// Returns how many were inserted.
+    pub fn create_many(bookmarks: &[NewBookmarkRow]) -> Result<usize, String> {
+        let mut conn = get_connection()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        let mut inserted = 0;
+        for (name, location, description, created_at, folder_id, tags) in bookmarks {
+            inserted += tx
+                .execute(
+                    "INSERT OR IGNORE INTO bookmarks (name, location, description, created_at, folder_id, tags) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![name, location, description, created_at, folder_id, tags],
+                )
+                .map_err(|e| format!("Failed to create bookmark: {}", e))?;
+        }
+
+        tx.commit().map_err(|e| format!("Failed to create bookmarks: {}", e))?;
+        Ok(inserted)
+    }
+
     /// Get all bookmarks
-    pub fn get_all() -> Result<Vec<(i32, String, String, String)>, String> {
+    pub fn get_all() -> Result<Vec<BookmarkRow>, String> {
         let conn = get_connection()?;
 
         let mut stmt = conn
-            .prepare("SELECT id, name, location, created_at FROM bookmarks ORDER BY name ASC")
+            .prepare(&format!("SELECT {} FROM bookmarks ORDER BY name ASC", BOOKMARK_COLUMNS))
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let bookmarks = stmt
-            .query_map([], |row| {
-                Ok((
-                    row.get::<_, i32>("id")?,
-                    row.get::<_, String>("name")?,
-                    row.get::<_, String>("location")?,
-                    row.get::<_, i64>("created_at")?,
-                ))
-            })
+            .query_map([], Self::map_row)
             .map_err(|e| format!("Failed to query bookmarks: {}", e))?
-            .collect::<Result<Vec<(i32, String, String, i64)>, _>>()
+            .collect::<Result<Vec<_>, _>>()
             .map_err(|e| format!("Failed to collect bookmarks: {}", e))?;
 
-        // Convert timestamps to formatted strings
-        let formatted_bookmarks = bookmarks
-            .into_iter()
-            .map(|(id, name, location, timestamp)| {
-                let created_at_str = chrono::DateTime::from_timestamp(timestamp, 0)
-                    .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-                    .unwrap_or_else(|| "Unknown".to_string());
-                (id, name, location, created_at_str)
-            })
-            .collect();
-
-        Ok(formatted_bookmarks)
+        Ok(bookmarks)
     }
 
     /// Delete a bookmark
@@ -69,37 +86,197 @@ impl BookmarksRepository {
     }
 
     /// Get a bookmark by ID
-    pub fn get_by_id(bookmark_id: i32) -> Result<(i32, String, String, i64), String> {
+    pub fn get_by_id(bookmark_id: i32) -> Result<BookmarkRow, String> {
         let conn = get_connection()?;
 
         let mut stmt = conn
-            .prepare("SELECT id, name, location, created_at FROM bookmarks WHERE id = ?1")
+            .prepare(&format!("SELECT {} FROM bookmarks WHERE id = ?1", BOOKMARK_COLUMNS))
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let bookmark = stmt
-            .query_row(params![bookmark_id], |row| {
-                Ok((
-                    row.get::<_, i32>("id")?,
-                    row.get::<_, String>("name")?,
-                    row.get::<_, String>("location")?,
-                    row.get::<_, i64>("created_at")?,
-                ))
-            })
+            .query_row(params![bookmark_id], Self::map_row)
             .map_err(|e| format!("Failed to get bookmark: {}", e))?;
 
         Ok(bookmark)
     }
 
-    /// Update a bookmark
-    pub fn update(bookmark_id: i32, name: &str, location: &str) -> Result<(), String> {
+    /// Update a bookmark, a new location needs to be checked again
+    pub fn update(bookmark_id: i32, name: &str, location: &str, description: &str, folder_id: Option<i32>, tags: &str) -> Result<(), String> {
         let conn = get_connection()?;
 
         conn.execute(
-            "UPDATE bookmarks SET name = ?1, location = ?2 WHERE id = ?3",
-            params![name, location, bookmark_id],
+            "UPDATE bookmarks SET name = ?1, location = ?2, description = ?3, folder_id = ?4, tags = ?5,
+                checked_at = CASE WHEN location = ?2 THEN checked_at ELSE NULL END
+             WHERE id = ?6",
+            params![name, location, description, folder_id, tags, bookmark_id],
         )
         .map_err(|e| format!("Failed to update bookmark: {}", e))?;
 
         Ok(())
     }
+
+    /// Move a bookmark into a folder (None = unfiled)
+    pub fn set_folder(bookmark_id: i32, folder_id: Option<i32>) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "UPDATE bookmarks SET folder_id = ?1 WHERE id = ?2",
+            params![folder_id, bookmark_id],
+        )
+        .map_err(|e| format!("Failed to move bookmark: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Point a bookmark somewhere else (e.g. where its old address redirects to)
+    pub fn set_location(bookmark_id: i32, location: &str) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "UPDATE bookmarks SET location = ?1, checked_at = NULL WHERE id = ?2",
+            params![location, bookmark_id],
+        )
+        .map_err(|e| format!("Failed to update bookmark location: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Store the results of a link check run
+    pub fn set_check_results(results: &[LinkCheckRow]) -> Result<(), String> {
+        let mut conn = get_connection()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        for (bookmark_id, status, error, redirect_url, checked_at) in results {
+            tx.execute(
+                "UPDATE bookmarks SET check_status = ?1, check_error = ?2, redirect_url = ?3, checked_at = ?4 WHERE id = ?5",
+                params![status, error, redirect_url, checked_at, bookmark_id],
+            )
+            .map_err(|e| format!("Failed to save link check: {}", e))?;
+        }
+
+        tx.commit().map_err(|e| format!("Failed to save link checks: {}", e))
+    }
+
+    /// Fold duplicates into one bookmark: it takes over their open history and snapshots, the
+    /// duplicates are deleted before the kept bookmark gets its new location
+    pub fn merge(keep_id: i32, remove_ids: &[i32], location: &str, description: &str, tags: &str) -> Result<(), String> {
+        let mut conn = get_connection()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        for remove_id in remove_ids {
+            tx.execute("UPDATE bookmark_opens SET bookmark_id = ?1 WHERE bookmark_id = ?2", params![keep_id, remove_id])
+                .map_err(|e| format!("Failed to move bookmark opens: {}", e))?;
+            tx.execute("UPDATE bookmark_snapshots SET bookmark_id = ?1 WHERE bookmark_id = ?2", params![keep_id, remove_id])
+                .map_err(|e| format!("Failed to move bookmark snapshots: {}", e))?;
+            tx.execute(
+                "UPDATE bookmarks SET
+                    open_count = open_count + (SELECT open_count FROM bookmarks WHERE id = ?2),
+                    last_opened_at = MAX(COALESCE(last_opened_at, 0), COALESCE((SELECT last_opened_at FROM bookmarks WHERE id = ?2), 0))
+                 WHERE id = ?1",
+                params![keep_id, remove_id],
+            )
+            .map_err(|e| format!("Failed to merge bookmark usage: {}", e))?;
+            tx.execute("DELETE FROM bookmarks WHERE id = ?1", params![remove_id])
+                .map_err(|e| format!("Failed to delete bookmark: {}", e))?;
+        }
+
+        tx.execute(
+            "UPDATE bookmarks SET location = ?1, description = ?2, tags = ?3,
+                last_opened_at = NULLIF(last_opened_at, 0),
+                checked_at = CASE WHEN location = ?1 THEN checked_at ELSE NULL END
+             WHERE id = ?4",
+            params![location, description, tags, keep_id],
+        )
+        .map_err(|e| format!("Failed to update bookmark: {}", e))?;
+
+        tx.commit().map_err(|e| format!("Failed to merge bookmarks: {}", e))
+    }
+
+    /// Count an open of a bookmark and keep it in its open history, which is cut to the latest `keep` opens
+    pub fn record_open(bookmark_id: i32, opened_at: i64, keep: i64) -> Result<(), String> {
+        let mut conn = get_connection()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        tx.execute(
+            "INSERT INTO bookmark_opens (bookmark_id, opened_at) VALUES (?1, ?2)",
+            params![bookmark_id, opened_at],
+        )
+        .map_err(|e| format!("Failed to record bookmark open: {}", e))?;
+        tx.execute(
+            "DELETE FROM bookmark_opens WHERE bookmark_id = ?1 AND id NOT IN (
+                SELECT id FROM bookmark_opens WHERE bookmark_id = ?1 ORDER BY opened_at DESC, id DESC LIMIT ?2
+             )",
+            params![bookmark_id, keep],
+        )
+        .map_err(|e| format!("Failed to trim bookmark open history: {}", e))?;
+        tx.execute(
+            "UPDATE bookmarks SET open_count = open_count + 1, last_opened_at = ?1 WHERE id = ?2",
+            params![opened_at, bookmark_id],
+        )
+        .map_err(|e| format!("Failed to record bookmark open: {}", e))?;
+
+        tx.commit().map_err(|e| format!("Failed to record bookmark open: {}", e))
+    }
+
+    /// The most recent opens of every bookmark, at most `per_bookmark` each: (bookmark_id, opened_at)
+    pub fn get_recent_opens(per_bookmark: i64) -> Result<Vec<(i32, i64)>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT bookmark_id, opened_at FROM (
+                    SELECT bookmark_id, opened_at,
+                           ROW_NUMBER() OVER (PARTITION BY bookmark_id ORDER BY opened_at DESC) AS recency
+                    FROM bookmark_opens
+                 ) WHERE recency <= ?1",
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let opens = stmt
+            .query_map(params![per_bookmark], |row| Ok((row.get::<_, i32>(0)?, row.get::<_, i64>(1)?)))
+            .map_err(|e| format!("Failed to query bookmark opens: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect bookmark opens: {}", e))?;
+
+        Ok(opens)
+    }
+
+    /// Replace the tags of several bookmarks at once: (bookmark_id, tags)
+    pub fn set_tags_many(updates: &[(i32, String)]) -> Result<(), String> {
+        let mut conn = get_connection()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        for (bookmark_id, tags) in updates {
+            tx.execute("UPDATE bookmarks SET tags = ?1 WHERE id = ?2", params![tags, bookmark_id])
+                .map_err(|e| format!("Failed to update bookmark tags: {}", e))?;
+        }
+
+        tx.commit().map_err(|e| format!("Failed to update bookmark tags: {}", e))
+    }
+
+    fn map_row(row: &rusqlite::Row) -> rusqlite::Result<BookmarkRow> {
+        Ok((
+            row.get::<_, i32>("id")?,
+            row.get::<_, String>("name")?,
+            row.get::<_, String>("location")?,
+            row.get::<_, i64>("created_at")?,
+            row.get::<_, Option<i32>>("folder_id")?,
+            row.get::<_, String>("tags")?,
+            row.get::<_, Option<i32>>("check_status")?,
+            row.get::<_, Option<String>>("check_error")?,
+            row.get::<_, Option<String>>("redirect_url")?,
+            row.get::<_, Option<i64>>("checked_at")?,
+            row.get::<_, String>("description")?,
+            row.get::<_, i64>("open_count")?,
+            row.get::<_, Option<i64>>("last_opened_at")?,
+        ))
+    }
 }
@@ -0,0 +1,115 @@
+// src/dal/repositories/drafts_repository.rs
+use crate::dal::db_context::get_connection;
+use rusqlite::{params, OptionalExtension};
+
+/// Raw draft row: (id, note_id, title, details, notebook, tags, updated_at)
+pub type DraftRow = (i32, Option<i32>, String, String, String, String, i64);
+
+/// Drafts Repository - autosaved input from the note modals
+pub struct DraftsRepository;
+
+impl DraftsRepository {
+    /// Insert a new draft and return its ID
+    pub fn create(note_id: Option<i32>, title: &str, details: &str, notebook: &str, tags: &str) -> Result<i32, String> {
+        let conn = get_connection()?;
+        let now = chrono::Utc::now().timestamp();
+
+        conn.execute(
+            "INSERT INTO drafts (note_id, title, details, notebook, tags, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![note_id, title, details, notebook, tags, now],
+        )
+        .map_err(|e| format!("Failed to create draft: {}", e))?;
+
+        Ok(conn.last_insert_rowid() as i32)
+    }
+
+    /// Overwrite a draft, returns false if it no longer exists
+    pub fn update(draft_id: i32, title: &str, details: &str, notebook: &str, tags: &str) -> Result<bool, String> {
+        let conn = get_connection()?;
+        let now = chrono::Utc::now().timestamp();
+
+        let updated = conn
+            .execute(
+                "UPDATE drafts SET title = ?1, details = ?2, notebook = ?3, tags = ?4, updated_at = ?5 WHERE id = ?6",
+                params![title, details, notebook, tags, now, draft_id],
+            )
+            .map_err(|e| format!("Failed to update draft: {}", e))?;
+
+        Ok(updated > 0)
+    }
+
+    /// Get every draft, newest first
+    pub fn get_all() -> Result<Vec<DraftRow>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare("SELECT id, note_id, title, details, notebook, tags, updated_at FROM drafts ORDER BY updated_at DESC, id DESC")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let drafts = stmt
+            .query_map([], Self::map_row)
+            .map_err(|e| format!("Failed to query drafts: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect drafts: {}", e))?;
+
+        Ok(drafts)
+    }
+
+    /// Get a draft by ID
+    pub fn get_by_id(draft_id: i32) -> Result<DraftRow, String> {
+        let conn = get_connection()?;
+
+        conn.query_row(
+            "SELECT id, note_id, title, details, notebook, tags, updated_at FROM drafts WHERE id = ?1",
+            params![draft_id],
+            Self::map_row,
+        )
+        .map_err(|e| format!("Failed to get draft: {}", e))
+    }
+
+    /// Get the latest draft for a note (or for a new note when note_id is None)
+    pub fn get_latest_for_note(note_id: Option<i32>) -> Result<Option<DraftRow>, String> {
+        let conn = get_connection()?;
+
+        conn.query_row(
+            "SELECT id, note_id, title, details, notebook, tags, updated_at FROM drafts
+             WHERE note_id IS ?1 ORDER BY updated_at DESC, id DESC LIMIT 1",
+            params![note_id],
+            Self::map_row,
+        )
+        .optional()
+        .map_err(|e| format!("Failed to get draft: {}", e))
+    }
+
+    /// Delete a draft
+    pub fn delete(draft_id: i32) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute("DELETE FROM drafts WHERE id = ?1", params![draft_id])
+            .map_err(|e| format!("Failed to delete draft: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Delete every draft of a note
+    pub fn delete_for_note(note_id: i32) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute("DELETE FROM drafts WHERE note_id = ?1", params![note_id])
+            .map_err(|e| format!("Failed to delete drafts: {}", e))?;
+
+        Ok(())
+    }
+
+    fn map_row(row: &rusqlite::Row) -> rusqlite::Result<DraftRow> {
+        Ok((
+            row.get::<_, i32>("id")?,
+            row.get::<_, Option<i32>>("note_id")?,
+            row.get::<_, String>("title")?,
+            row.get::<_, String>("details")?,
+            row.get::<_, String>("notebook")?,
+            row.get::<_, String>("tags")?,
+            row.get::<_, i64>("updated_at")?,
+        ))
+    }
+}
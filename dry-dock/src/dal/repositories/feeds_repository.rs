@@ -2,6 +2,18 @@
 use crate::dal::db_context::get_connection;
 use rusqlite::params;
 
+/// Feed with its unread item count: (id, url, title, category, unread_count)
+pub type FeedRow = (i32, String, String, String, i64);
+
+/// New feed for create_many: (url, title, category)
+pub type NewFeedRow = (String, String, String);
+
+/// HTTP caching state of a feed: (etag, last_modified, next_fetch_at, retry_at)
+pub type FeedFetchState = (Option<String>, Option<String>, Option<i64>, Option<i64>);
+
+/// Raw feed item row: (id, feed_id, feed_title, title, link, description, pub_date, is_read, is_starred)
+pub type FeedItemRow = (i32, i32, String, String, String, String, i64, bool, bool);
+
 /// Feeds Repository - handles all RSS feed-related database operations
 pub struct FeedsRepository;
 
@@ -20,6 +32,32 @@ impl FeedsRepository {
         Ok(())
     }
 
+    /// Insert many feeds in one transaction, skipping URLs that are already subscribed.
+    /// Returns (id, url) of the feeds that were inserted.
+    pub fn create_many(feeds: &[NewFeedRow]) -> Result<Vec<(i32, String)>, String> {
+        let mut conn = get_connection()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+        let now = chrono::Utc::now().timestamp();
+
+        let mut inserted = Vec::new();
+        for (url, title, category) in feeds {
+            let changed = tx
+                .execute(
+                    "INSERT OR IGNORE INTO feeds (title, url, category, created_at) VALUES (?1, ?2, ?3, ?4)",
+                    params![title, url, category, now],
+                )
+                .map_err(|e| format!("Failed to add feed: {}", e))?;
+            if changed == 1 {
+                inserted.push((tx.last_insert_rowid() as i32, url.clone()));
+            }
+        }
+
+        tx.commit().map_err(|e| format!("Failed to commit feeds: {}", e))?;
+        Ok(inserted)
+    }
+
     /// Get all feeds
     pub fn get_all() -> Result<Vec<(i32, String, String)>, String> {
         let conn = get_connection()?;
@@ -37,6 +75,29 @@ impl FeedsRepository {
         Ok(feeds)
     }
 
+    /// Get all feeds by category and title with how many of their items are unread
+    pub fn get_all_with_unread_counts() -> Result<Vec<FeedRow>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT f.id, f.url, f.title, f.category, COUNT(i.id)
+                 FROM feeds f
+                 LEFT JOIN feed_items i ON i.feed_id = f.id AND i.is_read = 0
+                 GROUP BY f.id
+                 ORDER BY f.category COLLATE NOCASE, f.title COLLATE NOCASE",
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let feeds = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)))
+            .map_err(|e| format!("Failed to query feeds: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect feeds: {}", e))?;
+
+        Ok(feeds)
+    }
+
     /// Update feed's last_updated timestamp
     pub fn update_last_updated(feed_id: i32, timestamp: i64) -> Result<(), String> {
         let conn = get_connection()?;
@@ -50,6 +111,44 @@ impl FeedsRepository {
         Ok(())
     }
 
+    /// Get the validators, the max-age expiry and the Retry-After time the feed's server gave us
+    pub fn get_fetch_state(feed_id: i32) -> Result<FeedFetchState, String> {
+        let conn = get_connection()?;
+
+        conn.query_row(
+            "SELECT etag, last_modified, next_fetch_at, retry_at FROM feeds WHERE id = ?1",
+            params![feed_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .map_err(|e| format!("Failed to read feed fetch state: {}", e))
+    }
+
+    /// Save the validators and max-age expiry from the latest answered request, clearing any Retry-After
+    pub fn update_fetch_state(feed_id: i32, etag: Option<&str>, last_modified: Option<&str>, next_fetch_at: Option<i64>) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "UPDATE feeds SET etag = ?1, last_modified = ?2, next_fetch_at = ?3, retry_at = NULL WHERE id = ?4",
+            params![etag, last_modified, next_fetch_at, feed_id],
+        )
+        .map_err(|e| format!("Failed to update feed fetch state: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Save when a rate limited or unavailable feed may be asked again
+    pub fn set_retry_at(feed_id: i32, retry_at: i64) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "UPDATE feeds SET retry_at = ?1 WHERE id = ?2",
+            params![retry_at, feed_id],
+        )
+        .map_err(|e| format!("Failed to update feed retry time: {}", e))?;
+
+        Ok(())
+    }
+
     /// Delete a feed by ID
     pub fn delete(feed_id: i32) -> Result<(), String> {
         let conn = get_connection()?;
@@ -68,34 +167,99 @@ impl FeedsRepository {
 pub struct FeedItemsRepository;
 
 impl FeedItemsRepository {
-    /// Get feed items with optional limit
-    pub fn get_latest(limit: i32) -> Result<Vec<(i32, String, String, String, i64)>, String> {
+    /// Get a page of the newest feed items, optionally only those of one feed, unread or starred.
+    /// Pages continue after the (pub_date, id) of the previous page's last item (None for the first),
+    /// so items that leave the filter while being read don't shift the next page.
+    pub fn get_filtered(feed_id: Option<i32>, unread_only: bool, starred_only: bool, before: Option<(i64, i32)>, limit: i64) -> Result<Vec<FeedItemRow>, String> {
         let conn = get_connection()?;
 
         let mut stmt = conn
-            .prepare("SELECT id, title, link, description, pub_date FROM feed_items ORDER BY pub_date DESC LIMIT ?1")
+            .prepare(
+                "SELECT i.id, i.feed_id, f.title AS feed_title, i.title, i.link, i.description, i.pub_date, i.is_read, i.is_starred
+                 FROM feed_items i
+                 JOIN feeds f ON f.id = i.feed_id
+                 WHERE (?1 IS NULL OR i.feed_id = ?1)
+                   AND (?2 = 0 OR i.is_read = 0)
+                   AND (?3 = 0 OR i.is_starred = 1)
+                   AND (?4 IS NULL OR (i.pub_date, i.id) < (?4, ?5))
+                 ORDER BY i.pub_date DESC, i.id DESC
+                 LIMIT ?6",
+            )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
-        let mut items = stmt
-            .query_map(params![limit], |row| {
+        let items = stmt
+            .query_map(params![feed_id, unread_only, starred_only, before.map(|(pub_date, _)| pub_date), before.map(|(_, id)| id), limit], |row| {
                 Ok((
                     row.get::<_, i32>("id")?,
+                    row.get::<_, i32>("feed_id")?,
+                    row.get::<_, String>("feed_title")?,
                     row.get::<_, String>("title")?,
                     row.get::<_, String>("link")?,
                     row.get::<_, String>("description")?,
                     row.get::<_, i64>("pub_date")?,
+                    row.get::<_, bool>("is_read")?,
+                    row.get::<_, bool>("is_starred")?,
                 ))
             })
             .map_err(|e| format!("Failed to query feed items: {}", e))?
             .collect::<Result<Vec<_>, _>>()
             .map_err(|e| format!("Failed to collect feed items: {}", e))?;
 
-        // Sort by pub_date DESC
-        items.sort_by(|a, b| b.4.cmp(&a.4));
-        
         Ok(items)
     }
 
+    /// Count the feed items get_filtered pages through
+    pub fn count_filtered(feed_id: Option<i32>, unread_only: bool, starred_only: bool) -> Result<i64, String> {
+        let conn = get_connection()?;
+
+        conn.query_row(
+            "SELECT COUNT(*) FROM feed_items
+             WHERE (?1 IS NULL OR feed_id = ?1)
+               AND (?2 = 0 OR is_read = 0)
+               AND (?3 = 0 OR is_starred = 1)",
+            params![feed_id, unread_only, starred_only],
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Failed to count feed items: {}", e))
+    }
+
+    /// Mark a feed item read or unread
+    pub fn set_read(item_id: i32, is_read: bool) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "UPDATE feed_items SET is_read = ?1 WHERE id = ?2",
+            params![is_read, item_id],
+        )
+        .map_err(|e| format!("Failed to update feed item: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Star or unstar a feed item
+    pub fn set_starred(item_id: i32, is_starred: bool) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "UPDATE feed_items SET is_starred = ?1 WHERE id = ?2",
+            params![is_starred, item_id],
+        )
+        .map_err(|e| format!("Failed to update feed item: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Mark every unread item read, of one feed or of all feeds, returns how many changed
+    pub fn mark_all_read(feed_id: Option<i32>) -> Result<usize, String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "UPDATE feed_items SET is_read = 1 WHERE is_read = 0 AND (?1 IS NULL OR feed_id = ?1)",
+            params![feed_id],
+        )
+        .map_err(|e| format!("Failed to mark feed items read: {}", e))
+    }
+
     /// Insert or ignore feed item (based on guid uniqueness)
     pub fn insert_or_ignore(
         feed_id: i32,
@@ -2,9 +2,19 @@
 pub mod notes_repository;
 pub mod feeds_repository;
 pub mod bookmarks_repository;
+pub mod bookmark_folders_repository;
+pub mod bookmark_snapshots_repository;
 pub mod logs_repository;
+pub mod settings_repository;
+pub mod note_sync_repository;
+pub mod drafts_repository;
 
 pub use notes_repository::NotesRepository;
 pub use feeds_repository::{FeedsRepository, FeedItemsRepository};
 pub use bookmarks_repository::BookmarksRepository;
-pub use logs_repository::LogsRepository;
\ No newline at end of file
+pub use bookmark_folders_repository::BookmarkFoldersRepository;
+pub use bookmark_snapshots_repository::BookmarkSnapshotsRepository;
+pub use logs_repository::LogsRepository;
+pub use settings_repository::SettingsRepository;
+pub use note_sync_repository::NoteSyncRepository;
+pub use drafts_repository::DraftsRepository;
\ No newline at end of file
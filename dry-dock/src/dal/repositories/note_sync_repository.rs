@@ -0,0 +1,123 @@
+// src/dal/repositories/note_sync_repository.rs
+use crate::dal::db_context::get_connection;
+use rusqlite::params;
+
+/// Raw sync state row: (note_id, file_path, content_hash, file_mtime, note_updated_at)
+pub type NoteSyncStateRow = (i32, String, String, i64, i64);
+
+/// Note Sync Repository - tracks what was last written to / read from the markdown mirror
+pub struct NoteSyncRepository;
+
+impl NoteSyncRepository {
+    /// Get the sync state of every mirrored note
+    pub fn get_all_states() -> Result<Vec<NoteSyncStateRow>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare("SELECT note_id, file_path, content_hash, file_mtime, note_updated_at FROM note_sync_state")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let states = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i32>("note_id")?,
+                    row.get::<_, String>("file_path")?,
+                    row.get::<_, String>("content_hash")?,
+                    row.get::<_, i64>("file_mtime")?,
+                    row.get::<_, i64>("note_updated_at")?,
+                ))
+            })
+            .map_err(|e| format!("Failed to query sync state: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect sync state: {}", e))?;
+
+        Ok(states)
+    }
+
+    /// Record what was last synced for a note
+    pub fn upsert_state(note_id: i32, file_path: &str, content_hash: &str, file_mtime: i64, note_updated_at: i64) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "INSERT OR REPLACE INTO note_sync_state (note_id, file_path, content_hash, file_mtime, note_updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![note_id, file_path, content_hash, file_mtime, note_updated_at],
+        )
+        .map_err(|e| format!("Failed to save sync state: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Forget the sync state of a note
+    pub fn delete_state(note_id: i32) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "DELETE FROM note_sync_state WHERE note_id = ?1",
+            params![note_id],
+        )
+        .map_err(|e| format!("Failed to delete sync state: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Record a conflict (replaces an older conflict for the same note)
+    pub fn add_conflict(note_id: i32, file_path: &str, file_content: &str) -> Result<(), String> {
+        let conn = get_connection()?;
+        let now = chrono::Utc::now().timestamp();
+
+        conn.execute(
+            "INSERT OR REPLACE INTO note_sync_conflicts (note_id, file_path, file_content, detected_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![note_id, file_path, file_content, now],
+        )
+        .map_err(|e| format!("Failed to save sync conflict: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Get all unresolved conflicts as (note_id, file_path, file_content, detected_at)
+    pub fn get_conflicts() -> Result<Vec<(i32, String, String, i64)>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare("SELECT note_id, file_path, file_content, detected_at FROM note_sync_conflicts ORDER BY detected_at DESC")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let conflicts = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i32>("note_id")?,
+                    row.get::<_, String>("file_path")?,
+                    row.get::<_, String>("file_content")?,
+                    row.get::<_, i64>("detected_at")?,
+                ))
+            })
+            .map_err(|e| format!("Failed to query sync conflicts: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect sync conflicts: {}", e))?;
+
+        Ok(conflicts)
+    }
+
+    /// Count unresolved conflicts
+    pub fn count_conflicts() -> Result<i64, String> {
+        let conn = get_connection()?;
+
+        conn.query_row("SELECT COUNT(*) FROM note_sync_conflicts", [], |row| row.get::<_, i64>(0))
+            .map_err(|e| format!("Failed to count sync conflicts: {}", e))
+    }
+
+    /// Remove a conflict once it has been resolved
+    pub fn delete_conflict(note_id: i32) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "DELETE FROM note_sync_conflicts WHERE note_id = ?1",
+            params![note_id],
+        )
+        .map_err(|e| format!("Failed to delete sync conflict: {}", e))?;
+
+        Ok(())
+    }
+}
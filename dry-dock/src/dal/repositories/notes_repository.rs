@@ -1,56 +1,82 @@
 // src/dal/repositories/notes_repository.rs
 use crate::dal::db_context::get_connection;
 use rusqlite::params;
+use crate::models::{NoteSort, NoteFilter};
+
+/// Raw note row: (id, title, details, notebook, created_at, updated_at, is_pinned, is_archived, is_locked, tags)
+pub type NoteRow = (i32, String, String, String, i64, Option<i64>, bool, bool, bool, String);
+
+/// Encrypted note content: (ciphertext, salt, nonce), all base64
+pub type NoteLockRow = (String, String, String);
+
+/// Sort key of the last note of a page: (is_pinned, created_at, last modified, title, id)
+pub type NoteCursor = (bool, i64, i64, String, i32);
+
+/// Columns selected for a NoteRow, in tuple order
+const NOTE_COLUMNS: &str = "id, title, details, notebook, created_at, updated_at, is_pinned, is_archived, is_locked, tags";
 
 /// Notes Repository - handles all note-related database operations
 pub struct NotesRepository;
 
 impl NotesRepository {
-    /// Create a new note
-    pub fn create(title: &str, details: &str) -> Result<(), String> {
+    /// Create a new note and return its ID
+    pub fn create(title: &str, details: &str, notebook: &str) -> Result<i32, String> {
         let conn = get_connection()?;
         let now = chrono::Utc::now().timestamp();
 
         conn.execute(
-            "INSERT INTO notes (title, details, created_at) VALUES (?1, ?2, ?3)",
-            params![title, details, now],
+            "INSERT INTO notes (title, details, notebook, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![title, details, notebook, now],
         )
         .map_err(|e| format!("Failed to create note: {}", e))?;
 
-        Ok(())
+        Ok(conn.last_insert_rowid() as i32)
     }
 
-    /// Delete a note by ID
-    pub fn delete(note_id: i32) -> Result<(), String> {
+    /// Get one page of notes, optionally filtered by a search query
+    /// An empty query returns every note in the filter. Pages continue after the cursor (None for
+    /// the first page) instead of using OFFSET, so late pages cost the same as the first one.
+    pub fn get_page(query: &str, filter: NoteFilter, sort: NoteSort, after: Option<&NoteCursor>, limit: i64) -> Result<Vec<NoteRow>, String> {
         let conn = get_connection()?;
 
-        conn.execute(
-            "DELETE FROM notes WHERE id = ?1",
-            params![note_id],
-        )
-        .map_err(|e| format!("Failed to delete note: {}", e))?;
+        let sql = format!(
+            "SELECT {} FROM notes WHERE {} AND (?3 IS NULL OR {}) ORDER BY {} LIMIT ?8",
+            NOTE_COLUMNS,
+            Self::where_clause(filter),
+            Self::after_clause(sort),
+            Self::order_by(sort)
+        );
+        let (is_pinned, created_at, modified_at, title, id) = match after {
+            Some((is_pinned, created_at, modified_at, title, id)) => (Some(*is_pinned), *created_at, *modified_at, title.as_str(), *id),
+            None => (None, 0, 0, "", 0),
+        };
 
-        Ok(())
+        let mut stmt = conn
+            .prepare(&sql)
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let notes = stmt
+            .query_map(
+                params![query, Self::like_pattern(query), is_pinned, created_at, modified_at, title, id, limit],
+                Self::map_row,
+            )
+            .map_err(|e| format!("Failed to query notes: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect notes: {}", e))?;
+
+        Ok(notes)
     }
 
-    /// Get all notes
-    pub fn get_all() -> Result<Vec<(i32, String, String, i64, Option<i64>)>, String> {
+    /// Get every note, used by the markdown mirror
+    pub fn get_all() -> Result<Vec<NoteRow>, String> {
         let conn = get_connection()?;
 
         let mut stmt = conn
-            .prepare("SELECT id, title, details, created_at, updated_at FROM notes ORDER BY created_at DESC")
+            .prepare(&format!("SELECT {} FROM notes ORDER BY id ASC", NOTE_COLUMNS))
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let notes = stmt
-            .query_map([], |row| {
-                Ok((
-                    row.get::<_, i32>("id")?,
-                    row.get::<_, String>("title")?,
-                    row.get::<_, String>("details")?,
-                    row.get::<_, i64>("created_at")?,
-                    row.get::<_, Option<i64>>("updated_at")?,
-                ))
-            })
+            .query_map([], Self::map_row)
             .map_err(|e| format!("Failed to query notes: {}", e))?
             .collect::<Result<Vec<_>, _>>()
             .map_err(|e| format!("Failed to collect notes: {}", e))?;
@@ -58,40 +84,258 @@ impl NotesRepository {
         Ok(notes)
     }
 
+    /// Count notes matching a filter and search query (an empty query counts the whole filter)
+    pub fn count(query: &str, filter: NoteFilter) -> Result<i64, String> {
+        let conn = get_connection()?;
+
+        conn.query_row(
+            &format!("SELECT COUNT(*) FROM notes WHERE {}", Self::where_clause(filter)),
+            params![query, Self::like_pattern(query)],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|e| format!("Failed to count notes: {}", e))
+    }
+
+    /// Pin or unpin several notes at once
+    pub fn set_pinned(note_ids: &[i32], pinned: bool) -> Result<(), String> {
+        Self::update_many("UPDATE notes SET is_pinned = ?1 WHERE id = ?2", note_ids, pinned)
+    }
+
+    /// Archive or restore several notes at once
+    pub fn set_archived(note_ids: &[i32], archived: bool) -> Result<(), String> {
+        Self::update_many("UPDATE notes SET is_archived = ?1 WHERE id = ?2", note_ids, archived)
+    }
+
+    /// Delete several notes at once
+    pub fn delete_many(note_ids: &[i32]) -> Result<(), String> {
+        let mut conn = get_connection()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        for note_id in note_ids {
+            tx.execute("DELETE FROM notes WHERE id = ?1", params![note_id])
+                .map_err(|e| format!("Failed to delete note: {}", e))?;
+        }
+
+        tx.commit().map_err(|e| format!("Failed to delete notes: {}", e))
+    }
+
+    /// Run a flag update for each note inside a single transaction
+    fn update_many(sql: &str, note_ids: &[i32], value: bool) -> Result<(), String> {
+        let mut conn = get_connection()?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        for note_id in note_ids {
+            tx.execute(sql, params![value, note_id])
+                .map_err(|e| format!("Failed to update note: {}", e))?;
+        }
+
+        tx.commit().map_err(|e| format!("Failed to update notes: {}", e))
+    }
+
+    /// WHERE clause for a filter plus the search query (?1 = raw query, ?2 = LIKE pattern)
+    /// Archived notes leave the Active list but still match a search, locked notes never do
+    fn where_clause(filter: NoteFilter) -> &'static str {
+        match filter {
+            NoteFilter::Active => "(is_archived = 0 OR ?1 <> '') \
+                AND (?1 = '' OR (is_locked = 0 AND (unicode_lower(title) LIKE ?2 ESCAPE '\\' OR unicode_lower(details) LIKE ?2 ESCAPE '\\')))",
+            NoteFilter::Pinned => "is_pinned = 1 AND is_archived = 0 \
+                AND (?1 = '' OR (is_locked = 0 AND (unicode_lower(title) LIKE ?2 ESCAPE '\\' OR unicode_lower(details) LIKE ?2 ESCAPE '\\')))",
+            NoteFilter::Archived => "is_archived = 1 \
+                AND (?1 = '' OR (is_locked = 0 AND (unicode_lower(title) LIKE ?2 ESCAPE '\\' OR unicode_lower(details) LIKE ?2 ESCAPE '\\')))",
+        }
+    }
+
+    /// ORDER BY clause for a sort option. The id tie-breaker keeps paging stable.
+    fn order_by(sort: NoteSort) -> &'static str {
+        match sort {
+            NoteSort::PinnedFirst => "is_pinned DESC, created_at DESC, id DESC",
+            NoteSort::Created => "created_at DESC, id DESC",
+            NoteSort::Updated => "COALESCE(updated_at, created_at) DESC, id DESC",
+            NoteSort::Title => "title COLLATE NOCASE ASC, id ASC",
+        }
+    }
+
+    /// Rows that sort after the cursor (?3 = is_pinned, ?4 = created_at, ?5 = last modified,
+    /// ?6 = title, ?7 = id), matching order_by column for column
+    fn after_clause(sort: NoteSort) -> &'static str {
+        match sort {
+            NoteSort::PinnedFirst => "(is_pinned, created_at, id) < (?3, ?4, ?7)",
+            NoteSort::Created => "(created_at, id) < (?4, ?7)",
+            NoteSort::Updated => "(COALESCE(updated_at, created_at), id) < (?5, ?7)",
+            NoteSort::Title => "(title > ?6 COLLATE NOCASE OR (title = ?6 COLLATE NOCASE AND id > ?7))",
+        }
+    }
+
+    /// Build a LIKE pattern that matches the query anywhere, escaping wildcards.
+    /// Lowercased to compare against unicode_lower(column), see db_context.
+    fn like_pattern(query: &str) -> String {
+        format!("%{}%", Self::escape_like(&query.to_lowercase()))
+    }
+
+    /// Escape LIKE wildcards (used with ESCAPE '\\')
+    fn escape_like(value: &str) -> String {
+        value
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    }
+
     /// Get a note by ID
-    pub fn get_by_id(note_id: i32) -> Result<(i32, String, String, i64, Option<i64>), String> {
+    pub fn get_by_id(note_id: i32) -> Result<NoteRow, String> {
         let conn = get_connection()?;
 
         let mut stmt = conn
-            .prepare("SELECT id, title, details, created_at, updated_at FROM notes WHERE id = ?1")
+            .prepare(&format!("SELECT {} FROM notes WHERE id = ?1", NOTE_COLUMNS))
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let note = stmt
-            .query_row(params![note_id], |row| {
-                Ok((
-                    row.get::<_, i32>("id")?,
-                    row.get::<_, String>("title")?,
-                    row.get::<_, String>("details")?,
-                    row.get::<_, i64>("created_at")?,
-                    row.get::<_, Option<i64>>("updated_at")?,
-                ))
-            })
+            .query_row(params![note_id], Self::map_row)
             .map_err(|e| format!("Failed to get note: {}", e))?;
 
         Ok(note)
     }
 
     /// Update a note
-    pub fn update(note_id: i32, title: &str, details: &str) -> Result<(), String> {
+    pub fn update(note_id: i32, title: &str, details: &str, notebook: &str) -> Result<(), String> {
         let conn = get_connection()?;
         let now = chrono::Utc::now().timestamp();
 
         conn.execute(
-            "UPDATE notes SET title = ?1, details = ?2, updated_at = ?3 WHERE id = ?4",
-            params![title, details, now, note_id],
+            "UPDATE notes SET title = ?1, details = ?2, notebook = ?3, updated_at = ?4 WHERE id = ?5",
+            params![title, details, notebook, now, note_id],
         )
         .map_err(|e| format!("Failed to update note: {}", e))?;
 
         Ok(())
     }
+
+    /// Replace a note's tags (already normalized, see NoteService)
+    pub fn set_tags(note_id: i32, tags: &str) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "UPDATE notes SET tags = ?1 WHERE id = ?2",
+            params![tags, note_id],
+        )
+        .map_err(|e| format!("Failed to update note tags: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Get every note carrying a tag
+    pub fn get_by_tag(tag: &str) -> Result<Vec<NoteRow>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare(&format!("SELECT {} FROM notes WHERE tags LIKE ?1 ESCAPE '\\' ORDER BY id ASC", NOTE_COLUMNS))
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let notes = stmt
+            .query_map(params![format!("%,{},%", Self::escape_like(tag))], Self::map_row)
+            .map_err(|e| format!("Failed to query notes: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect notes: {}", e))?;
+
+        Ok(notes)
+    }
+
+    /// Get every note in a notebook, oldest first
+    pub fn get_by_notebook(notebook: &str) -> Result<Vec<NoteRow>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare(&format!("SELECT {} FROM notes WHERE notebook = ?1 ORDER BY created_at ASC, id ASC", NOTE_COLUMNS))
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let notes = stmt
+            .query_map(params![notebook], Self::map_row)
+            .map_err(|e| format!("Failed to query notes: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect notes: {}", e))?;
+
+        Ok(notes)
+    }
+
+    /// Get the distinct notebook names in use
+    pub fn get_notebooks() -> Result<Vec<String>, String> {
+        let conn = get_connection()?;
+
+        let mut stmt = conn
+            .prepare("SELECT DISTINCT notebook FROM notes ORDER BY notebook COLLATE NOCASE ASC")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let notebooks = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| format!("Failed to query notebooks: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to collect notebooks: {}", e))?;
+
+        Ok(notebooks)
+    }
+
+    /// Get the ciphertext, salt and nonce of a locked note
+    pub fn get_lock(note_id: i32) -> Result<NoteLockRow, String> {
+        let conn = get_connection()?;
+
+        conn.query_row(
+            "SELECT details, lock_salt, lock_nonce FROM notes WHERE id = ?1 AND is_locked = 1",
+            params![note_id],
+            |row| Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            )),
+        )
+        .map_err(|e| format!("Failed to get locked note: {}", e))
+    }
+
+    /// Store encrypted content for a note and mark it locked
+    pub fn set_locked(note_id: i32, title: &str, notebook: &str, lock: &NoteLockRow) -> Result<(), String> {
+        let conn = get_connection()?;
+        let now = chrono::Utc::now().timestamp();
+        let (ciphertext, salt, nonce) = lock;
+
+        conn.execute(
+            "UPDATE notes SET title = ?1, notebook = ?2, details = ?3, lock_salt = ?4, lock_nonce = ?5, is_locked = 1, updated_at = ?6
+             WHERE id = ?7",
+            params![title, notebook, ciphertext, salt, nonce, now, note_id],
+        )
+        .map_err(|e| format!("Failed to lock note: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Store plain content for a locked note and clear the lock
+    pub fn set_unlocked(note_id: i32, details: &str) -> Result<(), String> {
+        let conn = get_connection()?;
+        let now = chrono::Utc::now().timestamp();
+
+        conn.execute(
+            "UPDATE notes SET details = ?1, lock_salt = NULL, lock_nonce = NULL, is_locked = 0, updated_at = ?2
+             WHERE id = ?3",
+            params![details, now, note_id],
+        )
+        .map_err(|e| format!("Failed to unlock note: {}", e))?;
+
+        Ok(())
+    }
+
+    fn map_row(row: &rusqlite::Row) -> rusqlite::Result<NoteRow> {
+        Ok((
+            row.get::<_, i32>("id")?,
+            row.get::<_, String>("title")?,
+            row.get::<_, String>("details")?,
+            row.get::<_, String>("notebook")?,
+            row.get::<_, i64>("created_at")?,
+            row.get::<_, Option<i64>>("updated_at")?,
+            row.get::<_, bool>("is_pinned")?,
+            row.get::<_, bool>("is_archived")?,
+            row.get::<_, bool>("is_locked")?,
+            row.get::<_, String>("tags")?,
+        ))
+    }
 }
@@ -0,0 +1,35 @@
+// src/dal/repositories/settings_repository.rs
+use crate::dal::db_context::get_connection;
+use rusqlite::{params, OptionalExtension};
+
+/// Settings Repository - simple key/value store for app settings
+pub struct SettingsRepository;
+
+impl SettingsRepository {
+    /// Get a setting value by key
+    pub fn get(key: &str) -> Result<Option<String>, String> {
+        let conn = get_connection()?;
+
+        conn.query_row(
+            "SELECT value FROM settings WHERE key = ?1",
+            params![key],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map_err(|e| format!("Failed to get setting '{}': {}", key, e))
+    }
+
+    /// Insert or replace a setting value
+    pub fn set(key: &str, value: &str) -> Result<(), String> {
+        let conn = get_connection()?;
+
+        conn.execute(
+            "INSERT INTO settings (key, value) VALUES (?1, ?2)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            params![key, value],
+        )
+        .map_err(|e| format!("Failed to save setting '{}': {}", key, e))?;
+
+        Ok(())
+    }
+}
@@ -42,6 +42,9 @@ impl DryDockApp {
 
 impl eframe::App for DryDockApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Global shortcuts (Ctrl+K quick launcher)
+        self.state.handle_shortcuts(ctx);
+
         // Top Menu Bar
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             egui::MenuBar::new().ui(ui, |ui| {
@@ -74,11 +77,13 @@ impl eframe::App for DryDockApp {
     }
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
-        // Cleanup if needed
+        // Don't lose anything typed into an open note modal
+        self.state.save_drafts();
     }
 
     fn save(&mut self, _storage: &mut dyn eframe::Storage) {
-        // Save state if needed
+        // Modals autosave their own drafts, this catches anything typed since the last autosave
+        self.state.save_drafts();
     }
 
     fn auto_save_interval(&self) -> std::time::Duration {
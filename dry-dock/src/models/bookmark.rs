@@ -5,16 +5,267 @@ pub struct Bookmark {
     pub id: i32,
     pub name: String,
     pub path: String,
+    pub description: String,
     pub created_at: String,
+    /// None for bookmarks that are not in a folder
+    pub folder_id: Option<i32>,
+    pub tags: Vec<String>,
+    /// What the location points at, worked out from the location itself
+    pub kind: BookmarkKind,
+    /// The location as shown on cards, worked out once since folders need a filesystem check
+    pub display: String,
+    /// None until the link checker has looked at it
+    pub link_check: Option<LinkCheck>,
+    /// How often and when it was last opened from Dry Dock
+    pub open_count: i64,
+    pub last_opened_at: Option<i64>,
+    /// Frequency weighted by recency of the latest opens, 0 when never opened (see bookmark_service::frecency)
+    pub frecency: f64,
 }
 
 impl Bookmark {
-    pub fn new(id: i32, name: String, path: String, created_at: String) -> Self {
-        Self {
-            id,
-            name,
-            path,
-            created_at,
+    /// Tags as typed in the bookmark modals ("docs, rust")
+    pub fn tags_text(&self) -> String {
+        self.tags.join(", ")
+    }
+}
+
+/// Sort options for the bookmarks list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BookmarkSort {
+    #[default]
+    Name,
+    /// Opened often and lately first
+    Frecency,
+    RecentlyOpened,
+    Newest,
+}
+
+impl BookmarkSort {
+    pub const ALL: [BookmarkSort; 4] = [BookmarkSort::Name, BookmarkSort::Frecency, BookmarkSort::RecentlyOpened, BookmarkSort::Newest];
+
+    pub fn label(&self) -> &str {
+        match self {
+            BookmarkSort::Name => "Name (A-Z)",
+            BookmarkSort::Frecency => "Frecency",
+            BookmarkSort::RecentlyOpened => "Recently opened",
+            BookmarkSort::Newest => "Recently added",
+        }
+    }
+}
+
+/// What a bookmark's location points at. Each kind has an opener in bookmark_opener_service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BookmarkKind {
+    /// http:// or https://
+    Url,
+    File,
+    Folder,
+    /// mailto:someone@example.com
+    Email,
+    /// ssh://user@host:port, opened in a terminal
+    Ssh,
+    /// cmd: followed by a shell command
+    Command,
+    /// app: followed by an application name or path
+    Application,
+    /// Any other scheme handled by an installed app (vscode://, obsidian://, zoommtg:, ...)
+    CustomScheme,
+}
+
+impl BookmarkKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BookmarkKind::Url => "Web page",
+            BookmarkKind::File => "File",
+            BookmarkKind::Folder => "Folder",
+            BookmarkKind::Email => "Email",
+            BookmarkKind::Ssh => "SSH",
+            BookmarkKind::Command => "Command",
+            BookmarkKind::Application => "Application",
+            BookmarkKind::CustomScheme => "App link",
         }
     }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            BookmarkKind::Url => "🌐",
+            BookmarkKind::File => "📄",
+            BookmarkKind::Folder => "📁",
+            BookmarkKind::Email => "✉",
+            BookmarkKind::Ssh => "🖥",
+            BookmarkKind::Command => "⌨",
+            BookmarkKind::Application => "🚀",
+            BookmarkKind::CustomScheme => "🔗",
+        }
+    }
+}
+
+/// Result of the last dead-link check of a bookmark
+#[derive(Debug, Clone)]
+pub struct LinkCheck {
+    /// HTTP status, None when there was no response or for file paths
+    pub status: Option<u16>,
+    /// Why the check failed ("Could not connect", "Path not found", ...)
+    pub error: Option<String>,
+    /// Where the address ends up after following redirects, when that is somewhere else
+    pub redirect_url: Option<String>,
+    pub checked_at: i64,
+}
+
+impl LinkCheck {
+    pub fn is_broken(&self) -> bool {
+        self.error.is_some() || self.status.is_some_and(|status| status >= 400)
+    }
+
+    /// Short text for the health badge
+    pub fn summary(&self) -> String {
+        match (&self.error, self.status, &self.redirect_url) {
+            (Some(error), _, _) => error.clone(),
+            (None, Some(status), _) if status >= 400 => format!("HTTP {}", status),
+            (None, _, Some(_)) => "Redirects".to_string(),
+            (None, Some(status), None) => format!("OK ({})", status),
+            (None, None, None) => "OK".to_string(),
+        }
+    }
+}
+
+/// Outcome of a link check run
+#[derive(Debug, Clone, Default)]
+pub struct LinkCheckReport {
+    pub checked: usize,
+    pub broken: usize,
+    pub redirected: usize,
+}
+
+/// Details read from a web page to fill in a new bookmark
+#[derive(Debug, Clone, Default)]
+pub struct PageMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+/// A saved offline copy of a bookmarked page
+#[derive(Debug, Clone)]
+pub struct BookmarkSnapshot {
+    pub id: i32,
+    /// Self-contained HTML file
+    pub path: std::path::PathBuf,
+    pub size_bytes: i64,
+    pub created_at: i64,
+}
+
+/// State of the git working tree a folder bookmark points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitStatus {
+    /// Branch name, or the short commit id when HEAD is detached
+    pub head: String,
+    pub detached: bool,
+    /// Commits (ahead, behind) the upstream branch, None when there is no upstream
+    pub ahead_behind: Option<(usize, usize)>,
+    /// Modified, staged and untracked files
+    pub changed: usize,
+}
+
+impl GitStatus {
+    pub fn is_dirty(&self) -> bool {
+        self.changed > 0
+    }
+}
+
+/// A file or subfolder listed in the file browser of a folder bookmark
+#[derive(Debug, Clone)]
+pub struct FileEntry {
+    pub name: String,
+    pub path: std::path::PathBuf,
+    pub is_dir: bool,
+    pub size_bytes: u64,
+}
+
+/// Start of a file for the preview, text is None for binary files
+#[derive(Debug, Clone)]
+pub struct FilePreview {
+    pub text: Option<String>,
+    /// Only the first part of the file was read
+    pub truncated: bool,
+    pub size_bytes: u64,
+}
+
+/// A folder in the bookmark tree
+#[derive(Debug, Clone)]
+pub struct BookmarkFolder {
+    pub id: i32,
+    pub name: String,
+    /// None for top level folders
+    pub parent_id: Option<i32>,
+}
+
+/// What happens to the folders of an imported bookmarks file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportFolderMode {
+    /// Recreate the folder tree
+    #[default]
+    Folders,
+    /// File everything unfiled and tag each bookmark with its folder names
+    Tags,
+}
+
+impl ImportFolderMode {
+    pub const ALL: [ImportFolderMode; 2] = [ImportFolderMode::Folders, ImportFolderMode::Tags];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ImportFolderMode::Folders => "Keep as folders",
+            ImportFolderMode::Tags => "Turn into tags",
+        }
+    }
+}
+
+/// A bookmark read from a browser, before it is saved
+#[derive(Debug, Clone)]
+pub struct ImportedBookmark {
+    pub name: String,
+    pub location: String,
+    pub description: String,
+    /// Folder names from the top level down
+    pub folders: Vec<String>,
+    pub tags: Vec<String>,
+    /// Unix timestamp the browser recorded, if any
+    pub added_at: Option<i64>,
+}
+
+/// Outcome of a bookmark import
+#[derive(Debug, Clone, Default)]
+pub struct BookmarkImportReport {
+    pub imported: usize,
+    /// Already saved (same location) or repeated in the file
+    pub duplicates: usize,
+    /// Entries that aren't links Dry Dock can open (javascript:, place: queries, ...)
+    pub skipped: usize,
+    pub folders_created: usize,
+}
+
+/// Which bookmark store a browser profile uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserKind {
+    /// places.sqlite
+    Firefox,
+    /// Bookmarks JSON file (Chrome, Chromium, Brave, Edge, Vivaldi)
+    Chromium,
+}
+
+/// A browser profile found on this machine
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrowserProfile {
+    pub browser: String,
+    pub profile: String,
+    pub kind: BrowserKind,
+    /// The places.sqlite or Bookmarks file
+    pub path: std::path::PathBuf,
+}
+
+impl BrowserProfile {
+    pub fn label(&self) -> String {
+        format!("{} ({})", self.browser, self.profile)
+    }
 }
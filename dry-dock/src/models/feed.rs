@@ -3,20 +3,52 @@
 #[derive(Debug, Clone)]
 pub struct FeedItem {
     pub id: i32,
+    pub feed_id: i32,
+    /// Title of the feed the item came from
+    pub feed_title: String,
     pub title: String,
     pub link: String,
     pub description: String,
     pub pub_date: i64,
+    pub is_read: bool,
+    pub is_starred: bool,
 }
 
-impl FeedItem {
-    pub fn new(id: i32, title: String, link: String, description: String, pub_date: i64) -> Self {
-        Self {
-            id,
-            title,
-            link,
-            description,
-            pub_date,
-        }
-    }
+/// A feed subscription
+#[derive(Debug, Clone)]
+pub struct Feed {
+    pub id: i32,
+    pub url: String,
+    pub title: String,
+    /// "Tech / Rust" for nested OPML outlines, empty when uncategorized
+    pub category: String,
+    pub unread_count: i64,
+}
+
+/// Outcome of an OPML import
+#[derive(Debug, Clone, Default)]
+pub struct FeedImportReport {
+    /// (id, url) of the feeds that were subscribed to
+    pub added: Vec<(i32, String)>,
+    /// Already subscribed or listed twice in the file
+    pub duplicates: usize,
+}
+
+/// One page of the feed items FeedsScreen lists
+#[derive(Debug, Clone, Default)]
+pub struct FeedItemPage {
+    pub items: Vec<FeedItem>,
+    /// How many items match the filter over all pages
+    pub total: usize,
+    /// Whether more items follow the last one on this page
+    pub has_more: bool,
+}
+
+/// Which feed items FeedsScreen lists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FeedItemFilter {
+    /// Only items of this feed, None for every feed
+    pub feed_id: Option<i32>,
+    pub unread_only: bool,
+    pub starred_only: bool,
 }
@@ -4,9 +4,11 @@ pub mod note;
 pub mod feed;
 pub mod bookmark;
 pub mod chat_message;
+pub mod settings;
 
 pub use config::Config;
-pub use note::Note;
-pub use feed::FeedItem;
-pub use bookmark::Bookmark;
+pub use note::{Note, NoteSort, NoteFilter, NoteSyncConflict, ConflictResolution, NoteDraft, ExportFormat};
+pub use feed::{Feed, FeedImportReport, FeedItem, FeedItemFilter, FeedItemPage};
+pub use bookmark::{Bookmark, BookmarkFolder, BookmarkKind, BookmarkSort, BookmarkImportReport, BrowserKind, BrowserProfile, ImportFolderMode, ImportedBookmark, LinkCheck, LinkCheckReport, PageMetadata, BookmarkSnapshot, GitStatus, FileEntry, FilePreview};
 pub use chat_message::{ChatMessage, MessageRole};
+pub use settings::{NoteMirrorSettings, PublishSettings};
@@ -5,18 +5,130 @@ pub struct Note {
     pub id: i32,
     pub title: String,
     pub details: String,
+    pub notebook: String,
     pub created_at: i64,
     pub updated_at: Option<i64>,
+    pub is_pinned: bool,
+    pub is_archived: bool,
+    /// Locked notes only hold ciphertext in `details`
+    pub is_locked: bool,
+    /// Lowercase tags, e.g. `public` marks notes for the published site
+    pub tags: Vec<String>,
 }
 
 impl Note {
-    pub fn new(id: i32, title: String, details: String, created_at: i64, updated_at: Option<i64>) -> Self {
-        Self {
-            id,
-            title,
-            details,
-            created_at,
-            updated_at,
+    /// Notebook used when none is given
+    pub const DEFAULT_NOTEBOOK: &'static str = "Notes";
+
+    /// Last time the note changed (created_at until it is first updated)
+    pub fn last_modified(&self) -> i64 {
+        self.updated_at.unwrap_or(self.created_at)
+    }
+
+    /// Tags as typed in the note modals ("public, rust")
+    pub fn tags_text(&self) -> String {
+        self.tags.join(", ")
+    }
+}
+
+/// Sort options for the notes list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoteSort {
+    #[default]
+    PinnedFirst,
+    Created,
+    Updated,
+    Title,
+}
+
+impl NoteSort {
+    pub const ALL: [NoteSort; 4] = [NoteSort::PinnedFirst, NoteSort::Created, NoteSort::Updated, NoteSort::Title];
+
+    pub fn label(&self) -> &str {
+        match self {
+            NoteSort::PinnedFirst => "Pinned first",
+            NoteSort::Created => "Recently created",
+            NoteSort::Updated => "Recently updated",
+            NoteSort::Title => "Title (A-Z)",
+        }
+    }
+}
+
+/// Which notes the list view shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NoteFilter {
+    /// Everything that is not archived (archived notes still show up in search results)
+    #[default]
+    Active,
+    Pinned,
+    Archived,
+}
+
+impl NoteFilter {
+    pub const ALL: [NoteFilter; 3] = [NoteFilter::Active, NoteFilter::Pinned, NoteFilter::Archived];
+
+    pub fn label(&self) -> &str {
+        match self {
+            NoteFilter::Active => "Active",
+            NoteFilter::Pinned => "Pinned",
+            NoteFilter::Archived => "Archived",
+        }
+    }
+}
+
+/// A note that changed both in Dry Dock and in the markdown mirror since the last sync
+#[derive(Debug, Clone)]
+pub struct NoteSyncConflict {
+    pub note_id: i32,
+    pub title: String,
+    pub file_path: String,
+    pub note_content: String,
+    pub file_content: String,
+    pub detected_at: i64,
+}
+
+/// How to resolve a sync conflict
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictResolution {
+    KeepNote,
+    KeepFile,
+}
+
+/// Unsaved input from the create/update note modals
+#[derive(Debug, Clone)]
+pub struct NoteDraft {
+    pub id: i32,
+    /// None for a note that was never created
+    pub note_id: Option<i32>,
+    pub title: String,
+    pub details: String,
+    pub notebook: String,
+    pub tags: String,
+    pub updated_at: i64,
+}
+
+/// File formats notes can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    #[default]
+    Html,
+    Pdf,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Html, ExportFormat::Pdf];
+
+    pub fn label(&self) -> &str {
+        match self {
+            ExportFormat::Html => "HTML (print-ready)",
+            ExportFormat::Pdf => "PDF",
+        }
+    }
+
+    pub fn extension(&self) -> &str {
+        match self {
+            ExportFormat::Html => "html",
+            ExportFormat::Pdf => "pdf",
         }
     }
 }
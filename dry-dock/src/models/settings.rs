@@ -0,0 +1,20 @@
+// src/models/settings.rs
+
+/// Settings for mirroring notes to a folder of markdown files
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NoteMirrorSettings {
+    pub enabled: bool,
+    pub directory: String,
+}
+
+/// Settings for publishing tagged notes as a static website
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PublishSettings {
+    /// Notes with this tag are published
+    pub tag: String,
+    /// Folder the site is written to
+    pub directory: String,
+    pub site_title: String,
+    /// Address the site is deployed at, used for absolute links in the feed
+    pub base_url: String,
+}
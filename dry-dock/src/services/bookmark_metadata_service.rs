@@ -0,0 +1,176 @@
+// src/services/bookmark_metadata_service.rs
+
+// Fill in a new bookmark from the page it points at: the <title> (or og:title), the meta
+// description and the favicon. Favicons are cached per host as 32x32 PNGs so the
+// Bookmarks screen can show them without going to the network.
+
+use std::path::PathBuf;
+use crate::common::helper::get_cache_path;
+use crate::common::html::{decode_entities, find_tag_end, parse_tag};
+use crate::common::http::{content_type, read_body_limited};
+use crate::models::PageMetadata;
+
+/// Only the <head> matters, pages are read no further than this
+const MAX_PAGE_BYTES: usize = 512 * 1024;
+const MAX_FAVICON_BYTES: usize = 1024 * 1024;
+const FAVICON_SIZE: u32 = 32;
+
+/// Fetch a page and read its title and description, caching its favicon on the way
+pub async fn fetch_page_metadata(url: &str) -> Result<PageMetadata, String> {
+    let client = reqwest::Client::builder()
+        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
+        .redirect(reqwest::redirect::Policy::limited(10))
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch {}: {}", url, e))?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP error {} for {}", response.status(), url));
+    }
+
+    // A link to a PDF or a download has no <head> to read, don't pull it down
+    if let Some(mime) = content_type(&response)
+        && !matches!(mime.as_str(), "text/html" | "application/xhtml+xml")
+    {
+        return Err(format!("{} is not a web page ({})", url, mime));
+    }
+
+    let page_url = response.url().clone();
+    let (bytes, _) = read_body_limited(response, MAX_PAGE_BYTES)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", url, e))?;
+    let html = String::from_utf8_lossy(&bytes);
+    let head = read_head(&html);
+
+    // Favicons are a nice extra, a missing one is not an error
+    let mut icon_urls: Vec<reqwest::Url> = head.icon.iter().filter_map(|icon| page_url.join(icon).ok()).collect();
+    if let Ok(fallback) = page_url.join("/favicon.ico") {
+        icon_urls.push(fallback);
+    }
+    for icon_url in icon_urls {
+        if download_favicon(&client, &icon_url, page_url.host_str().unwrap_or_default()).await.is_some() {
+            break;
+        }
+    }
+
+    Ok(PageMetadata {
+        title: head.og_title.or(head.title).filter(|title| !title.is_empty()),
+        description: head.description.filter(|description| !description.is_empty()),
+    })
+}
+
+/// The cached favicon of a bookmark's host, if one has been downloaded
+pub fn favicon_path(location: &str) -> Option<PathBuf> {
+    let url = reqwest::Url::parse(location).ok()?;
+    let path = favicon_file(url.host_str()?);
+    path.is_file().then_some(path)
+}
+
+fn favicon_file(host: &str) -> PathBuf {
+    let name: String = host
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .collect();
+    get_cache_path("DryDock", "favicons").join(format!("{}.png", name))
+}
+
+/// Download an icon, shrink it and store it as the host's favicon. None if any step fails.
+async fn download_favicon(client: &reqwest::Client, icon_url: &reqwest::Url, host: &str) -> Option<()> {
+    if host.is_empty() {
+        return None;
+    }
+
+    let response = client.get(icon_url.clone()).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    // A cut off image is of no use, so an icon over the limit is skipped
+    let (bytes, truncated) = read_body_limited(response, MAX_FAVICON_BYTES).await.ok()?;
+    if truncated {
+        return None;
+    }
+
+    // SVG icons and anything else the image crate can't read are skipped
+    let image = image::load_from_memory(&bytes).ok()?;
+    let image = image.resize(FAVICON_SIZE, FAVICON_SIZE, image::imageops::FilterType::Triangle);
+    image.save_with_format(favicon_file(host), image::ImageFormat::Png).ok()
+}
+
+#[derive(Default)]
+struct PageHead {
+    title: Option<String>,
+    og_title: Option<String>,
+    description: Option<String>,
+    icon: Option<String>,
+}
+
+/// Pick the title, description and icon link out of a page's <head>
+fn read_head(html: &str) -> PageHead {
+    let mut head = PageHead::default();
+    let mut fallback_description = None;
+    let mut touch_icon = None;
+    let mut in_title = false;
+    let mut text = String::new();
+
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = find_tag_end(rest) else { break };
+        let (name, closing, attributes) = parse_tag(&rest[1..end]);
+        rest = &rest[end + 1..];
+        let attribute = |key: &str| attributes.get(key).map(|value| decode_entities(value).trim().to_string());
+
+        match (name.as_str(), closing) {
+            ("BODY", false) | ("HEAD", true) => break,
+            // Their contents may hold '<', skip to the closing tag
+            ("SCRIPT" | "STYLE", false) => {
+                let close = format!("</{}", name.to_ascii_lowercase());
+                rest = rest.to_ascii_lowercase().find(&close).map_or("", |index| &rest[index..]);
+            }
+            ("TITLE", false) => {
+                in_title = true;
+                text.clear();
+            }
+            ("TITLE", true) if in_title => {
+                in_title = false;
+                head.title = Some(decode_entities(text.split_whitespace().collect::<Vec<_>>().join(" ").as_str()));
+            }
+            ("META", false) => {
+                let key = attribute("PROPERTY").or_else(|| attribute("NAME")).unwrap_or_default().to_lowercase();
+                let Some(content) = attribute("CONTENT") else { continue };
+                match key.as_str() {
+                    "og:title" => head.og_title = Some(content),
+                    "og:description" => head.description = Some(content),
+                    "description" | "twitter:description" => fallback_description = fallback_description.or(Some(content)),
+                    _ => {}
+                }
+            }
+            ("LINK", false) => {
+                let rel = attribute("REL").unwrap_or_default().to_lowercase();
+                let Some(href) = attribute("HREF") else { continue };
+                if rel.split_whitespace().any(|part| part == "icon") {
+                    head.icon = head.icon.or(Some(href));
+                } else if rel.starts_with("apple-touch-icon") {
+                    touch_icon = touch_icon.or(Some(href));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    head.description = head.description.or(fallback_description);
+    head.icon = head.icon.or(touch_icon);
+    head
+}
@@ -0,0 +1,519 @@
+// src/services/bookmark_opener_service.rs
+
+// A bookmark's location can be more than a web page or a path: mail addresses, SSH hosts,
+// shell commands, applications and the custom schemes apps register (vscode://, obsidian://...).
+// Every kind has an opener that knows how to recognise, validate, display and open it.
+// The registry asks them in order, so a new kind is one more BookmarkOpener registered
+// before the catch-all file opener. Openers only look at the text: a path is a folder when it
+// ends with a separator, any other path is checked on disk by resolve_kind when it is shown.
+
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::OnceLock;
+use crate::models::BookmarkKind;
+use super::{log_service, settings_service};
+
+/// Knows how to handle one kind of bookmark location
+pub trait BookmarkOpener: Send + Sync {
+    fn kind(&self) -> BookmarkKind;
+
+    /// True when the location is of this opener's kind, decided from the text alone
+    fn matches(&self, location: &str) -> bool;
+
+    /// Err with a message for the user when the location can't be opened as typed
+    fn validate(&self, _location: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// The location as shown on a bookmark card
+    fn display(&self, location: &str) -> String {
+        location.to_string()
+    }
+
+    fn open(&self, location: &str) -> Result<(), String>;
+}
+
+/// The openers Dry Dock knows about, asked in registration order
+pub struct OpenerRegistry {
+    openers: Vec<Box<dyn BookmarkOpener>>,
+}
+
+impl Default for OpenerRegistry {
+    fn default() -> Self {
+        let mut registry = Self { openers: Vec::new() };
+        registry.register(Box::new(UrlOpener));
+        registry.register(Box::new(EmailOpener));
+        registry.register(Box::new(SshOpener));
+        registry.register(Box::new(CommandOpener));
+        registry.register(Box::new(ApplicationOpener));
+        registry.register(Box::new(CustomSchemeOpener));
+        registry.register(Box::new(FolderOpener));
+        registry
+    }
+}
+
+impl OpenerRegistry {
+    pub fn register(&mut self, opener: Box<dyn BookmarkOpener>) {
+        self.openers.push(opener);
+    }
+
+    /// The opener for a location, anything unclaimed is treated as a file path
+    pub fn find(&self, location: &str) -> &dyn BookmarkOpener {
+        self.openers
+            .iter()
+            .find(|opener| opener.matches(location))
+            .map_or(&FileOpener as &dyn BookmarkOpener, |opener| opener.as_ref())
+    }
+}
+
+static REGISTRY: OnceLock<OpenerRegistry> = OnceLock::new();
+
+pub fn registry() -> &'static OpenerRegistry {
+    REGISTRY.get_or_init(OpenerRegistry::default)
+}
+
+pub fn kind_of(location: &str) -> BookmarkKind {
+    registry().find(location.trim()).kind()
+}
+
+pub fn validate(location: &str) -> Result<(), String> {
+    registry().find(location.trim()).validate(location.trim())
+}
+
+/// Tell a folder from a file on disk, other kinds are returned as they are.
+/// Only for bookmarks being shown or opened, never for a whole list.
+pub fn resolve_kind(kind: BookmarkKind, location: &str) -> BookmarkKind {
+    if kind == BookmarkKind::File && expand_home(location.trim()).is_dir() {
+        BookmarkKind::Folder
+    } else {
+        kind
+    }
+}
+
+/// Open a bookmark location with the opener of its kind
+pub fn open(location: &str) -> Result<(), String> {
+    let opener = registry().find(location);
+    opener.open(location)?;
+    let kind = resolve_kind(opener.kind(), location);
+    log_service::add_log_entry("INFO", &format!("Opening {}: {}", kind.label().to_lowercase(), location));
+    Ok(())
+}
+
+/////
+/// Built-in openers
+/////
+struct UrlOpener;
+
+impl BookmarkOpener for UrlOpener {
+    fn kind(&self) -> BookmarkKind {
+        BookmarkKind::Url
+    }
+
+    fn matches(&self, location: &str) -> bool {
+        matches!(scheme(location).as_deref(), Some("http" | "https"))
+    }
+
+    fn validate(&self, location: &str) -> Result<(), String> {
+        match reqwest::Url::parse(location) {
+            Ok(url) if url.host_str().is_some() => Ok(()),
+            _ => Err(format!("'{}' is not a valid web address", location)),
+        }
+    }
+
+    fn open(&self, location: &str) -> Result<(), String> {
+        webbrowser::open(location).map_err(|e| format!("Failed to open URL: {}", e))
+    }
+}
+
+struct EmailOpener;
+
+impl BookmarkOpener for EmailOpener {
+    fn kind(&self) -> BookmarkKind {
+        BookmarkKind::Email
+    }
+
+    fn matches(&self, location: &str) -> bool {
+        scheme(location).as_deref() == Some("mailto")
+    }
+
+    fn validate(&self, location: &str) -> Result<(), String> {
+        if self.display(location).contains('@') {
+            Ok(())
+        } else {
+            Err("Email bookmarks look like mailto:someone@example.com".to_string())
+        }
+    }
+
+    /// mailto:someone@example.com?subject=Hi -> someone@example.com
+    fn display(&self, location: &str) -> String {
+        let address = &location["mailto:".len()..];
+        address.split('?').next().unwrap_or_default().to_string()
+    }
+
+    fn open(&self, location: &str) -> Result<(), String> {
+        opener::open(location).map_err(|e| format!("Failed to open mail client: {}", e))
+    }
+}
+
+struct SshOpener;
+
+impl SshOpener {
+    /// ssh://user@host:22 -> the arguments for ssh
+    fn ssh_args(location: &str) -> Result<Vec<String>, String> {
+        let url = reqwest::Url::parse(location).map_err(|e| format!("Invalid SSH address: {}", e))?;
+        let host = url.host_str().ok_or_else(|| "SSH bookmarks look like ssh://user@host:port".to_string())?;
+        // ssh would read them as options (-oProxyCommand=... runs a command)
+        if host.starts_with('-') || url.username().starts_with('-') {
+            return Err("SSH user and host can't start with '-'".to_string());
+        }
+
+        let mut args = Vec::new();
+        if let Some(port) = url.port() {
+            args.push("-p".to_string());
+            args.push(port.to_string());
+        }
+        // Everything after is the destination, never an option
+        args.push("--".to_string());
+        match url.username() {
+            "" => args.push(host.to_string()),
+            user => args.push(format!("{}@{}", user, host)),
+        }
+        Ok(args)
+    }
+}
+
+impl BookmarkOpener for SshOpener {
+    fn kind(&self) -> BookmarkKind {
+        BookmarkKind::Ssh
+    }
+
+    fn matches(&self, location: &str) -> bool {
+        scheme(location).as_deref() == Some("ssh")
+    }
+
+    fn validate(&self, location: &str) -> Result<(), String> {
+        Self::ssh_args(location).map(|_| ())
+    }
+
+    fn display(&self, location: &str) -> String {
+        let (_, address) = location.split_once(':').unwrap_or_default();
+        address.trim_matches('/').to_string()
+    }
+
+    fn open(&self, location: &str) -> Result<(), String> {
+        let args = Self::ssh_args(location)?;
+        // Terminal.app handles ssh:// itself
+        if cfg!(target_os = "macos") {
+            return opener::open(location).map_err(|e| format!("Failed to open SSH session: {}", e));
+        }
+
+        let mut command = vec!["ssh".to_string()];
+        command.extend(args);
+        open_in_terminal(&command)
+    }
+}
+
+struct CommandOpener;
+
+impl CommandOpener {
+    fn command(location: &str) -> &str {
+        location["cmd:".len()..].trim()
+    }
+}
+
+impl BookmarkOpener for CommandOpener {
+    fn kind(&self) -> BookmarkKind {
+        BookmarkKind::Command
+    }
+
+    fn matches(&self, location: &str) -> bool {
+        scheme(location).as_deref() == Some("cmd")
+    }
+
+    fn validate(&self, location: &str) -> Result<(), String> {
+        if Self::command(location).is_empty() {
+            return Err("Command bookmarks look like cmd: code ~/projects/dry-dock".to_string());
+        }
+        Ok(())
+    }
+
+    fn display(&self, location: &str) -> String {
+        format!("$ {}", Self::command(location))
+    }
+
+    /// Runs in the background from the home folder, the outcome goes to the log
+    fn open(&self, location: &str) -> Result<(), String> {
+        let command_line = Self::command(location).to_string();
+        let mut command = if cfg!(target_os = "windows") {
+            let mut command = Command::new("cmd");
+            command.args(["/C", &command_line]);
+            command
+        } else {
+            let mut command = Command::new("sh");
+            command.args(["-c", &command_line]);
+            command
+        };
+        if let Some(home) = dirs::home_dir() {
+            command.current_dir(home);
+        }
+
+        let child = command
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Failed to run command: {}", e))?;
+
+        std::thread::spawn(move || match child.wait_with_output() {
+            Ok(output) if output.status.success() => {
+                log_service::add_log_entry("INFO", &format!("Command finished: {}", command_line));
+            }
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                log_service::add_log_entry("ERROR", &format!(
+                    "Command '{}' failed ({}): {}",
+                    command_line, output.status, stderr.trim()
+                ));
+            }
+            Err(e) => log_service::add_log_entry("ERROR", &format!("Command '{}' failed: {}", command_line, e)),
+        });
+        Ok(())
+    }
+}
+
+struct ApplicationOpener;
+
+impl ApplicationOpener {
+    fn application(location: &str) -> &str {
+        location["app:".len()..].trim()
+    }
+}
+
+impl BookmarkOpener for ApplicationOpener {
+    fn kind(&self) -> BookmarkKind {
+        BookmarkKind::Application
+    }
+
+    fn matches(&self, location: &str) -> bool {
+        scheme(location).as_deref() == Some("app")
+    }
+
+    fn validate(&self, location: &str) -> Result<(), String> {
+        if Self::application(location).is_empty() {
+            return Err("Application bookmarks look like app: Firefox or app: /path/to/program".to_string());
+        }
+        Ok(())
+    }
+
+    fn display(&self, location: &str) -> String {
+        Self::application(location).to_string()
+    }
+
+    fn open(&self, location: &str) -> Result<(), String> {
+        let application = Self::application(location);
+        let path = expand_home(application);
+
+        let result = if cfg!(target_os = "macos") {
+            Command::new("open").arg("-a").arg(&path).spawn().map(|_| ())
+        } else if path.exists() {
+            Command::new(&path).spawn().map(|_| ())
+        } else if cfg!(target_os = "windows") {
+            Command::new("cmd").args(["/C", "start", "", application]).spawn().map(|_| ())
+        } else {
+            // A desktop entry name (firefox, org.gnome.Nautilus), or else a program on the PATH
+            match Command::new("gtk-launch").arg(application).status() {
+                Ok(status) if status.success() => Ok(()),
+                _ => Command::new(application).spawn().map(|_| ()),
+            }
+        };
+        result.map_err(|e| format!("Failed to launch {}: {}", application, e))
+    }
+}
+
+/// Schemes other apps registered with the OS: vscode://, obsidian://, slack://, zoommtg:...
+struct CustomSchemeOpener;
+
+impl BookmarkOpener for CustomSchemeOpener {
+    fn kind(&self) -> BookmarkKind {
+        BookmarkKind::CustomScheme
+    }
+
+    fn matches(&self, location: &str) -> bool {
+        scheme(location).is_some()
+    }
+
+    fn validate(&self, location: &str) -> Result<(), String> {
+        reqwest::Url::parse(location)
+            .map(|_| ())
+            .map_err(|e| format!("'{}' is not a valid link: {}", location, e))
+    }
+
+    fn open(&self, location: &str) -> Result<(), String> {
+        opener::open(location).map_err(|e| format!("No application could open {}: {}", location, e))
+    }
+}
+
+struct FolderOpener;
+
+impl BookmarkOpener for FolderOpener {
+    fn kind(&self) -> BookmarkKind {
+        BookmarkKind::Folder
+    }
+
+    /// ~/projects/ or C:\Users\me\ (without the separator it's left to resolve_kind)
+    fn matches(&self, location: &str) -> bool {
+        location == "~" || location.ends_with('/') || location.ends_with('\\')
+    }
+
+    fn validate(&self, location: &str) -> Result<(), String> {
+        validate_path(location)
+    }
+
+    fn open(&self, location: &str) -> Result<(), String> {
+        opener::open(expand_home(location)).map_err(|e| format!("Failed to open folder: {}", e))
+    }
+}
+
+/// Catch-all: whatever has no scheme is a path, a file or a folder
+struct FileOpener;
+
+impl BookmarkOpener for FileOpener {
+    fn kind(&self) -> BookmarkKind {
+        BookmarkKind::File
+    }
+
+    fn matches(&self, _location: &str) -> bool {
+        true
+    }
+
+    fn validate(&self, location: &str) -> Result<(), String> {
+        validate_path(location)
+    }
+
+    fn open(&self, location: &str) -> Result<(), String> {
+        let path = expand_home(location);
+        if !path.exists() {
+            return Err(format!("Path does not exist: {}", location));
+        }
+        opener::open(path).map_err(|e| format!("Failed to open file path: {}", e))
+    }
+}
+
+/////
+/// Helpers
+/////
+/// Lower case scheme of a location, None for paths. One letter "schemes" are Windows drives (C:\).
+fn scheme(location: &str) -> Option<String> {
+    let (scheme, _) = location.split_once(':')?;
+    let valid = scheme.len() > 1
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then(|| scheme.to_ascii_lowercase())
+}
+
+fn validate_path(location: &str) -> Result<(), String> {
+    if expand_home(location).is_absolute() {
+        Ok(())
+    } else {
+        Err(format!("'{}' is not a web address or a full file path", location))
+    }
+}
+
+/// ~/projects -> /home/me/projects
+pub fn expand_home(path: &str) -> PathBuf {
+    match path.strip_prefix("~/").zip(dirs::home_dir()) {
+        Some((rest, home)) => home.join(rest),
+        None if path == "~" => dirs::home_dir().unwrap_or_default(),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Run a command in a new terminal window
+fn open_in_terminal(command: &[String]) -> Result<(), String> {
+    if cfg!(target_os = "windows") {
+        return Command::new("cmd")
+            .args(["/C", "start", ""])
+            .args(command)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to open a terminal: {}", e));
+    }
+
+    for (terminal, flag) in terminals() {
+        match Command::new(&terminal).arg(flag).args(command).spawn() {
+            Ok(_) => return Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(format!("Failed to open {}: {}", terminal, e)),
+        }
+    }
+    Err("No terminal emulator found, set $TERMINAL to the one you use".to_string())
+}
+
+/// Open a terminal window with a shell in a folder
+pub fn open_terminal_at(location: &str) -> Result<(), String> {
+    let folder = expand_home(location);
+    if !folder.is_dir() {
+        return Err(format!("Folder does not exist: {}", location));
+    }
+
+    if cfg!(target_os = "macos") {
+        return Command::new("open")
+            .args(["-a", "Terminal"])
+            .arg(&folder)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to open a terminal: {}", e));
+    }
+    if cfg!(target_os = "windows") {
+        return Command::new("cmd")
+            .args(["/C", "start", "", "cmd"])
+            .current_dir(&folder)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to open a terminal: {}", e));
+    }
+
+    for (terminal, _) in terminals() {
+        match Command::new(&terminal).current_dir(&folder).spawn() {
+            Ok(_) => return Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(format!("Failed to open {}: {}", terminal, e)),
+        }
+    }
+    Err("No terminal emulator found, set $TERMINAL to the one you use".to_string())
+}
+
+/// Open a folder with the editor command from Settings ("code" unless changed)
+pub fn open_in_editor(location: &str) -> Result<(), String> {
+    let folder = expand_home(location);
+    if !folder.exists() {
+        return Err(format!("Path does not exist: {}", location));
+    }
+
+    let editor = settings_service::get_editor_command();
+    let mut parts = editor.split_whitespace();
+    let Some(program) = parts.next() else {
+        return Err("No editor set, choose one in Settings".to_string());
+    };
+
+    Command::new(program)
+        .args(parts)
+        .arg(&folder)
+        .current_dir(if folder.is_dir() { folder.as_path() } else { folder.parent().unwrap_or(&folder) })
+        .stdin(std::process::Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to run editor '{}': {}", editor, e))
+}
+
+/// Linux terminal emulators, $TERMINAL first, with the flag each uses to run a command
+fn terminals() -> Vec<(String, &'static str)> {
+    let mut terminals: Vec<(String, &str)> = Vec::new();
+    if let Ok(terminal) = std::env::var("TERMINAL") {
+        terminals.push((terminal, "-e"));
+    }
+    for (terminal, flag) in [("x-terminal-emulator", "-e"), ("gnome-terminal", "--"), ("konsole", "-e"), ("xfce4-terminal", "-x"), ("alacritty", "-e"), ("kitty", "--"), ("xterm", "-e")] {
+        terminals.push((terminal.to_string(), flag));
+    }
+    terminals
+}
@@ -5,42 +5,883 @@
 // These BookMarks can be URLS, file paths and maybe other things down the line.
 // With url and file path we own the folder or the actual file.
 
-use crate::dal::BookmarksRepository;
-use super::log_service;
+// Bookmarks can be filed into a tree of folders and carry tags (see tag_service).
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use crate::dal::{BookmarkFoldersRepository, BookmarksRepository};
+use crate::dal::repositories::bookmarks_repository::BookmarkRow;
+use crate::models::{Bookmark, BookmarkFolder, BookmarkKind, BookmarkSort, BookmarkImportReport, ImportFolderMode, ImportedBookmark, LinkCheck};
+use crate::common::fuzzy::fuzzy_score;
+use crate::common::html::{decode_entities, find_tag_end, parse_tag};
+use super::export_service::escape_html;
+use super::{bookmark_opener_service, log_service, snapshot_service, tag_service};
 
 /////
 /// BLL Functions for Bookmark Management
 /// As things get more complex we can add more logic here.
 /////
-pub fn add_new_bookmark(name: &str, path: &str) -> Result<(), String> {
-    BookmarksRepository::create(name, path)
+pub fn add_new_bookmark(name: &str, path: &str, description: &str, folder_id: Option<i32>, tags: &str) -> Result<(), String> {
+    let path = normalize_url(path);
+    validate_bookmark(name, &path)?;
+    let existing = fetch_all_bookmarks()?;
+    check_not_saved(&existing, &path, None)?;
+    for duplicate in DuplicateIndex::new(existing).find(&path, None) {
+        log_service::add_log_entry("WARNING", &format!(
+            "New bookmark {} looks like '{}' ({}), use Find Duplicates to merge them.",
+            path, duplicate.name, duplicate.path
+        ));
+    }
+    BookmarksRepository::create(name.trim(), &path, description.trim(), folder_id, &tag_service::normalize_tags(tags))
+}
+
+pub fn update_bookmark(id: i32, name: &str, path: &str, description: &str, folder_id: Option<i32>, tags: &str) -> Result<(), String> {
+    let path = normalize_url(path);
+    validate_bookmark(name, &path)?;
+    check_not_saved(&fetch_all_bookmarks()?, &path, Some(id))?;
+    BookmarksRepository::update(id, name.trim(), &path, description.trim(), folder_id, &tag_service::normalize_tags(tags))
 }
 
 pub fn delete_bookmark(id: i32) -> Result<(), String> {
+    // Snapshot rows go with the bookmark, so find their files first
+    snapshot_service::delete_snapshot_files(id);
     BookmarksRepository::delete(id)
 }
 
-pub fn fetch_all_bookmarks() -> Result<Vec<(i32, String, String, String)>, String> {
-    BookmarksRepository::get_all()
+pub fn get_bookmark(id: i32) -> Result<Bookmark, String> {
+    Ok(to_bookmark(BookmarksRepository::get_by_id(id)?))
 }
 
-pub fn open_bookmark_path(path: &str) {
-    // Are we opening a URL or a file path? (Down the line we can add more types)
-    if path.starts_with("http://") || path.starts_with("https://") {
-        // Open URL in default browser
-        if let Err(e) = webbrowser::open(path) {
-            log_service::add_log_entry("ERROR", &format!("Failed to open URL: {}", e));
-        } else {
-            log_service::add_log_entry("INFO", &format!("Opening URL: {}", path));
+pub fn fetch_all_bookmarks() -> Result<Vec<Bookmark>, String> {
+    let weights = recent_open_weights()?;
+    let mut bookmarks: Vec<Bookmark> = BookmarksRepository::get_all()?.into_iter().map(to_bookmark).collect();
+    for bookmark in &mut bookmarks {
+        if let Some(weights) = weights.get(&bookmark.id) {
+            bookmark.frecency = frecency(bookmark.open_count, weights);
+        }
+    }
+    Ok(bookmarks)
+}
+
+/// Bookmarks loaded by fetch_all_bookmarks fuzzy matching the query by name or location, best first.
+/// A match in the name counts double and frecency breaks ties, so an empty query lists the most used bookmarks.
+pub fn search_bookmarks(bookmarks: &[Bookmark], query: &str, limit: usize) -> Vec<Bookmark> {
+    let mut matches: Vec<(i64, &Bookmark)> = bookmarks
+        .iter()
+        .filter_map(|bookmark| {
+            let by_name = fuzzy_score(query, &bookmark.name).map(|score| score * 2);
+            let by_location = fuzzy_score(query, &bookmark.path);
+            let score = by_name.max(by_location)?;
+            Some((score, bookmark))
+        })
+        .collect();
+
+    matches.sort_by(|(a_score, a), (b_score, b)| {
+        b_score
+            .cmp(a_score)
+            .then_with(|| b.frecency.total_cmp(&a.frecency))
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    matches.into_iter().take(limit).map(|(_, bookmark)| bookmark.clone()).collect()
+}
+
+/// Sort bookmarks loaded by fetch_all_bookmarks, ties go by name
+pub fn sort_bookmarks(bookmarks: &mut [Bookmark], sort: BookmarkSort) {
+    bookmarks.sort_by(|a, b| {
+        let order = match sort {
+            BookmarkSort::Name => std::cmp::Ordering::Equal,
+            BookmarkSort::Frecency => b.frecency.total_cmp(&a.frecency),
+            BookmarkSort::RecentlyOpened => b.last_opened_at.cmp(&a.last_opened_at),
+            BookmarkSort::Newest => b.created_at.cmp(&a.created_at),
+        };
+        order.then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+}
+
+/// How many of the latest opens go into a bookmark's frecency, older ones aren't kept
+const FRECENCY_SAMPLES: i64 = 10;
+
+/// Age weight of the latest opens of every opened bookmark
+fn recent_open_weights() -> Result<HashMap<i32, Vec<f64>>, String> {
+    let now = chrono::Utc::now().timestamp();
+    let mut weights: HashMap<i32, Vec<f64>> = HashMap::new();
+    for (bookmark_id, opened_at) in BookmarksRepository::get_recent_opens(FRECENCY_SAMPLES)? {
+        let weight = match (now - opened_at) / (24 * 60 * 60) {
+            ..=3 => 100.0,
+            4..=14 => 70.0,
+            15..=31 => 50.0,
+            32..=90 => 30.0,
+            _ => 10.0,
+        };
+        weights.entry(bookmark_id).or_default().push(weight);
+    }
+    Ok(weights)
+}
+
+/// The way browsers rank their address bar: the latest opens weighted by age, averaged,
+/// and scaled by how often the bookmark was opened in total
+fn frecency(open_count: i64, weights: &[f64]) -> f64 {
+    if weights.is_empty() {
+        return 0.0;
+    }
+    let average = weights.iter().sum::<f64>() / weights.len() as f64;
+    average * open_count as f64
+}
+
+/// Move a bookmark into a folder (None = unfiled)
+pub fn move_bookmark(id: i32, folder_id: Option<i32>) -> Result<(), String> {
+    BookmarksRepository::set_folder(id, folder_id)
+}
+
+/////
+/// Folders
+/////
+pub fn get_folders() -> Result<Vec<BookmarkFolder>, String> {
+    Ok(BookmarkFoldersRepository::get_all()?
+        .into_iter()
+        .map(|(id, name, parent_id)| BookmarkFolder { id, name, parent_id })
+        .collect())
+}
+
+pub fn create_folder(name: &str, parent_id: Option<i32>) -> Result<i32, String> {
+    let name = name.trim();
+    validate_folder_name(name, parent_id, None)?;
+    BookmarkFoldersRepository::create(name, parent_id)
+}
+
+pub fn rename_folder(id: i32, name: &str) -> Result<(), String> {
+    let name = name.trim();
+    let parent_id = find_folder(id)?.parent_id;
+    validate_folder_name(name, parent_id, Some(id))?;
+    BookmarkFoldersRepository::rename(id, name)
+}
+
+/// Move a folder (and everything in it) under another folder, None = top level
+pub fn move_folder(id: i32, parent_id: Option<i32>) -> Result<(), String> {
+    // BLL: A folder can't end up inside itself
+    let folders = get_folders()?;
+    let mut ancestor = parent_id;
+    while let Some(ancestor_id) = ancestor {
+        if ancestor_id == id {
+            return Err("A folder can't be moved into itself".to_string());
+        }
+        ancestor = folders.iter().find(|folder| folder.id == ancestor_id).and_then(|folder| folder.parent_id);
+    }
+
+    let name = find_folder(id)?.name;
+    validate_folder_name(&name, parent_id, Some(id))?;
+    BookmarkFoldersRepository::set_parent(id, parent_id)
+}
+
+/// Delete a folder, its bookmarks and subfolders move up to the parent folder
+pub fn delete_folder(id: i32) -> Result<(), String> {
+    BookmarkFoldersRepository::delete(id)?;
+    log_service::add_log_entry("INFO", &format!("Bookmark folder {} deleted.", id));
+    Ok(())
+}
+
+/// Full "Parent / Child" path of every folder, sorted, for folder pickers
+pub fn folder_paths(folders: &[BookmarkFolder]) -> Vec<(i32, String)> {
+    let by_id: HashMap<i32, &BookmarkFolder> = folders.iter().map(|folder| (folder.id, folder)).collect();
+
+    let mut paths: Vec<(i32, String)> = folders
+        .iter()
+        .map(|folder| {
+            let mut parts = vec![folder.name.as_str()];
+            let mut parent = folder.parent_id;
+            // The depth guard stops a corrupted tree from looping forever
+            while let Some(parent_id) = parent
+                && parts.len() < 64
+            {
+                let Some(parent_folder) = by_id.get(&parent_id) else { break };
+                parts.push(parent_folder.name.as_str());
+                parent = parent_folder.parent_id;
+            }
+            parts.reverse();
+            (folder.id, parts.join(" / "))
+        })
+        .collect();
+
+    paths.sort_by_key(|(_, path)| path.to_lowercase());
+    paths
+}
+
+/////
+/// Tags
+/////
+/// Every tag in use and how many bookmarks carry it, sorted by tag
+pub fn count_tags(bookmarks: &[Bookmark]) -> Vec<(String, usize)> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for tag in bookmarks.iter().flat_map(|bookmark| &bookmark.tags) {
+        *counts.entry(tag.clone()).or_default() += 1;
+    }
+    counts.into_iter().collect()
+}
+
+/// Rename a tag on every bookmark, renaming onto an existing tag merges the two
+pub fn rename_tag(old: &str, new: &str) -> Result<(), String> {
+    let new = tag_service::normalize_tag(new);
+    if new.is_empty() {
+        return Err("Tag cannot be empty".to_string());
+    }
+    retag(old, Some(&new))
+}
+
+/// Remove a tag from every bookmark
+pub fn delete_tag(tag: &str) -> Result<(), String> {
+    retag(tag, None)
+}
+
+fn retag(old: &str, new: Option<&str>) -> Result<(), String> {
+    let old = tag_service::normalize_tag(old);
+    let updates: Vec<(i32, String)> = fetch_all_bookmarks()?
+        .into_iter()
+        .filter(|bookmark| bookmark.tags.contains(&old))
+        .map(|bookmark| {
+            let tags: Vec<&str> = bookmark.tags
+                .iter()
+                .map(|tag| if *tag == old { new.unwrap_or("") } else { tag.as_str() })
+                .collect();
+            (bookmark.id, tag_service::normalize_tags(&tags.join(",")))
+        })
+        .collect();
+
+    BookmarksRepository::set_tags_many(&updates)?;
+    log_service::add_log_entry("INFO", &format!("Updated tag '{}' on {} bookmark(s).", old, updates.len()));
+    Ok(())
+}
+
+/////
+/// URL normalization and duplicates
+/// Web addresses are stored cleaned up (see normalize_url). Near-duplicates that only differ in
+/// scheme, www., trailing slash, fragment or query order share a duplicate_key.
+/////
+/// Query parameters that only track where a click came from
+const TRACKING_PARAMS: [&str; 16] = [
+    "fbclid", "gclid", "gclsrc", "dclid", "msclkid", "yclid", "twclid", "igshid", "mc_cid", "mc_eid",
+    "_hsenc", "_hsmi", "mkt_tok", "ref_src", "oly_anon_id", "oly_enc_id",
+];
+
+/// Clean up a web address before it is stored: add the scheme to bare domains, lower case the
+/// host, drop the default port, tracking parameters and empty query/fragment.
+/// Anything that isn't a web address is returned trimmed.
+pub fn normalize_url(location: &str) -> String {
+    let location = location.trim();
+    let with_scheme = if looks_like_bare_domain(location) {
+        format!("https://{}", location)
+    } else {
+        location.to_string()
+    };
+    if bookmark_opener_service::kind_of(&with_scheme) != BookmarkKind::Url {
+        return location.to_string();
+    }
+
+    let Ok(mut url) = reqwest::Url::parse(&with_scheme) else {
+        return location.to_string();
+    };
+    let query = url.query().map(strip_tracking_params).filter(|query| !query.is_empty());
+    url.set_query(query.as_deref());
+    if url.fragment() == Some("") {
+        url.set_fragment(None);
+    }
+    url.to_string()
+}
+
+/// What two addresses have in common when they are the same page: no scheme or www., no trailing
+/// slash, no fragment (unless it is a #/ or #! app route) and sorted query parameters
+pub fn duplicate_key(location: &str) -> String {
+    let normalized = normalize_url(location);
+    let Ok(url) = reqwest::Url::parse(&normalized) else {
+        return normalized;
+    };
+    if !matches!(url.scheme(), "http" | "https") {
+        return normalized;
+    }
+
+    let host = url.host_str().unwrap_or_default();
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    let port = url.port().map(|port| format!(":{}", port)).unwrap_or_default();
+    let path = url.path().trim_end_matches('/');
+
+    let mut key = format!("{}{}{}", host, port, path);
+    if let Some(query) = url.query() {
+        let mut params: Vec<&str> = query.split('&').filter(|param| !param.is_empty()).collect();
+        params.sort_unstable();
+        key.push('?');
+        key.push_str(&params.join("&"));
+    }
+    if let Some(fragment) = url.fragment().filter(|fragment| fragment.starts_with('/') || fragment.starts_with('!')) {
+        key.push('#');
+        key.push_str(fragment);
+    }
+    key
+}
+
+/// Web bookmarks by duplicate_key, worked out once so many locations can be checked against them
+pub struct DuplicateIndex {
+    keys: Vec<(String, Bookmark)>,
+}
+
+impl DuplicateIndex {
+    /// Other kinds only have exact duplicates, check_not_saved refuses those
+    pub fn new(bookmarks: Vec<Bookmark>) -> Self {
+        let keys = bookmarks
+            .into_iter()
+            .filter(|bookmark| bookmark.kind == BookmarkKind::Url)
+            .map(|bookmark| (duplicate_key(&bookmark.path), bookmark))
+            .collect();
+        Self { keys }
+    }
+
+    pub fn load() -> Result<Self, String> {
+        Ok(Self::new(fetch_all_bookmarks()?))
+    }
+
+    /// Saved bookmarks that are probably the same page as `location`
+    pub fn find(&self, location: &str, exclude_id: Option<i32>) -> Vec<&Bookmark> {
+        let location = normalize_url(location);
+        if bookmark_opener_service::kind_of(&location) != BookmarkKind::Url {
+            return Vec::new();
+        }
+
+        let key = duplicate_key(&location);
+        self.keys
+            .iter()
+            .filter(|(bookmark_key, bookmark)| Some(bookmark.id) != exclude_id && *bookmark_key == key)
+            .map(|(_, bookmark)| bookmark)
+            .collect()
+    }
+}
+
+/// Groups of two or more bookmarks that point at the same page, biggest first
+pub fn find_duplicate_groups() -> Result<Vec<Vec<Bookmark>>, String> {
+    let mut groups: BTreeMap<String, Vec<Bookmark>> = BTreeMap::new();
+    for bookmark in fetch_all_bookmarks()? {
+        groups.entry(duplicate_key(&bookmark.path)).or_default().push(bookmark);
+    }
+
+    let mut groups: Vec<Vec<Bookmark>> = groups.into_values().filter(|group| group.len() > 1).collect();
+    for group in &mut groups {
+        // The one to keep by default first: most opened, then oldest
+        group.sort_by(|a, b| b.open_count.cmp(&a.open_count).then_with(|| a.created_at.cmp(&b.created_at)));
+    }
+    groups.sort_by_key(|group| std::cmp::Reverse(group.len()));
+    Ok(groups)
+}
+
+/// Merge duplicates into the bookmark to keep: it gets their tags, a description if it has none,
+/// their open history and snapshots, and its address is normalized. The others are deleted.
+pub fn merge_duplicates(keep_id: i32, remove_ids: &[i32]) -> Result<(), String> {
+    let bookmarks = fetch_all_bookmarks()?;
+    let keep = bookmarks
+        .iter()
+        .find(|bookmark| bookmark.id == keep_id)
+        .ok_or_else(|| format!("Bookmark {} not found", keep_id))?;
+    let removed: Vec<&Bookmark> = bookmarks.iter().filter(|bookmark| remove_ids.contains(&bookmark.id)).collect();
+
+    let mut tags = keep.tags.clone();
+    tags.extend(removed.iter().flat_map(|bookmark| bookmark.tags.iter().cloned()));
+    let description = match keep.description.is_empty() {
+        true => removed.iter().map(|bookmark| bookmark.description.as_str()).find(|description| !description.is_empty()).unwrap_or_default(),
+        false => keep.description.as_str(),
+    };
+
+    let removed_ids: Vec<i32> = removed.iter().map(|bookmark| bookmark.id).collect();
+    BookmarksRepository::merge(
+        keep_id,
+        &removed_ids,
+        &normalize_url(&keep.path),
+        description,
+        &tag_service::normalize_tags(&tags.join(",")),
+    )?;
+    log_service::add_log_entry("INFO", &format!("Merged {} duplicate(s) into '{}'.", removed_ids.len(), keep.name));
+    Ok(())
+}
+
+/// Bring web addresses saved before they were normalized into their stored form (run at startup).
+/// One that would clash with a bookmark already saved that way is left for Find Duplicates to merge.
+pub fn normalize_saved_locations() -> Result<usize, String> {
+    let bookmarks = BookmarksRepository::get_all()?;
+    let mut taken: HashSet<String> = bookmarks.iter().map(|(_, _, location, ..)| location.clone()).collect();
+
+    let mut updated = 0;
+    for (id, _, location, ..) in bookmarks {
+        // Only web addresses change, so startup never waits on the file system
+        let lower = location.to_ascii_lowercase();
+        if !lower.starts_with("http://") && !lower.starts_with("https://") {
+            continue;
+        }
+        let normalized = normalize_url(&location);
+        if normalized == location || taken.contains(&normalized) {
+            continue;
+        }
+        BookmarksRepository::set_location(id, &normalized)?;
+        taken.insert(normalized);
+        updated += 1;
+    }
+
+    if updated > 0 {
+        log_service::add_log_entry("INFO", &format!("Normalized the address of {} bookmark(s).", updated));
+    }
+    Ok(updated)
+}
+
+/// "example.com/page" typed without a scheme
+fn looks_like_bare_domain(location: &str) -> bool {
+    let host = location.split(['/', '?', '#']).next().unwrap_or_default();
+    !location.contains("://")
+        && !location.contains(char::is_whitespace)
+        && !host.starts_with(['.', '~'])
+        && !host.ends_with('.')
+        && host.contains('.')
+        && !host.contains('\\')
+        && host.split(':').nth(1).is_none_or(|port| port.chars().all(|c| c.is_ascii_digit()))
+        && !Path::new(location).exists()
+}
+
+/// Drop tracking parameters (utm_* and TRACKING_PARAMS) from a raw query, keeping the rest as written
+fn strip_tracking_params(query: &str) -> String {
+    query
+        .split('&')
+        .filter(|param| {
+            let name = param.split('=').next().unwrap_or_default().to_ascii_lowercase();
+            !param.is_empty() && !name.starts_with("utm_") && !TRACKING_PARAMS.contains(&name.as_str())
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// A location can only be saved once, compare the way it will be stored
+fn check_not_saved(bookmarks: &[Bookmark], location: &str, exclude_id: Option<i32>) -> Result<(), String> {
+    match bookmarks.iter().find(|bookmark| Some(bookmark.id) != exclude_id && bookmark.path == location) {
+        Some(existing) => Err(format!("{} is already bookmarked as '{}'", location, existing.name)),
+        None => Ok(()),
+    }
+}
+
+/////
+/// Import / Export
+/// Netscape bookmarks.html is what every browser exports and imports.
+/////
+/// Import a browser's bookmarks.html export
+pub fn import_netscape_html(path: &Path, mode: ImportFolderMode) -> Result<BookmarkImportReport, String> {
+    let html = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    let upper = html.to_ascii_uppercase();
+    if !upper.contains("NETSCAPE-BOOKMARK-FILE") && !upper.contains("<DT>") {
+        return Err(format!("{} is not a bookmarks HTML export", path.display()));
+    }
+
+    let report = import_bookmarks(parse_netscape_html(&html), mode)?;
+    log_service::add_log_entry("INFO", &format!(
+        "Imported {} bookmark(s) from {} ({} duplicate(s), {} skipped).",
+        report.imported, path.display(), report.duplicates, report.skipped
+    ));
+    Ok(report)
+}
+
+/// Save bookmarks read from a browser. Locations that are already saved are skipped,
+/// folders are matched by name so importing twice doesn't duplicate the tree.
+pub fn import_bookmarks(entries: Vec<ImportedBookmark>, mode: ImportFolderMode) -> Result<BookmarkImportReport, String> {
+    let mut report = BookmarkImportReport::default();
+    let mut seen: HashSet<String> = BookmarksRepository::get_all()?
+        .into_iter()
+        .map(|(_, _, location, ..)| duplicate_key(&location))
+        .collect();
+    let mut folders = get_folders()?;
+    let now = chrono::Utc::now().timestamp();
+
+    let mut rows = Vec::new();
+    for entry in entries {
+        let location = normalize_url(&url_to_location(entry.location.trim()));
+        let name = match entry.name.trim() {
+            "" => location.clone(),
+            name => name.to_string(),
+        };
+        if !is_importable(&location) || validate_bookmark(&name, &location).is_err() {
+            report.skipped += 1;
+            continue;
+        }
+        if !seen.insert(duplicate_key(&location)) {
+            report.duplicates += 1;
+            continue;
+        }
+
+        let mut tags = entry.tags;
+        let folder_id = match mode {
+            ImportFolderMode::Folders => ensure_folder_path(&entry.folders, &mut folders, &mut report)?,
+            ImportFolderMode::Tags => {
+                // "Bookmarks bar" becomes the single tag "bookmarks-bar"
+                tags.extend(entry.folders.iter().map(|folder| folder.split_whitespace().collect::<Vec<_>>().join("-")));
+                None
+            }
+        };
+
+        rows.push((name, location, entry.description.trim().to_string(), entry.added_at.unwrap_or(now), folder_id, tag_service::normalize_tags(&tags.join(","))));
+    }
+
+    let inserted = BookmarksRepository::create_many(&rows)?;
+    report.duplicates += rows.len() - inserted;
+    report.imported = inserted;
+    Ok(report)
+}
+
+/// Write every bookmark and folder to a bookmarks.html file browsers can import, returns the bookmark count
+pub fn export_netscape_html(path: &Path) -> Result<usize, String> {
+    let bookmarks = BookmarksRepository::get_all()?;
+    let folders = get_folders()?;
+
+    let mut html = String::from(
+        "<!DOCTYPE NETSCAPE-Bookmark-file-1>\n\
+         <!-- This is an automatically generated file.\n     It will be read and overwritten.\n     DO NOT EDIT! -->\n\
+         <META HTTP-EQUIV=\"Content-Type\" CONTENT=\"text/html; charset=UTF-8\">\n\
+         <TITLE>Bookmarks</TITLE>\n\
+         <H1>Bookmarks</H1>\n\
+         <DL><p>\n",
+    );
+    write_netscape_folder(&mut html, None, &folders, &bookmarks, 1);
+    html.push_str("</DL><p>\n");
+
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, html).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    log_service::add_log_entry("INFO", &format!("Exported {} bookmark(s) to {}.", bookmarks.len(), path.display()));
+    Ok(bookmarks.len())
+}
+
+fn write_netscape_folder(html: &mut String, parent_id: Option<i32>, folders: &[BookmarkFolder], bookmarks: &[BookmarkRow], depth: usize) {
+    let indent = "    ".repeat(depth);
+
+    // The depth guard stops a corrupted tree from looping forever
+    if depth < 64 {
+        for folder in folders.iter().filter(|folder| folder.parent_id == parent_id) {
+            html.push_str(&format!("{}<DT><H3>{}</H3>\n{}<DL><p>\n", indent, escape_html(&folder.name), indent));
+            write_netscape_folder(html, Some(folder.id), folders, bookmarks, depth + 1);
+            html.push_str(&format!("{}</DL><p>\n", indent));
         }
-    } else if std::path::Path::new(path).exists() {
-        // Open file/folder path
-        if let Err(e) = opener::open(path) {
-            log_service::add_log_entry("ERROR", &format!("Failed to open file path: {}", e));
+    }
+
+    for (_, name, location, created_at, _, tags, .., description, _, _) in bookmarks.iter().filter(|bookmark| bookmark.4 == parent_id) {
+        let tags = tag_service::split_tags(tags);
+        let tags_attribute = if tags.is_empty() {
+            String::new()
         } else {
-            log_service::add_log_entry("INFO", &format!("Opening file: {}", path));
+            format!(" TAGS=\"{}\"", escape_html(&tags.join(",")))
+        };
+        html.push_str(&format!(
+            "{}<DT><A HREF=\"{}\" ADD_DATE=\"{}\"{}>{}</A>\n",
+            indent,
+            escape_html(&location_to_url(location)),
+            created_at,
+            tags_attribute,
+            escape_html(name),
+        ));
+        if !description.is_empty() {
+            html.push_str(&format!("{}<DD>{}\n", indent, escape_html(description)));
+        }
+    }
+}
+
+/// Read the bookmarks out of a Netscape bookmarks.html. The format is loose HTML
+/// (unclosed <DT> and <p>), so rather than an HTML parser this walks the tags:
+/// <H3> names a folder, the <DL> after it holds its contents and <A> is a bookmark.
+fn parse_netscape_html(html: &str) -> Vec<ImportedBookmark> {
+    let mut bookmarks: Vec<ImportedBookmark> = Vec::new();
+    // One entry per open <DL>, None for lists that aren't a folder (the outermost one)
+    let mut lists: Vec<Option<String>> = Vec::new();
+    let mut pending_folder: Option<String> = None;
+    let mut link: Option<HashMap<String, String>> = None;
+    let mut in_folder_name = false;
+    // <DD> after a bookmark holds its description, up to the next tag
+    let mut in_description = false;
+    let mut text = String::new();
+
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // Skip comments whole, they may contain '<' and '>'
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = find_tag_end(rest) else { break };
+        let (name, closing, attributes) = parse_tag(&rest[1..end]);
+        rest = &rest[end + 1..];
+
+        if in_description {
+            in_description = false;
+            if let Some(bookmark) = bookmarks.last_mut() {
+                bookmark.description = decode_entities(text.trim());
+            }
+        }
+
+        match (name.as_str(), closing) {
+            ("H3", false) => {
+                in_folder_name = true;
+                text.clear();
+            }
+            ("H3", true) if in_folder_name => {
+                in_folder_name = false;
+                pending_folder = Some(decode_entities(text.trim()));
+            }
+            ("DL", false) => lists.push(pending_folder.take()),
+            ("DL", true) => {
+                lists.pop();
+            }
+            ("A", false) => {
+                link = Some(attributes);
+                text.clear();
+            }
+            ("DD", false) => {
+                in_description = true;
+                text.clear();
+            }
+            ("A", true) => {
+                if let Some(mut attributes) = link.take()
+                    && let Some(location) = attributes.remove("HREF")
+                {
+                    bookmarks.push(ImportedBookmark {
+                        name: decode_entities(text.trim()),
+                        location: decode_entities(&location),
+                        description: String::new(),
+                        folders: lists.iter().flatten().cloned().collect(),
+                        tags: attributes
+                            .get("TAGS")
+                            .map(|tags| decode_entities(tags).split(',').map(str::to_string).collect())
+                            .unwrap_or_default(),
+                        added_at: attributes.get("ADD_DATE").and_then(|date| parse_add_date(date)),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    bookmarks
+}
+
+/// ADD_DATE is in seconds, but some browsers write milliseconds or microseconds
+fn parse_add_date(value: &str) -> Option<i64> {
+    let mut timestamp: i64 = value.trim().parse().ok()?;
+    while timestamp > 100_000_000_000 {
+        timestamp /= 1000;
+    }
+    (timestamp > 0).then_some(timestamp)
+}
+
+/// Only web pages, files, folders and mail links are imported. Browser-internal links (javascript:
+/// bookmarklets, Firefox place: queries, ...) can't be opened from Dry Dock, and a cmd: or app: entry
+/// in a downloaded bookmarks.html would run a program with a single click.
+fn is_importable(location: &str) -> bool {
+    matches!(
+        bookmark_opener_service::kind_of(location),
+        BookmarkKind::Url | BookmarkKind::File | BookmarkKind::Folder | BookmarkKind::Email
+    )
+}
+
+/// Dry Dock stores local bookmarks as plain paths, browsers as file:// URLs
+fn url_to_location(url: &str) -> String {
+    match url.strip_prefix("file://") {
+        Some(path) => {
+            let path = percent_decode(path);
+            // file:///C:/Users -> C:/Users
+            match path.strip_prefix('/') {
+                Some(windows) if windows.get(1..2) == Some(":") => windows.to_string(),
+                _ => path,
+            }
         }
+        None => url.to_string(),
+    }
+}
+
+fn location_to_url(location: &str) -> String {
+    let path = Path::new(location);
+    if location.contains("://") || !path.is_absolute() {
+        return location.to_string();
+    }
+
+    let path = location.replace('\\', "/").replace('%', "%25").replace(' ', "%20");
+    if path.starts_with('/') {
+        format!("file://{}", path)
     } else {
-        log_service::add_log_entry("ERROR", &format!("Path does not exist: {}", path));
+        format!("file:///{}", path)
+    }
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%'
+            && let Some(byte) = text.get(index + 1..index + 3).and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            decoded.push(byte);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).to_string()
+}
+
+/// Folder for an imported bookmark, reusing folders with the same name and creating the rest
+fn ensure_folder_path(names: &[String], folders: &mut Vec<BookmarkFolder>, report: &mut BookmarkImportReport) -> Result<Option<i32>, String> {
+    let mut parent_id = None;
+    for name in names.iter().map(|name| name.trim()).filter(|name| !name.is_empty()) {
+        let existing = folders
+            .iter()
+            .find(|folder| folder.parent_id == parent_id && folder.name.eq_ignore_ascii_case(name))
+            .map(|folder| folder.id);
+
+        parent_id = Some(match existing {
+            Some(id) => id,
+            None => {
+                let id = BookmarkFoldersRepository::create(name, parent_id)?;
+                folders.push(BookmarkFolder { id, name: name.to_string(), parent_id });
+                report.folders_created += 1;
+                id
+            }
+        });
+    }
+    Ok(parent_id)
+}
+
+/// Open a bookmark with the opener registered for its kind (see bookmark_opener_service)
+/// and count the open for frecency
+pub fn open_bookmark_path(bookmark_id: i32, path: &str) {
+    if let Err(e) = bookmark_opener_service::open(path) {
+        log_service::add_log_entry("ERROR", &e);
+        return;
+    }
+    if let Err(e) = BookmarksRepository::record_open(bookmark_id, chrono::Utc::now().timestamp(), FRECENCY_SAMPLES) {
+        log_service::add_log_entry("WARNING", &format!("Failed to record bookmark open: {}", e));
+    }
+}
+
+fn validate_bookmark(name: &str, path: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Bookmark name cannot be empty".to_string());
+    }
+    if path.trim().is_empty() {
+        return Err("Bookmark location cannot be empty".to_string());
+    }
+    bookmark_opener_service::validate(path)
+}
+
+/// BLL: Folder names can't be blank or repeat within the same parent
+fn validate_folder_name(name: &str, parent_id: Option<i32>, folder_id: Option<i32>) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Folder name cannot be empty".to_string());
+    }
+
+    let taken = get_folders()?.iter().any(|folder| {
+        folder.parent_id == parent_id && Some(folder.id) != folder_id && folder.name.eq_ignore_ascii_case(name)
+    });
+    if taken {
+        return Err(format!("A folder named '{}' already exists there", name));
     }
-}
\ No newline at end of file
+    Ok(())
+}
+
+fn find_folder(id: i32) -> Result<BookmarkFolder, String> {
+    get_folders()?
+        .into_iter()
+        .find(|folder| folder.id == id)
+        .ok_or_else(|| format!("Folder {} not found", id))
+}
+
+/// Transform a repository tuple into a Bookmark model
+fn to_bookmark(row: BookmarkRow) -> Bookmark {
+    let (id, name, path, created_at, folder_id, tags, check_status, check_error, redirect_url, checked_at, description, open_count, last_opened_at) = row;
+    let created_at = chrono::DateTime::from_timestamp(created_at, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "Unknown".to_string());
+    // Text only, a whole list is loaded at once. Folders are told from files when a card is drawn.
+    let opener = bookmark_opener_service::registry().find(&path);
+
+    Bookmark {
+        id,
+        name,
+        kind: opener.kind(),
+        display: opener.display(&path),
+        path,
+        description,
+        created_at,
+        folder_id,
+        tags: tag_service::split_tags(&tags),
+        link_check: checked_at.map(|checked_at| LinkCheck {
+            status: check_status.and_then(|status| u16::try_from(status).ok()),
+            error: check_error,
+            redirect_url,
+            checked_at,
+        }),
+        open_count,
+        last_opened_at,
+        frecency: 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_url_cleans_up_web_addresses() {
+        assert_eq!(normalize_url("x.com/a?utm_source=news"), "https://x.com/a");
+        assert_eq!(normalize_url("  HTTPS://X.com:443/a/  "), "https://x.com/a/");
+        assert_eq!(normalize_url("https://x.com/a?b=2&fbclid=abc&a=1#"), "https://x.com/a?b=2&a=1");
+        assert_eq!(normalize_url("https://x.com/app#/inbox"), "https://x.com/app#/inbox");
+    }
+
+    #[test]
+    fn normalize_url_leaves_other_locations_alone() {
+        assert_eq!(normalize_url(" mailto:someone@example.com "), "mailto:someone@example.com");
+        assert_eq!(normalize_url("cmd: code ~/projects"), "cmd: code ~/projects");
+        assert_eq!(normalize_url("/home/user/notes.md"), "/home/user/notes.md");
+    }
+
+    #[test]
+    fn duplicate_key_matches_the_same_page() {
+        let key = duplicate_key("https://x.com/a/");
+        assert_eq!(duplicate_key("http://x.com/a"), key);
+        assert_eq!(duplicate_key("x.com/a?utm_source=news"), key);
+        assert_eq!(duplicate_key("https://www.x.com/a#top"), key);
+        assert_eq!(duplicate_key("https://x.com/a?b=2&a=1"), duplicate_key("https://x.com/a?a=1&b=2"));
+    }
+
+    #[test]
+    fn duplicate_key_keeps_different_pages_apart() {
+        assert_ne!(duplicate_key("https://x.com/a"), duplicate_key("https://x.com/b"));
+        assert_ne!(duplicate_key("https://x.com/a?id=1"), duplicate_key("https://x.com/a?id=2"));
+        assert_ne!(duplicate_key("https://x.com/app#/inbox"), duplicate_key("https://x.com/app#/sent"));
+        assert_ne!(duplicate_key("https://x.com:8080/a"), duplicate_key("https://x.com/a"));
+    }
+
+    #[test]
+    fn looks_like_bare_domain_only_accepts_hosts() {
+        assert!(looks_like_bare_domain("x.com/a"));
+        assert!(looks_like_bare_domain("localhost.dev:8080"));
+        assert!(!looks_like_bare_domain("https://x.com/a"));
+        assert!(!looks_like_bare_domain("~/notes.md"));
+        assert!(!looks_like_bare_domain("x.com/a b"));
+        assert!(!looks_like_bare_domain("x.com:port"));
+        assert!(!looks_like_bare_domain("README"));
+    }
+
+    #[test]
+    fn strip_tracking_params_keeps_the_rest_as_written() {
+        assert_eq!(strip_tracking_params("b=2&utm_source=news&UTM_Medium=mail&fbclid=abc&a=1"), "b=2&a=1");
+        assert_eq!(strip_tracking_params("utm_campaign=spring"), "");
+        assert_eq!(strip_tracking_params("q=rust&&page=2"), "q=rust&page=2");
+    }
+}
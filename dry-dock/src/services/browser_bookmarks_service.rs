@@ -0,0 +1,301 @@
+// src/services/browser_bookmarks_service.rs
+
+// Import bookmarks straight from the browsers installed on this machine, no HTML export needed.
+// Firefox keeps them in places.sqlite, Chromium based browsers in a "Bookmarks" JSON file.
+// Saving goes through bookmark_service::import_bookmarks so duplicates and folders are handled the same way.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use rusqlite::{Connection, OpenFlags};
+use crate::models::{BookmarkImportReport, BrowserKind, BrowserProfile, ImportFolderMode, ImportedBookmark};
+use super::{bookmark_service, log_service, settings_service};
+
+/// Chromium counts microseconds from 1601-01-01
+const CHROMIUM_EPOCH_OFFSET: i64 = 11_644_473_600;
+
+/// Where each Chromium based browser keeps its profiles, relative to the config dir (Linux, macOS)
+/// or the local data dir (Windows)
+const CHROMIUM_BROWSERS: [(&str, [&str; 3]); 5] = [
+    ("Chrome", ["google-chrome", "Google/Chrome", "Google/Chrome/User Data"]),
+    ("Chromium", ["chromium", "Chromium", "Chromium/User Data"]),
+    ("Brave", ["BraveSoftware/Brave-Browser", "BraveSoftware/Brave-Browser", "BraveSoftware/Brave-Browser/User Data"]),
+    ("Edge", ["microsoft-edge", "Microsoft Edge", "Microsoft/Edge/User Data"]),
+    ("Vivaldi", ["vivaldi", "Vivaldi", "Vivaldi/User Data"]),
+];
+
+/// Every Firefox and Chromium profile with a bookmark store
+pub fn detect_profiles() -> Vec<BrowserProfile> {
+    let mut profiles = firefox_profiles();
+    profiles.extend(chromium_profiles());
+    profiles
+}
+
+/// Import a profile's bookmarks. With `resync` only bookmarks added after the previous import are read.
+pub fn import_profile(profile: &BrowserProfile, mode: ImportFolderMode, resync: bool) -> Result<BookmarkImportReport, String> {
+    let mark_key = profile.path.to_string_lossy().to_string();
+    let previous_mark = settings_service::get_bookmark_import_mark(&mark_key);
+
+    let mut entries = match profile.kind {
+        BrowserKind::Firefox => read_firefox(&profile.path)?,
+        BrowserKind::Chromium => read_chromium(&profile.path)?,
+    };
+    let newest = entries.iter().filter_map(|entry| entry.added_at).max();
+
+    if resync && let Some(mark) = previous_mark {
+        entries.retain(|entry| entry.added_at.is_none_or(|added_at| added_at > mark));
+    }
+
+    let report = bookmark_service::import_bookmarks(entries, mode)?;
+
+    if let Some(newest) = newest.max(previous_mark) {
+        settings_service::save_bookmark_import_mark(&mark_key, newest)?;
+    }
+    log_service::add_log_entry("INFO", &format!(
+        "Imported {} bookmark(s) from {} ({} duplicate(s), {} skipped).",
+        report.imported, profile.label(), report.duplicates, report.skipped
+    ));
+    Ok(report)
+}
+
+/// When bookmarks were last imported from a profile (the newest date added seen then)
+pub fn last_import(profile: &BrowserProfile) -> Option<i64> {
+    settings_service::get_bookmark_import_mark(&profile.path.to_string_lossy())
+}
+
+fn firefox_profiles() -> Vec<BrowserProfile> {
+    let home = dirs::home_dir().unwrap_or_default();
+    let config = dirs::config_dir().unwrap_or_default();
+    let roots = [
+        home.join(".mozilla/firefox"),
+        home.join("snap/firefox/common/.mozilla/firefox"),
+        home.join(".var/app/org.mozilla.firefox/.mozilla/firefox"),
+        // macOS (Application Support) and Windows (AppData\Roaming)
+        config.join("Firefox/Profiles"),
+        config.join("Mozilla/Firefox/Profiles"),
+    ];
+
+    let mut profiles = Vec::new();
+    for root in roots {
+        for dir in subdirectories(&root) {
+            let path = dir.join("places.sqlite");
+            if !path.is_file() {
+                continue;
+            }
+            // "x8c2k1.default-release" -> "default-release"
+            let dir_name = dir.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            let profile = dir_name.split_once('.').map_or(dir_name.clone(), |(_, name)| name.to_string());
+            profiles.push(BrowserProfile { browser: "Firefox".to_string(), profile, kind: BrowserKind::Firefox, path });
+        }
+    }
+    profiles
+}
+
+fn chromium_profiles() -> Vec<BrowserProfile> {
+    let bases: Vec<PathBuf> = [dirs::config_dir(), dirs::data_local_dir()].into_iter().flatten().collect();
+
+    let mut profiles: Vec<BrowserProfile> = Vec::new();
+    for (browser, relative_paths) in CHROMIUM_BROWSERS {
+        for user_data in bases.iter().flat_map(|base| relative_paths.iter().map(move |relative| base.join(relative))) {
+            let names = chromium_profile_names(&user_data);
+            for dir in subdirectories(&user_data) {
+                let path = dir.join("Bookmarks");
+                if !path.is_file() || profiles.iter().any(|profile| profile.path == path) {
+                    continue;
+                }
+                let dir_name = dir.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+                let profile = names.get(&dir_name).cloned().unwrap_or(dir_name);
+                profiles.push(BrowserProfile { browser: browser.to_string(), profile, kind: BrowserKind::Chromium, path });
+            }
+        }
+    }
+    profiles
+}
+
+/// Profile directory -> the name the user gave it, from "Local State"
+fn chromium_profile_names(user_data: &Path) -> HashMap<String, String> {
+    let Ok(text) = std::fs::read_to_string(user_data.join("Local State")) else { return HashMap::new() };
+    let Ok(state) = serde_json::from_str::<serde_json::Value>(&text) else { return HashMap::new() };
+
+    state["profile"]["info_cache"]
+        .as_object()
+        .map(|cache| {
+            cache
+                .iter()
+                .filter_map(|(dir, info)| Some((dir.clone(), info["name"].as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn subdirectories(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let mut dirs: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).filter(|path| path.is_dir()).collect();
+    dirs.sort();
+    dirs
+}
+
+/// Read places.sqlite. Firefox keeps the database locked and recent changes in the -wal file,
+/// so both are copied to a temp folder and the copy is opened read-only.
+fn read_firefox(places: &Path) -> Result<Vec<ImportedBookmark>, String> {
+    let temp = std::env::temp_dir().join(format!("dry-dock-firefox-import-{}", std::process::id()));
+    std::fs::create_dir_all(&temp).map_err(|e| format!("Failed to create temp folder: {}", e))?;
+
+    let result = (|| {
+        let copy = temp.join("places.sqlite");
+        std::fs::copy(places, &copy).map_err(|e| format!("Failed to copy {}: {}", places.display(), e))?;
+        let wal = places.with_file_name("places.sqlite-wal");
+        if wal.is_file() {
+            std::fs::copy(&wal, temp.join("places.sqlite-wal")).map_err(|e| format!("Failed to copy {}: {}", wal.display(), e))?;
+        }
+
+        let conn = Connection::open_with_flags(&copy, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| format!("Failed to open Firefox bookmarks: {}", e))?;
+        read_places(&conn)
+    })();
+
+    let _ = std::fs::remove_dir_all(&temp);
+    result
+}
+
+fn read_places(conn: &Connection) -> Result<Vec<ImportedBookmark>, String> {
+    // type 1 = bookmark, 2 = folder; fk points at the URL in moz_places
+    let mut stmt = conn
+        .prepare(
+            "SELECT b.id, b.type, b.parent, b.title, b.guid, b.dateAdded, p.url
+             FROM moz_bookmarks b LEFT JOIN moz_places p ON p.id = b.fk
+             ORDER BY b.parent, b.position",
+        )
+        .map_err(|e| format!("Failed to read Firefox bookmarks: {}", e))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+                row.get::<_, Option<String>>(4)?.unwrap_or_default(),
+                row.get::<_, Option<i64>>(5)?,
+                row.get::<_, Option<String>>(6)?,
+            ))
+        })
+        .map_err(|e| format!("Failed to read Firefox bookmarks: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to read Firefox bookmarks: {}", e))?;
+
+    // Folder id -> (name, parent). The built-in roots are stored as "menu", "toolbar", ...
+    let mut folders: HashMap<i64, (String, i64)> = HashMap::new();
+    let mut root_id = None;
+    let mut tags_root_id = None;
+    for (id, kind, parent, title, guid, _, _) in &rows {
+        if *kind != 2 {
+            continue;
+        }
+        let name = match guid.as_str() {
+            "root________" => {
+                root_id = Some(*id);
+                String::new()
+            }
+            "tags________" => {
+                tags_root_id = Some(*id);
+                String::new()
+            }
+            "menu________" => "Bookmarks Menu".to_string(),
+            "toolbar_____" => "Bookmarks Toolbar".to_string(),
+            "unfiled_____" => "Other Bookmarks".to_string(),
+            "mobile______" => "Mobile Bookmarks".to_string(),
+            _ => title.clone(),
+        };
+        folders.insert(*id, (name, *parent));
+    }
+
+    // Firefox tags are folders under the tags root holding a bookmark per tagged URL
+    let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+    let is_tag_folder = |parent: &i64| folders.get(parent).is_some_and(|(_, grandparent)| Some(*grandparent) == tags_root_id);
+    for (_, kind, parent, _, _, _, url) in &rows {
+        if *kind == 1
+            && let Some(url) = url
+            && is_tag_folder(parent)
+        {
+            tags.entry(url.clone()).or_default().push(folders[parent].0.clone());
+        }
+    }
+
+    let mut bookmarks = Vec::new();
+    for (_, kind, parent, title, _, date_added, url) in rows {
+        let Some(url) = url else { continue };
+        if kind != 1 || is_tag_folder(&parent) || Some(parent) == tags_root_id {
+            continue;
+        }
+
+        let mut path = Vec::new();
+        let mut folder = Some(parent);
+        // The depth guard stops a corrupted tree from looping forever
+        while let Some(id) = folder
+            && Some(id) != root_id
+            && path.len() < 64
+        {
+            let Some((name, grandparent)) = folders.get(&id) else { break };
+            path.push(name.clone());
+            folder = Some(*grandparent);
+        }
+        path.reverse();
+
+        bookmarks.push(ImportedBookmark {
+            name: title,
+            tags: tags.get(&url).cloned().unwrap_or_default(),
+            location: url,
+            description: String::new(),
+            folders: path,
+            // Microseconds
+            added_at: date_added.map(|date| date / 1_000_000),
+        });
+    }
+    Ok(bookmarks)
+}
+
+/// Read a Chromium "Bookmarks" file, every entry under "roots" is a folder tree
+fn read_chromium(path: &Path) -> Result<Vec<ImportedBookmark>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let json: serde_json::Value = serde_json::from_str(&text)
+        .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+    let mut bookmarks = Vec::new();
+    if let Some(roots) = json["roots"].as_object() {
+        for root in roots.values().filter(|root| root.is_object()) {
+            walk_chromium_node(root, &mut Vec::new(), &mut bookmarks);
+        }
+    }
+    Ok(bookmarks)
+}
+
+fn walk_chromium_node(node: &serde_json::Value, path: &mut Vec<String>, bookmarks: &mut Vec<ImportedBookmark>) {
+    let name = node["name"].as_str().unwrap_or_default().to_string();
+
+    match node["type"].as_str() {
+        Some("url") => {
+            let added_at = node["date_added"]
+                .as_str()
+                .and_then(|date| date.parse::<i64>().ok())
+                .map(|date| date / 1_000_000 - CHROMIUM_EPOCH_OFFSET)
+                .filter(|date| *date > 0);
+
+            bookmarks.push(ImportedBookmark {
+                name,
+                location: node["url"].as_str().unwrap_or_default().to_string(),
+                description: String::new(),
+                folders: path.clone(),
+                tags: Vec::new(),
+                added_at,
+            });
+        }
+        Some("folder") if path.len() < 64 => {
+            path.push(name);
+            for child in node["children"].as_array().into_iter().flatten() {
+                walk_chromium_node(child, path, bookmarks);
+            }
+            path.pop();
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,128 @@
+// src/services/draft_service.rs
+
+// Autosave for the create/update note modals.
+// A DraftSession lives in the modal and writes the typed text to the drafts table every few
+// seconds (and when the modal closes without saving or the app exits). The draft is removed
+// once the note is saved, anything left over is offered for restore.
+
+use std::time::{Duration, Instant};
+use crate::dal::DraftsRepository;
+use crate::dal::repositories::drafts_repository::DraftRow;
+use crate::models::NoteDraft;
+use super::log_service;
+
+/// How often a modal with unsaved changes writes its draft
+pub const DRAFT_SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Get every draft, newest first
+pub fn get_drafts() -> Result<Vec<NoteDraft>, String> {
+    Ok(DraftsRepository::get_all()?.into_iter().map(to_draft).collect())
+}
+
+/// Get a draft by ID
+pub fn get_draft(draft_id: i32) -> Result<NoteDraft, String> {
+    DraftsRepository::get_by_id(draft_id).map(to_draft)
+}
+
+/// Latest draft for a note, or for a new note when note_id is None
+pub fn find_draft(note_id: Option<i32>) -> Result<Option<NoteDraft>, String> {
+    Ok(DraftsRepository::get_latest_for_note(note_id)?.map(to_draft))
+}
+
+/// Throw a draft away
+pub fn discard_draft(draft_id: i32) -> Result<(), String> {
+    DraftsRepository::delete(draft_id)
+}
+
+/// Autosave state for one open note modal
+pub struct DraftSession {
+    note_id: Option<i32>,
+    draft_id: Option<i32>,
+    dirty: bool,
+    last_saved: Instant,
+}
+
+impl DraftSession {
+    /// Start a session for a new note (None) or an existing note
+    pub fn new(note_id: Option<i32>) -> Self {
+        Self {
+            note_id,
+            draft_id: None,
+            dirty: false,
+            last_saved: Instant::now(),
+        }
+    }
+
+    /// Continue writing to an existing draft
+    pub fn resume(&mut self, draft: &NoteDraft) {
+        self.draft_id = Some(draft.id);
+    }
+
+    /// Record that the input changed since the last save
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Save if something changed and DRAFT_SAVE_INTERVAL has passed
+    pub fn autosave(&mut self, title: &str, details: &str, notebook: &str, tags: &str) {
+        if self.dirty && self.last_saved.elapsed() >= DRAFT_SAVE_INTERVAL {
+            self.save(title, details, notebook, tags);
+        }
+    }
+
+    /// Save now if something changed (closing the modal, app exit)
+    pub fn save(&mut self, title: &str, details: &str, notebook: &str, tags: &str) {
+        if !self.dirty {
+            return;
+        }
+        self.dirty = false;
+        self.last_saved = Instant::now();
+
+        // Nothing typed yet, nothing worth keeping
+        if title.trim().is_empty() && details.trim().is_empty() && tags.trim().is_empty() {
+            self.discard();
+            return;
+        }
+
+        let result = match self.draft_id {
+            Some(draft_id) => DraftsRepository::update(draft_id, title, details, notebook, tags).and_then(|updated| {
+                // Discarded from the Drafts list while the modal was open
+                if updated { Ok(draft_id) } else { DraftsRepository::create(self.note_id, title, details, notebook, tags) }
+            }),
+            None => DraftsRepository::create(self.note_id, title, details, notebook, tags),
+        };
+
+        match result {
+            Ok(draft_id) => self.draft_id = Some(draft_id),
+            Err(e) => log_service::add_log_entry("ERROR", &format!("Failed to save draft: {}", e)),
+        }
+    }
+
+    /// Remove the draft (the note was saved)
+    pub fn discard(&mut self) {
+        self.dirty = false;
+        if let Some(draft_id) = self.draft_id.take()
+            && let Err(e) = DraftsRepository::delete(draft_id)
+        {
+            log_service::add_log_entry("ERROR", &format!("Failed to delete draft: {}", e));
+        }
+    }
+
+    /// Whether there are changes that were not written yet (used to schedule a repaint)
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+}
+
+fn to_draft(row: DraftRow) -> NoteDraft {
+    let (id, note_id, title, details, notebook, tags, updated_at) = row;
+    NoteDraft {
+        id,
+        note_id,
+        title,
+        details,
+        notebook,
+        tags,
+        updated_at,
+    }
+}
@@ -0,0 +1,606 @@
+// src/services/export_service.rs
+
+// Export notes for people who don't use Dry Dock.
+// HTML: one standalone file (inline CSS, local images embedded as data URIs) that also prints well.
+// PDF: laid out here and written with printpdf, no browser involved. Builtin PDF fonts are used,
+// so text is limited to the WinAnsi character set.
+// Both start with a table of contents built from the note titles and headings.
+
+use std::path::{Path, PathBuf};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use pulldown_cmark::{CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd, html};
+use crate::models::{ExportFormat, Note};
+use super::{log_service, note_mirror_service};
+
+/// Export notes to `directory/<title>.<ext>` and return the written path
+/// Locked notes are skipped, their content is never exported
+pub fn export_notes(notes: &[Note], title: &str, format: ExportFormat, directory: &Path) -> Result<PathBuf, String> {
+    let notes: Vec<&Note> = notes.iter().filter(|note| !note.is_locked).collect();
+    if notes.is_empty() {
+        return Err("Nothing to export (locked notes are never exported)".to_string());
+    }
+
+    std::fs::create_dir_all(directory)
+        .map_err(|e| format!("Failed to create folder '{}': {}", directory.display(), e))?;
+    let path = directory.join(format!("{}.{}", file_stem(title), format.extension()));
+
+    let bytes = match format {
+        ExportFormat::Html => render_html(&notes, title).into_bytes(),
+        ExportFormat::Pdf => render_pdf(&notes, title)?,
+    };
+
+    std::fs::write(&path, bytes)
+        .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+
+    log_service::add_log_entry("INFO", &format!("Exported {} note(s) to {}", notes.len(), path.display()));
+    Ok(path)
+}
+
+/// Markdown extensions used for exported and published notes
+pub fn markdown_options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS
+}
+
+/// Headings of a note as (level, text), in document order
+fn note_headings(note: &Note) -> Vec<(usize, String)> {
+    let mut headings = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+
+    for event in Parser::new_ext(&note.details, markdown_options()) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => current = Some((level as usize, String::new())),
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, heading)) = current.as_mut() {
+                    heading.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, heading)) = current.take() {
+                    headings.push((level, heading.trim().to_string()));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    headings
+}
+
+/// Note headings move one level down so the note title is the only top level heading
+pub fn shift_heading(level: HeadingLevel) -> HeadingLevel {
+    HeadingLevel::try_from(level as usize + 1).unwrap_or(HeadingLevel::H6)
+}
+
+/// Find a local image referenced by a note
+/// Relative paths are looked up next to the note's file in the markdown mirror
+pub fn resolve_image(note: &Note, dest: &str) -> Option<PathBuf> {
+    if dest.starts_with("http://") || dest.starts_with("https://") || dest.starts_with("data:") {
+        return None;
+    }
+
+    let path = PathBuf::from(dest.strip_prefix("file://").unwrap_or(dest));
+    if path.is_absolute() {
+        return path.is_file().then_some(path);
+    }
+
+    let root = note_mirror_service::mirror_root()?;
+    [root.join(&note.notebook).join(&path), root.join(&path)]
+        .into_iter()
+        .find(|candidate| candidate.is_file())
+}
+
+// ---------------------------------------------------------------------------------------------
+// HTML
+// ---------------------------------------------------------------------------------------------
+
+const HTML_STYLE: &str = "
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; line-height: 1.6; color: #1f2328; max-width: 800px; margin: 40px auto; padding: 0 24px; }
+h1, h2, h3, h4, h5, h6 { line-height: 1.25; margin-top: 1.5em; }
+h1 { border-bottom: 1px solid #d0d7de; padding-bottom: .3em; }
+.meta { color: #656d76; font-size: .9em; margin-top: -.5em; }
+.toc { border: 1px solid #d0d7de; border-radius: 6px; padding: 12px 24px; }
+.toc ul { list-style: none; padding-left: 0; }
+.toc a { color: #0969da; text-decoration: none; }
+pre { background: #f6f8fa; border-radius: 6px; padding: 12px; overflow-x: auto; }
+code { font-family: ui-monospace, Menlo, Consolas, monospace; font-size: .9em; }
+blockquote { color: #656d76; border-left: 4px solid #d0d7de; margin-left: 0; padding-left: 16px; }
+img { max-width: 100%; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #d0d7de; padding: 6px 12px; }
+@page { margin: 2cm; }
+@media print {
+    body { margin: 0; max-width: none; }
+    .note { page-break-before: always; }
+    .toc a { color: inherit; }
+}
+";
+
+fn render_html(notes: &[&Note], title: &str) -> String {
+    let mut toc = String::new();
+    let mut body = String::new();
+
+    for (note_index, note) in notes.iter().enumerate() {
+        let note_anchor = format!("note-{}", note_index + 1);
+        toc.push_str(&format!(
+            "<li><a href=\"#{}\"><strong>{}</strong></a></li>\n",
+            note_anchor,
+            escape_html(&note.title)
+        ));
+
+        let headings = note_headings(note);
+        for (heading_index, (level, text)) in headings.iter().enumerate() {
+            toc.push_str(&format!(
+                "<li style=\"margin-left: {}em\"><a href=\"#{}-{}\">{}</a></li>\n",
+                level,
+                note_anchor,
+                heading_index + 1,
+                escape_html(text)
+            ));
+        }
+
+        body.push_str(&format!("<section class=\"note\">\n<h1 id=\"{}\">{}</h1>\n", note_anchor, escape_html(&note.title)));
+        body.push_str(&format!("<p class=\"meta\">{} &middot; {}</p>\n", escape_html(&note.notebook), format_date(note.last_modified())));
+
+        // Give headings anchors for the TOC and embed local images
+        let mut heading_index = 0;
+        let events = Parser::new_ext(&note.details, markdown_options()).map(|event| match event {
+            Event::Start(Tag::Heading { level, classes, attrs, .. }) => {
+                heading_index += 1;
+                Event::Start(Tag::Heading {
+                    level: shift_heading(level),
+                    id: Some(CowStr::from(format!("{}-{}", note_anchor, heading_index))),
+                    classes,
+                    attrs,
+                })
+            }
+            Event::End(TagEnd::Heading(level)) => Event::End(TagEnd::Heading(shift_heading(level))),
+            Event::Start(Tag::Image { link_type, dest_url, title, id }) => {
+                let dest_url = image_data_uri(note, &dest_url).map(CowStr::from).unwrap_or(dest_url);
+                Event::Start(Tag::Image { link_type, dest_url, title, id })
+            }
+            event => event,
+        });
+        html::push_html(&mut body, events);
+        body.push_str("</section>\n");
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>{style}</style>\n</head>\n<body>\n\
+         <header>\n<h1>{title}</h1>\n<p class=\"meta\">Exported from Dry Dock on {date}</p>\n</header>\n\
+         <nav class=\"toc\">\n<h2>Contents</h2>\n<ul>\n{toc}</ul>\n</nav>\n{body}</body>\n</html>\n",
+        title = escape_html(title),
+        style = HTML_STYLE,
+        date = format_date(chrono::Utc::now().timestamp()),
+        toc = toc,
+        body = body,
+    )
+}
+
+/// Local image as a data URI so the HTML file stands on its own
+fn image_data_uri(note: &Note, dest: &str) -> Option<String> {
+    let path = resolve_image(note, dest)?;
+    let mime = match path.extension()?.to_string_lossy().to_lowercase().as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        _ => return None,
+    };
+    let bytes = std::fs::read(&path).ok()?;
+    Some(format!("data:{};base64,{}", mime, BASE64.encode(bytes)))
+}
+
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// ---------------------------------------------------------------------------------------------
+// PDF
+// ---------------------------------------------------------------------------------------------
+
+// A4 page in mm
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const PAGE_MARGIN: f32 = 20.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * PAGE_MARGIN;
+const PT_TO_MM: f32 = 0.3528;
+const BODY_SIZE: f32 = 11.0;
+const CODE_SIZE: f32 = 9.0;
+const TOC_SIZE: f32 = 11.0;
+/// Images wider than this are scaled down before embedding to keep the PDF small
+const MAX_IMAGE_PIXELS: u32 = 1600;
+
+#[derive(Clone, Copy)]
+enum PdfFont {
+    Regular,
+    Bold,
+    Italic,
+    Mono,
+}
+
+/// Something placed on a content page, y is measured from the top of the page
+enum Placed {
+    Text { page: usize, x: f32, y: f32, size: f32, font: PdfFont, text: String },
+    Image { page: usize, x: f32, y: f32, width: f32, height: f32, image: image::RgbImage },
+    Rule { page: usize, y: f32 },
+}
+
+/// Block level markdown content, inline formatting is flattened
+enum Block {
+    Heading(usize, String),
+    Paragraph(String),
+    ListItem(usize, String),
+    Quote(String),
+    Code(String),
+    Image(PathBuf, String),
+    Rule,
+}
+
+/// Simple top-to-bottom layout onto A4 pages
+struct PdfLayout {
+    placed: Vec<Placed>,
+    /// (level, text, content page) for the table of contents
+    toc: Vec<(usize, String, usize)>,
+    page: usize,
+    y: f32,
+}
+
+impl PdfLayout {
+    fn new() -> Self {
+        Self { placed: Vec::new(), toc: Vec::new(), page: 0, y: PAGE_MARGIN }
+    }
+
+    fn new_page(&mut self) {
+        self.page += 1;
+        self.y = PAGE_MARGIN;
+    }
+
+    /// Move to a new page if `height` doesn't fit on this one
+    fn ensure(&mut self, height: f32) {
+        if self.y + height > PAGE_HEIGHT - PAGE_MARGIN && self.y > PAGE_MARGIN {
+            self.new_page();
+        }
+    }
+
+    fn text(&mut self, text: &str, size: f32, font: PdfFont, indent: f32, space_after: f32) {
+        let line_height = size * PT_TO_MM * 1.4;
+        for line in wrap_text(text, size, font, CONTENT_WIDTH - indent) {
+            self.ensure(line_height);
+            self.y += line_height;
+            self.placed.push(Placed::Text {
+                page: self.page,
+                x: PAGE_MARGIN + indent,
+                y: self.y,
+                size,
+                font,
+                text: line,
+            });
+        }
+        self.y += space_after;
+    }
+
+    fn heading(&mut self, level: usize, text: &str) {
+        let size = match level {
+            0 => 20.0,
+            1 => 16.0,
+            2 => 14.0,
+            _ => 12.0,
+        };
+        // Keep the heading together with at least a couple of lines after it
+        self.ensure(size * PT_TO_MM * 1.4 + BODY_SIZE * PT_TO_MM * 3.0);
+        self.y += 3.0;
+        self.toc.push((level, text.to_string(), self.page));
+        self.text(text, size, PdfFont::Bold, 0.0, 2.0);
+    }
+
+    fn image(&mut self, path: &Path, alt: &str) {
+        let Ok(image) = image::open(path) else {
+            self.text(&format!("[image: {}]", alt), BODY_SIZE, PdfFont::Italic, 0.0, 2.0);
+            return;
+        };
+
+        let image = if image.width() > MAX_IMAGE_PIXELS {
+            image.resize(MAX_IMAGE_PIXELS, u32::MAX, image::imageops::FilterType::Triangle)
+        } else {
+            image
+        };
+        let image = image.to_rgb8();
+
+        // 96 dpi natural size, shrunk to fit the content area
+        let natural_width = image.width() as f32 * 25.4 / 96.0;
+        let natural_height = image.height() as f32 * 25.4 / 96.0;
+        let max_height = PAGE_HEIGHT - 2.0 * PAGE_MARGIN;
+        let scale = (CONTENT_WIDTH / natural_width).min(max_height / natural_height).min(1.0);
+        let (width, height) = (natural_width * scale, natural_height * scale);
+
+        self.ensure(height);
+        self.placed.push(Placed::Image { page: self.page, x: PAGE_MARGIN, y: self.y, width, height, image });
+        self.y += height + 4.0;
+    }
+
+    fn rule(&mut self) {
+        self.ensure(6.0);
+        self.y += 3.0;
+        self.placed.push(Placed::Rule { page: self.page, y: self.y });
+        self.y += 3.0;
+    }
+}
+
+/// Flatten a note's markdown into blocks
+fn note_blocks(note: &Note) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut text = String::new();
+    let mut list_depth = 0;
+    let mut in_quote = false;
+    let mut image: Option<PathBuf> = None;
+
+    for event in Parser::new_ext(&note.details, markdown_options()) {
+        match event {
+            Event::Start(Tag::Heading { .. }) | Event::Start(Tag::Paragraph) | Event::Start(Tag::CodeBlock(_)) => text.clear(),
+            Event::End(TagEnd::Heading(level)) => blocks.push(Block::Heading(level as usize, std::mem::take(&mut text))),
+            Event::End(TagEnd::Paragraph) => {
+                let paragraph = std::mem::take(&mut text);
+                if paragraph.trim().is_empty() {
+                    // Paragraph that only held an image
+                } else if list_depth > 0 {
+                    blocks.push(Block::ListItem(list_depth, paragraph));
+                } else if in_quote {
+                    blocks.push(Block::Quote(paragraph));
+                } else {
+                    blocks.push(Block::Paragraph(paragraph));
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => blocks.push(Block::Code(std::mem::take(&mut text))),
+            Event::Start(Tag::List(_)) => {
+                // Text of the parent item comes before its nested list
+                if list_depth > 0 && !text.trim().is_empty() {
+                    blocks.push(Block::ListItem(list_depth, std::mem::take(&mut text)));
+                }
+                list_depth += 1;
+            }
+            Event::End(TagEnd::List(_)) => list_depth -= 1,
+            Event::Start(Tag::Item) => text.clear(),
+            // Tight lists have no paragraph inside the item
+            Event::End(TagEnd::Item) if !text.trim().is_empty() => {
+                blocks.push(Block::ListItem(list_depth, std::mem::take(&mut text)));
+            }
+            Event::Start(Tag::BlockQuote(_)) => in_quote = true,
+            Event::End(TagEnd::BlockQuote(_)) => in_quote = false,
+            Event::Start(Tag::Image { dest_url, .. }) => {
+                image = resolve_image(note, &dest_url);
+                if image.is_none() {
+                    text.push_str(&format!("[image: {}]", dest_url));
+                }
+            }
+            Event::End(TagEnd::Image) => {
+                // Alt text was collected into `text`, the image goes on its own line
+                if let Some(path) = image.take() {
+                    blocks.push(Block::Image(path, std::mem::take(&mut text)));
+                }
+            }
+            Event::Text(value) | Event::Code(value) => text.push_str(&value),
+            Event::TaskListMarker(done) => text.push_str(if done { "[x] " } else { "[ ] " }),
+            Event::SoftBreak => text.push(' '),
+            Event::HardBreak => text.push('\n'),
+            Event::Rule => blocks.push(Block::Rule),
+            _ => {}
+        }
+    }
+
+    blocks
+}
+
+fn render_pdf(notes: &[&Note], title: &str) -> Result<Vec<u8>, String> {
+    use printpdf::{BuiltinFont, Color, ColorBits, ColorSpace, CustomPdfConformance, Image, ImageTransform, ImageXObject, Line, Mm, PdfConformance, PdfDocument, Point, Px, Rgb};
+
+    // Lay out the content first so the table of contents knows the page numbers
+    let mut layout = PdfLayout::new();
+    for (index, note) in notes.iter().enumerate() {
+        if index > 0 {
+            layout.new_page();
+        }
+        layout.heading(0, &note.title);
+        layout.text(&format!("{} - {}", note.notebook, format_date(note.last_modified())), 9.0, PdfFont::Italic, 0.0, 4.0);
+
+        for block in note_blocks(note) {
+            match block {
+                Block::Heading(level, text) => layout.heading(level, &text),
+                Block::Paragraph(text) => layout.text(&text, BODY_SIZE, PdfFont::Regular, 0.0, 3.0),
+                Block::ListItem(depth, text) => layout.text(&format!("- {}", text), BODY_SIZE, PdfFont::Regular, depth as f32 * 6.0, 1.0),
+                Block::Quote(text) => layout.text(&text, BODY_SIZE, PdfFont::Italic, 8.0, 3.0),
+                Block::Code(code) => {
+                    for line in code.trim_end_matches('\n').lines() {
+                        layout.text(&line.replace('\t', "    "), CODE_SIZE, PdfFont::Mono, 4.0, 0.0);
+                    }
+                    layout.y += 3.0;
+                }
+                Block::Image(path, alt) => layout.image(&path, &alt),
+                Block::Rule => layout.rule(),
+            }
+        }
+    }
+
+    let toc_line_height = TOC_SIZE * PT_TO_MM * 1.6;
+    let toc_per_page = ((PAGE_HEIGHT - 2.0 * PAGE_MARGIN - 20.0) / toc_line_height) as usize;
+    let toc_pages = layout.toc.len().div_ceil(toc_per_page).max(1);
+
+    // The default conformance embeds a large ICC profile, a plain document doesn't need it
+    let document = PdfDocument::empty(title).with_conformance(PdfConformance::Custom(CustomPdfConformance {
+        allows_default_fonts: true,
+        ..Default::default()
+    }));
+    let font = |builtin| document.add_builtin_font(builtin).map_err(|e| format!("Failed to load PDF font: {}", e));
+    let regular = font(BuiltinFont::Helvetica)?;
+    let bold = font(BuiltinFont::HelveticaBold)?;
+    let italic = font(BuiltinFont::HelveticaOblique)?;
+    let mono = font(BuiltinFont::Courier)?;
+    let font_ref = |font: PdfFont| match font {
+        PdfFont::Regular => &regular,
+        PdfFont::Bold => &bold,
+        PdfFont::Italic => &italic,
+        PdfFont::Mono => &mono,
+    };
+
+    // Table of contents pages
+    let mut toc_chunks: Vec<&[(usize, String, usize)]> = layout.toc.chunks(toc_per_page.max(1)).collect();
+    if toc_chunks.is_empty() {
+        toc_chunks.push(&[]);
+    }
+    for (toc_page, entries) in toc_chunks.into_iter().enumerate() {
+        let (page, page_layer) = document.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Contents");
+        let layer = document.get_page(page).get_layer(page_layer);
+        let mut y = PAGE_MARGIN + 10.0;
+
+        if toc_page == 0 {
+            layer.use_text(title, 22.0, Mm(PAGE_MARGIN), Mm(PAGE_HEIGHT - y), &bold);
+            y += 12.0;
+        }
+
+        for (level, text, content_page) in entries {
+            let page_number = (content_page + toc_pages + 1).to_string();
+            let indent = *level as f32 * 6.0;
+            let font = if *level == 0 { &bold } else { &regular };
+            let max_width = CONTENT_WIDTH - indent - 15.0;
+            let text = wrap_text(text, TOC_SIZE, PdfFont::Regular, max_width).into_iter().next().unwrap_or_default();
+
+            layer.use_text(text, TOC_SIZE, Mm(PAGE_MARGIN + indent), Mm(PAGE_HEIGHT - y), font);
+            let number_x = PAGE_MARGIN + CONTENT_WIDTH - text_width(&page_number, TOC_SIZE, PdfFont::Regular);
+            layer.use_text(page_number, TOC_SIZE, Mm(number_x), Mm(PAGE_HEIGHT - y), &regular);
+            y += toc_line_height;
+        }
+    }
+
+    // Content pages
+    let mut layers = Vec::new();
+    for _ in 0..=layout.page {
+        let (page, page_layer) = document.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Content");
+        layers.push(document.get_page(page).get_layer(page_layer));
+    }
+
+    for placed in layout.placed {
+        match placed {
+            Placed::Text { page, x, y, size, font, text } => {
+                layers[page].use_text(text, size, Mm(x), Mm(PAGE_HEIGHT - y), font_ref(font));
+            }
+            Placed::Image { page, x, y, width, height, image } => {
+                let (pixels_wide, pixels_high) = (image.width() as usize, image.height() as usize);
+                let natural_width = pixels_wide as f32 * 25.4 / 96.0;
+                let natural_height = pixels_high as f32 * 25.4 / 96.0;
+                Image::from(ImageXObject {
+                    width: Px(pixels_wide),
+                    height: Px(pixels_high),
+                    color_space: ColorSpace::Rgb,
+                    bits_per_component: ColorBits::Bit8,
+                    interpolate: true,
+                    image_data: image.into_raw(),
+                    image_filter: None,
+                    smask: None,
+                    clipping_bbox: None,
+                })
+                .add_to_layer(layers[page].clone(), ImageTransform {
+                    translate_x: Some(Mm(x)),
+                    translate_y: Some(Mm(PAGE_HEIGHT - y - height)),
+                    scale_x: Some(width / natural_width),
+                    scale_y: Some(height / natural_height),
+                    dpi: Some(96.0),
+                    ..Default::default()
+                });
+            }
+            Placed::Rule { page, y } => {
+                layers[page].set_outline_color(Color::Rgb(Rgb::new(0.8, 0.8, 0.8, None)));
+                layers[page].set_outline_thickness(0.5);
+                layers[page].add_line(Line {
+                    points: vec![
+                        (Point::new(Mm(PAGE_MARGIN), Mm(PAGE_HEIGHT - y)), false),
+                        (Point::new(Mm(PAGE_MARGIN + CONTENT_WIDTH), Mm(PAGE_HEIGHT - y)), false),
+                    ],
+                    is_closed: false,
+                });
+            }
+        }
+    }
+
+    document.save_to_bytes().map_err(|e| format!("Failed to write PDF: {}", e))
+}
+
+/// Approximate width in mm of text in a builtin font (Courier is exact, Helvetica is close enough)
+fn text_width(text: &str, size: f32, font: PdfFont) -> f32 {
+    let em: f32 = match font {
+        PdfFont::Mono => text.chars().count() as f32 * 0.6,
+        _ => text
+            .chars()
+            .map(|c| match c {
+                'i' | 'j' | 'l' | '.' | ',' | ':' | ';' | '\'' | '|' | '!' | ' ' => 0.28,
+                'f' | 't' | 'r' | 'I' | '(' | ')' | '[' | ']' | '-' => 0.35,
+                'm' | 'w' | 'M' | 'W' | '@' => 0.85,
+                'A'..='Z' => 0.68,
+                _ => 0.56,
+            })
+            .sum(),
+    };
+    em * size * PT_TO_MM
+}
+
+/// Word wrap text to a width in mm, words longer than a line are broken up
+fn wrap_text(text: &str, size: f32, font: PdfFont, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    // Code keeps its spacing and is cut at the line width instead of between words
+    if matches!(font, PdfFont::Mono) {
+        let per_line = ((max_width / text_width("m", size, font)) as usize).max(1);
+        let chars: Vec<char> = text.chars().collect();
+        for chunk in chars.chunks(per_line) {
+            lines.push(chunk.iter().collect());
+        }
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        return lines;
+    }
+
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+            if text_width(&candidate, size, font) <= max_width {
+                line = candidate;
+                continue;
+            }
+
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            for c in word.chars() {
+                line.push(c);
+                if text_width(&line, size, font) > max_width {
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, c.to_string()));
+                }
+            }
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// File name for an export, keeps it readable but safe on every platform
+fn file_stem(title: &str) -> String {
+    let stem: String = title
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == ' ' || c == '-' || c == '_' { c } else { '-' })
+        .collect();
+    let stem = stem.trim().to_string();
+    if stem.is_empty() { "Dry Dock Export".to_string() } else { stem }
+}
+
+pub fn format_date(timestamp: i64) -> String {
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.with_timezone(&chrono::Local).format("%B %d, %Y").to_string())
+        .unwrap_or_default()
+}
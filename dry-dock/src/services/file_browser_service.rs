@@ -0,0 +1,91 @@
+// src/services/file_browser_service.rs
+
+// Folder bookmarks can be browsed without leaving Dry Dock: BookmarksScreen lists one folder at a
+// time as it is expanded, text files get a preview, anything else is handed to the default app.
+
+use std::io::Read;
+use std::path::Path;
+use crate::models::{FileEntry, FilePreview};
+use super::{bookmark_service, log_service};
+
+/// Only the start of big files is previewed
+const MAX_PREVIEW_BYTES: u64 = 256 * 1024;
+
+/// Files and subfolders of a folder, subfolders first, each group by name.
+/// Hidden entries (.git, .env...) are left out like file managers do.
+pub fn list_folder(folder: &Path) -> Result<Vec<FileEntry>, String> {
+    let read_dir = std::fs::read_dir(folder).map_err(|e| format!("Failed to read folder: {}", e))?;
+
+    let mut entries: Vec<FileEntry> = read_dir
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') {
+                return None;
+            }
+            // Follow symlinks so a linked folder can be expanded like any other
+            let metadata = std::fs::metadata(entry.path()).ok()?;
+            Some(FileEntry {
+                name,
+                path: entry.path(),
+                is_dir: metadata.is_dir(),
+                size_bytes: if metadata.is_dir() { 0 } else { metadata.len() },
+            })
+        })
+        .collect();
+
+    entries.sort_by_cached_key(|entry| (!entry.is_dir, entry.name.to_lowercase()));
+    Ok(entries)
+}
+
+/// Read the start of a file for the preview
+pub fn read_preview(path: &Path) -> Result<FilePreview, String> {
+    let file = std::fs::File::open(path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let size_bytes = file.metadata().map_err(|e| format!("Failed to read file: {}", e))?.len();
+
+    let mut bytes = Vec::new();
+    file.take(MAX_PREVIEW_BYTES)
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+
+    // A NUL byte near the start is the usual sign of a binary file
+    let text = (!bytes.iter().take(8000).any(|byte| *byte == 0))
+        .then(|| String::from_utf8_lossy(&bytes).to_string());
+
+    Ok(FilePreview { text, truncated: size_bytes > MAX_PREVIEW_BYTES, size_bytes })
+}
+
+/// Markdown files are previewed as text with their code blocks highlighted
+pub fn is_markdown(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| matches!(extension.to_ascii_lowercase().as_str(), "md" | "markdown" | "mdown"))
+}
+
+/// Open a file or folder with the app the OS uses for it
+pub fn open_path(path: &Path) -> Result<(), String> {
+    opener::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))
+}
+
+/// Save a file or subfolder as a bookmark of its own, named after it and kept in the given folder
+pub fn bookmark_path(path: &Path, folder_id: Option<i32>) -> Result<(), String> {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string());
+
+    bookmark_service::add_new_bookmark(&name, &path.to_string_lossy(), "", folder_id, "")?;
+    log_service::add_log_entry("INFO", &format!("Bookmarked {}", path.display()));
+    Ok(())
+}
+
+/// 12 KB, 3.4 MB
+pub fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{} KB", bytes / 1024)
+    } else {
+        format!("{} B", bytes)
+    }
+}
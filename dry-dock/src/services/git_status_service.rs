@@ -0,0 +1,105 @@
+// src/services/git_status_service.rs
+
+// Folder bookmarks often point at git checkouts. Their branch, distance to the upstream and
+// uncommitted changes are read straight from the repository with libgit2 (no git binary, no
+// network: ahead/behind is against the last fetched upstream). Reading a big working tree takes
+// a moment, so BookmarksScreen does it on a background thread and shows the last result.
+
+use std::collections::HashMap;
+use git2::{Repository, StatusOptions};
+use crate::models::{Bookmark, BookmarkKind, GitStatus};
+use super::{bookmark_opener_service, log_service};
+
+/// Branch name (or short commit id), whether HEAD is detached, and (ahead, behind) its upstream
+type Head = (String, bool, Option<(usize, usize)>);
+
+/// Git status of a location, None when it is not the top folder of a working tree
+pub fn read_status(location: &str) -> Result<Option<GitStatus>, String> {
+    let path = bookmark_opener_service::expand_home(location);
+    if !path.join(".git").exists() {
+        return Ok(None);
+    }
+
+    let repo = Repository::open(&path).map_err(|e| format!("Failed to open git repository: {}", e))?;
+    if repo.is_bare() {
+        return Ok(None);
+    }
+
+    let (head, detached, ahead_behind) = read_head(&repo)?;
+
+    let mut options = StatusOptions::new();
+    options
+        .include_untracked(true)
+        .recurse_untracked_dirs(false)
+        .exclude_submodules(true);
+    let changed = repo
+        .statuses(Some(&mut options))
+        .map_err(|e| format!("Failed to read git status: {}", e))?
+        .iter()
+        .filter(|entry| !entry.status().is_ignored())
+        .count();
+
+    Ok(Some(GitStatus { head, detached, ahead_behind, changed }))
+}
+
+/// Status of every folder bookmark that is a working tree, by bookmark id
+pub fn read_statuses(bookmarks: &[(i32, String)]) -> HashMap<i32, GitStatus> {
+    let mut statuses = HashMap::new();
+    for (id, location) in bookmarks {
+        match read_status(location) {
+            Ok(Some(status)) => {
+                statuses.insert(*id, status);
+            }
+            Ok(None) => {}
+            Err(e) => log_service::add_log_entry("ERROR", &format!("Error reading git status of {}: {}", location, e)),
+        }
+    }
+    statuses
+}
+
+/// The bookmarks worth asking, (id, location) so the list can move to another thread.
+/// Every path is included, read_status skips the ones that aren't working trees.
+pub fn candidates(bookmarks: &[Bookmark]) -> Vec<(i32, String)> {
+    bookmarks
+        .iter()
+        .filter(|bookmark| matches!(bookmark.kind, BookmarkKind::File | BookmarkKind::Folder))
+        .map(|bookmark| (bookmark.id, bookmark.path.clone()))
+        .collect()
+}
+
+fn read_head(repo: &Repository) -> Result<Head, String> {
+    let head = match repo.head() {
+        Ok(head) => head,
+        // Fresh repository without commits, HEAD still names the branch to be
+        Err(e) if e.code() == git2::ErrorCode::UnbornBranch => {
+            let branch = repo
+                .find_reference("HEAD")
+                .ok()
+                .and_then(|head| head.symbolic_target().map(|target| target.trim_start_matches("refs/heads/").to_string()))
+                .unwrap_or_default();
+            return Ok((branch, false, None));
+        }
+        Err(e) => return Err(format!("Failed to read git HEAD: {}", e)),
+    };
+
+    if !head.is_branch() {
+        let commit = head.target().map(|oid| oid.to_string()[..7].to_string()).unwrap_or_default();
+        return Ok((commit, true, None));
+    }
+
+    let name = head.shorthand().unwrap_or_default().to_string();
+    let local = head.target();
+    let upstream = git2::Branch::wrap(head)
+        .upstream()
+        .ok()
+        .and_then(|upstream| upstream.get().target());
+    let ahead_behind = match local.zip(upstream) {
+        Some((local, upstream)) => Some(
+            repo.graph_ahead_behind(local, upstream)
+                .map_err(|e| format!("Failed to compare with upstream: {}", e))?,
+        ),
+        None => None,
+    };
+
+    Ok((name, false, ahead_behind))
+}
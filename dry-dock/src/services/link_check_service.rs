@@ -0,0 +1,125 @@
+// src/services/link_check_service.rs
+
+// Bookmarks rot: sites go away, move, or start redirecting. This goes through every bookmark,
+// asks web bookmarks for their status (a few at a time) and checks that path bookmarks still exist.
+// Runs once a day in the background (see BackgroundServiceManager) or from the Bookmarks screen.
+
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use crate::dal::BookmarksRepository;
+use crate::dal::repositories::bookmarks_repository::LinkCheckRow;
+use crate::models::{BookmarkKind, LinkCheckReport};
+use super::{bookmark_opener_service, bookmark_service, log_service, settings_service};
+
+/// How many sites are asked at the same time
+const MAX_CONCURRENT_CHECKS: usize = 8;
+
+/// Seconds between scheduled runs
+pub const CHECK_INTERVAL_SECS: i64 = 24 * 60 * 60;
+
+/// True when the scheduled check hasn't run in the last CHECK_INTERVAL_SECS
+pub fn is_check_due() -> bool {
+    let now = chrono::Utc::now().timestamp();
+    settings_service::get_link_check_last_run().is_none_or(|last_run| now - last_run >= CHECK_INTERVAL_SECS)
+}
+
+/// Check every bookmark and store the results
+pub async fn check_all_bookmarks() -> Result<LinkCheckReport, String> {
+    let bookmarks = BookmarksRepository::get_all()?;
+
+    let client = reqwest::Client::builder()
+        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
+        .redirect(reqwest::redirect::Policy::limited(10))
+        .timeout(std::time::Duration::from_secs(20))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_CHECKS));
+    let mut tasks = JoinSet::new();
+    let mut results: Vec<LinkCheckRow> = Vec::new();
+    let now = chrono::Utc::now().timestamp();
+
+    for (id, _, location, ..) in bookmarks {
+        let kind = bookmark_opener_service::kind_of(&location);
+        if kind == BookmarkKind::Url {
+            let client = client.clone();
+            let semaphore = semaphore.clone();
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                let (status, error, redirect_url) = check_url(&client, &location).await;
+                (id, status, error, redirect_url)
+            });
+        } else if matches!(kind, BookmarkKind::File | BookmarkKind::Folder) {
+            let error = (!bookmark_opener_service::expand_home(&location).exists()).then(|| "Path not found".to_string());
+            results.push((id, None, error, None, now));
+        }
+        // Other kinds (mailto:, ssh://, commands...) aren't checked
+    }
+
+    while let Some(result) = tasks.join_next().await {
+        match result {
+            Ok((id, status, error, redirect_url)) => results.push((id, status, error, redirect_url, now)),
+            Err(e) => log_service::add_log_entry("ERROR", &format!("Link check task failed: {}", e)),
+        }
+    }
+
+    let report = LinkCheckReport {
+        checked: results.len(),
+        broken: results.iter().filter(|(_, status, error, _, _)| error.is_some() || status.is_some_and(|status| status >= 400)).count(),
+        redirected: results.iter().filter(|(_, _, _, redirect_url, _)| redirect_url.is_some()).count(),
+    };
+
+    BookmarksRepository::set_check_results(&results)?;
+    settings_service::save_link_check_last_run(now)?;
+    log_service::add_log_entry("INFO", &format!(
+        "Checked {} bookmark(s): {} broken, {} redirected.",
+        report.checked, report.broken, report.redirected
+    ));
+    Ok(report)
+}
+
+/// Point a bookmark at the address its old one redirects to
+pub fn use_redirect_target(bookmark_id: i32) -> Result<(), String> {
+    let (.., redirect_url, _, _, _, _) = BookmarksRepository::get_by_id(bookmark_id)?;
+    let redirect_url = redirect_url.ok_or_else(|| "This bookmark doesn't redirect anywhere".to_string())?;
+    // Stored like any other address, redirects often add tracking parameters
+    let redirect_url = bookmark_service::normalize_url(&redirect_url);
+
+    BookmarksRepository::set_location(bookmark_id, &redirect_url)
+        .map_err(|e| format!("{} (is the new address already bookmarked?)", e))?;
+    log_service::add_log_entry("INFO", &format!("Bookmark {} updated to {}.", bookmark_id, redirect_url));
+    Ok(())
+}
+
+/// (status, error, redirect target). Tries HEAD first and falls back to GET for servers that refuse HEAD.
+async fn check_url(client: &reqwest::Client, url: &str) -> (Option<i32>, Option<String>, Option<String>) {
+    let response = match client.head(url).send().await {
+        Ok(response) if !matches!(response.status().as_u16(), 400 | 403 | 405 | 501) => Ok(response),
+        _ => client.get(url).send().await,
+    };
+
+    match response {
+        Ok(response) => {
+            let status = i32::from(response.status().as_u16());
+            // Compare parsed URLs so "https://example.com" and "https://example.com/" are the same place
+            let redirect_url = match reqwest::Url::parse(url) {
+                Ok(original) if original == *response.url() => None,
+                _ => Some(response.url().to_string()),
+            };
+            (Some(status), None, redirect_url)
+        }
+        Err(e) => {
+            let error = if e.is_timeout() {
+                "Timed out".to_string()
+            } else if e.is_connect() {
+                "Could not connect".to_string()
+            } else if e.is_redirect() {
+                "Too many redirects".to_string()
+            } else {
+                e.to_string()
+            };
+            (None, Some(error), None)
+        }
+    }
+}
@@ -2,8 +2,21 @@ pub mod rss_service;
 pub mod terminal_bridge_service;
 pub mod assistant_bridge_service;
 pub mod note_service;
+pub mod note_mirror_service;
+pub mod note_crypto_service;
+pub mod draft_service;
+pub mod export_service;
+pub mod publish_service;
 pub mod settings_service;
+pub mod tag_service;
 pub mod bookmark_service;
+pub mod bookmark_opener_service;
+pub mod browser_bookmarks_service;
+pub mod link_check_service;
+pub mod bookmark_metadata_service;
+pub mod snapshot_service;
+pub mod git_status_service;
+pub mod file_browser_service;
 pub mod log_service;
 
 pub use note_service::NoteService;
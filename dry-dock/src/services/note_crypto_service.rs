@@ -0,0 +1,63 @@
+// src/services/note_crypto_service.rs
+
+// Passphrase encryption for locked notes.
+// The key is derived from the passphrase with Argon2id (fresh random salt per lock) and the
+// content is sealed with XChaCha20-Poly1305, so a wrong passphrase or tampered ciphertext fails
+// to decrypt instead of returning garbage. Ciphertext, salt and nonce are stored base64 encoded.
+
+use argon2::Argon2;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use crate::dal::repositories::notes_repository::NoteLockRow;
+
+/// Shortest passphrase we accept when locking a note
+pub const MIN_PASSPHRASE_LEN: usize = 8;
+
+const SALT_LEN: usize = 16;
+
+/// Encrypt text with a passphrase, returning (ciphertext, salt, nonce)
+pub fn encrypt(plaintext: &str, passphrase: &str) -> Result<NoteLockRow, String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+
+    let cipher = cipher_for(passphrase, &salt)?;
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .map_err(|_| "Failed to encrypt note".to_string())?;
+
+    Ok((BASE64.encode(ciphertext), BASE64.encode(salt), BASE64.encode(nonce)))
+}
+
+/// Decrypt text locked with `encrypt`
+pub fn decrypt(lock: &NoteLockRow, passphrase: &str) -> Result<String, String> {
+    let (ciphertext, salt, nonce) = lock;
+    let ciphertext = BASE64.decode(ciphertext).map_err(|e| format!("Failed to read encrypted note: {}", e))?;
+    let salt = BASE64.decode(salt).map_err(|e| format!("Failed to read encrypted note: {}", e))?;
+    let nonce = BASE64.decode(nonce).map_err(|e| format!("Failed to read encrypted note: {}", e))?;
+
+    if nonce.len() != 24 {
+        return Err("Failed to read encrypted note: bad nonce".to_string());
+    }
+
+    let plaintext = cipher_for(passphrase, &salt)?
+        .decrypt(XNonce::from_slice(&nonce), ciphertext.as_ref())
+        .map_err(|_| "Wrong passphrase".to_string())?;
+
+    String::from_utf8(plaintext).map_err(|e| format!("Failed to read encrypted note: {}", e))
+}
+
+/// Derive the 256-bit key from the passphrase with Argon2id default parameters
+fn cipher_for(passphrase: &str, salt: &[u8]) -> Result<XChaCha20Poly1305, String> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Failed to derive key: {}", e))?;
+
+    let cipher = XChaCha20Poly1305::new(&key.into());
+    key.fill(0);
+    Ok(cipher)
+}
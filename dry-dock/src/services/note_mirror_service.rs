@@ -0,0 +1,482 @@
+// src/services/note_mirror_service.rs
+
+// Optional two-way mirror between the notes table and a folder of markdown files.
+// Every note is written to <dir>/<notebook>/<slug>.md and edits made on disk (another editor,
+// a git pull, ...) are pulled back into the notes table.
+//
+// note_sync_state remembers the content hash, file mtime and note updated_at from the last sync
+// so we can tell which side changed. When both sides changed we record a conflict and leave
+// both versions alone until the user picks one.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use sha2::{Digest, Sha256};
+use crate::dal::{NotesRepository, NoteSyncRepository};
+use crate::models::{ConflictResolution, Note, NoteSyncConflict};
+use super::{log_service, settings_service, NoteService};
+
+/// The watcher thread, Settings and "Sync Now" can all start a sync. Two overlapping runs would
+/// both see the same untracked file or conflict, so only one runs at a time.
+static SYNC_LOCK: Mutex<()> = Mutex::new(());
+
+/// What the last sync did
+#[derive(Debug, Default)]
+pub struct SyncReport {
+    pub written: usize,
+    pub pulled: usize,
+    pub created: usize,
+    pub conflicts: usize,
+}
+
+impl SyncReport {
+    pub fn has_changes(&self) -> bool {
+        self.written + self.pulled + self.created + self.conflicts > 0
+    }
+}
+
+/// Last synced state of a mirrored note
+struct SyncState {
+    file_path: PathBuf,
+    content_hash: String,
+    file_mtime: i64,
+    note_updated_at: i64,
+}
+
+/// The mirror folder, if mirroring is turned on
+pub fn mirror_root() -> Option<PathBuf> {
+    let settings = settings_service::get_note_mirror_settings();
+    if settings.enabled && !settings.directory.trim().is_empty() {
+        Some(PathBuf::from(settings.directory.trim()))
+    } else {
+        None
+    }
+}
+
+/// Run a full two-way sync between the notes table and the mirror folder
+pub fn sync_all() -> Result<SyncReport, String> {
+    // A panicked sync leaves nothing half done that the next one can't redo
+    let _guard = SYNC_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let mut report = SyncReport::default();
+    let root = match mirror_root() {
+        Some(root) => root,
+        None => return Ok(report),
+    };
+
+    std::fs::create_dir_all(&root)
+        .map_err(|e| format!("Failed to create mirror folder '{}': {}", root.display(), e))?;
+
+    let notes = NoteService::get_all_notes()?;
+    let mut states = load_states()?;
+    let pending_conflicts = conflicted_note_ids()?;
+
+    // Anything on disk that no note points at yet is a candidate for a new note (or a moved file)
+    let tracked: HashSet<PathBuf> = states.values().map(|s| s.file_path.clone()).collect();
+    let mut untracked: Vec<PathBuf> = collect_markdown_files(&root)
+        .into_iter()
+        .filter(|path| !tracked.contains(path))
+        .collect();
+
+    // Locked notes are never written to disk
+    for note in notes.iter().filter(|note| !note.is_locked) {
+        if pending_conflicts.contains(&note.id) {
+            continue;
+        }
+
+        let rendered = render_note(note);
+        let note_hash = content_hash(&rendered);
+
+        let Some(state) = states.get(&note.id) else {
+            // Never mirrored before
+            let path = note_path(&root, note, &states);
+            if path.exists() {
+                untracked.retain(|p| p != &path);
+                let file_content = read_file(&path)?;
+                if content_hash(&file_content) == note_hash {
+                    record_state(note.id, &path, &file_content, note.last_modified())?;
+                } else {
+                    NoteSyncRepository::add_conflict(note.id, &path.to_string_lossy(), &file_content)?;
+                    report.conflicts += 1;
+                }
+            } else {
+                write_note(&path, note, &rendered)?;
+                report.written += 1;
+            }
+            insert_state(&mut states, note.id, &path, &note_hash);
+            continue;
+        };
+
+        let note_changed = note.last_modified() >= state.note_updated_at && note_hash != state.content_hash;
+
+        if !state.file_path.exists() {
+            // A file with the same content somewhere else means it was moved or renamed on disk
+            if let Some(index) = untracked.iter().position(|p| hash_file(p).as_deref() == Some(state.content_hash.as_str())) {
+                let moved = untracked.remove(index);
+                pull_note(&root, note.id, &moved, &read_file(&moved)?)?;
+                report.pulled += 1;
+            } else {
+                // Deleted on disk, the note in Dry Dock wins and gets written back
+                let path = note_path(&root, note, &states);
+                write_note(&path, note, &rendered)?;
+                report.written += 1;
+            }
+            continue;
+        }
+
+        let file_content = read_file(&state.file_path)?;
+        let file_hash = content_hash(&file_content);
+        let file_changed = file_mtime(&state.file_path) >= state.file_mtime && file_hash != state.content_hash;
+
+        match (note_changed, file_changed) {
+            (false, false) => {}
+            (true, false) => {
+                let path = note_path(&root, note, &states);
+                if path != state.file_path {
+                    // Renamed or moved to another notebook in the app
+                    let _ = std::fs::remove_file(&state.file_path);
+                }
+                write_note(&path, note, &rendered)?;
+                report.written += 1;
+            }
+            (false, true) => {
+                pull_note(&root, note.id, &state.file_path, &file_content)?;
+                report.pulled += 1;
+            }
+            (true, true) => {
+                if file_hash == note_hash {
+                    // Both sides made the same change
+                    record_state(note.id, &state.file_path, &file_content, note.last_modified())?;
+                } else {
+                    NoteSyncRepository::add_conflict(note.id, &state.file_path.to_string_lossy(), &file_content)?;
+                    report.conflicts += 1;
+                }
+            }
+        }
+    }
+
+    // New files on disk become new notes
+    for path in untracked {
+        let file_content = read_file(&path)?;
+        let (title, details) = parse_markdown_file(&file_content, &path);
+        let note_id = NotesRepository::create(&title, &details, &notebook_for(&root, &path))?;
+        record_state(note_id, &path, &file_content, chrono::Utc::now().timestamp())?;
+        report.created += 1;
+    }
+
+    if report.has_changes() {
+        log_service::add_log_entry("INFO", &format!(
+            "Notes mirror synced: {} written, {} pulled, {} created, {} conflicts",
+            report.written, report.pulled, report.created, report.conflicts
+        ));
+    }
+
+    Ok(report)
+}
+
+/// Write a single note to the mirror after it was saved in the app
+pub fn push_note(note_id: i32) -> Result<(), String> {
+    let root = match mirror_root() {
+        Some(root) => root,
+        None => return Ok(()),
+    };
+
+    // Leave both versions alone until the user resolves the conflict
+    if conflicted_note_ids()?.contains(&note_id) {
+        return Ok(());
+    }
+
+    let note = NoteService::get_note_by_id(note_id)?;
+    if note.is_locked {
+        return Ok(());
+    }
+
+    let states = load_states()?;
+    let path = note_path(&root, &note, &states);
+
+    if let Some(state) = states.get(&note_id) {
+        // The file was edited on disk and not pulled yet, don't overwrite it
+        if let Some(file_hash) = hash_file(&state.file_path)
+            && file_hash != state.content_hash
+        {
+            let file_content = read_file(&state.file_path)?;
+            NoteSyncRepository::add_conflict(note_id, &state.file_path.to_string_lossy(), &file_content)?;
+            return Ok(());
+        }
+
+        if state.file_path != path {
+            let _ = std::fs::remove_file(&state.file_path);
+        }
+    }
+
+    write_note(&path, &note, &render_note(&note))
+}
+
+/// Remove a note's mirrored file (called before the note is deleted or locked).
+/// Runs even with the mirror turned off: a file left from when it was on would keep a locked
+/// note's plaintext on disk and come back as a new note once the mirror is turned on again.
+pub fn remove_note_file(note_id: i32) -> Result<(), String> {
+    NoteSyncRepository::delete_conflict(note_id)?;
+
+    if let Some(state) = load_states()?.remove(&note_id) {
+        if state.file_path.exists() {
+            std::fs::remove_file(&state.file_path)
+                .map_err(|e| format!("Failed to remove '{}': {}", state.file_path.display(), e))?;
+        }
+        NoteSyncRepository::delete_state(note_id)?;
+    }
+
+    Ok(())
+}
+
+/// Number of unresolved conflicts
+pub fn count_conflicts() -> Result<i64, String> {
+    NoteSyncRepository::count_conflicts()
+}
+
+/// Get every unresolved conflict along with the current note content
+pub fn get_conflicts() -> Result<Vec<NoteSyncConflict>, String> {
+    let conflicts = NoteSyncRepository::get_conflicts()?
+        .into_iter()
+        .filter_map(|(note_id, file_path, file_content, detected_at)| {
+            let note = NoteService::get_note_by_id(note_id).ok()?;
+            Some(NoteSyncConflict {
+                note_id,
+                title: note.title.clone(),
+                file_path,
+                note_content: render_note(&note),
+                file_content,
+                detected_at,
+            })
+        })
+        .collect();
+
+    Ok(conflicts)
+}
+
+/// Resolve a conflict by keeping one side and overwriting the other
+pub fn resolve_conflict(note_id: i32, resolution: ConflictResolution) -> Result<(), String> {
+    let root = mirror_root().ok_or("Notes mirror is not enabled".to_string())?;
+    let conflict = NoteSyncRepository::get_conflicts()?
+        .into_iter()
+        .find(|(id, _, _, _)| *id == note_id)
+        .ok_or(format!("No conflict found for note {}", note_id))?;
+
+    let conflict_path = PathBuf::from(&conflict.1);
+    match resolution {
+        ConflictResolution::KeepNote => {
+            let note = NoteService::get_note_by_id(note_id)?;
+            if conflict_path != note_path(&root, &note, &load_states()?) {
+                let _ = std::fs::remove_file(&conflict_path);
+            }
+            let path = note_path(&root, &note, &load_states()?);
+            write_note(&path, &note, &render_note(&note))?;
+        }
+        ConflictResolution::KeepFile => {
+            // Prefer what is on disk now, fall back to the snapshot taken when the conflict was found
+            let file_content = read_file(&conflict_path).unwrap_or(conflict.2);
+            pull_note(&root, note_id, &conflict_path, &file_content)?;
+        }
+    }
+
+    NoteSyncRepository::delete_conflict(note_id)?;
+    log_service::add_log_entry("INFO", &format!("Resolved mirror conflict for note {}", note_id));
+    Ok(())
+}
+
+/// Markdown written for a note: the title as a heading followed by the details
+fn render_note(note: &Note) -> String {
+    format!("# {}\n\n{}\n", note.title, note.details.trim_end())
+}
+
+/// Split a markdown file back into (title, details)
+/// A leading "# " heading is the title, otherwise the file name is used
+fn parse_markdown_file(content: &str, path: &Path) -> (String, String) {
+    let content = content.trim_start_matches('\u{feff}');
+    let mut lines = content.lines();
+
+    if let Some(title) = lines.next().and_then(|line| line.strip_prefix("# ")) {
+        let details = lines.collect::<Vec<_>>().join("\n");
+        return (title.trim().to_string(), details.trim().to_string());
+    }
+
+    let title = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().replace(['-', '_'], " "))
+        .unwrap_or_else(|| "Untitled".to_string());
+    (title, content.trim().to_string())
+}
+
+/// Pull a file's content into an existing note and remember the synced state
+fn pull_note(root: &Path, note_id: i32, path: &Path, file_content: &str) -> Result<(), String> {
+    let (title, details) = parse_markdown_file(file_content, path);
+    NotesRepository::update(note_id, &title, &details, &notebook_for(root, path))?;
+    let note = NoteService::get_note_by_id(note_id)?;
+    record_state(note_id, path, file_content, note.last_modified())
+}
+
+/// Write a note's markdown to disk and remember the synced state
+fn write_note(path: &Path, note: &Note, rendered: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create folder '{}': {}", parent.display(), e))?;
+    }
+    std::fs::write(path, rendered)
+        .map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+    record_state(note.id, path, rendered, note.last_modified())
+}
+
+fn record_state(note_id: i32, path: &Path, content: &str, note_updated_at: i64) -> Result<(), String> {
+    NoteSyncRepository::upsert_state(
+        note_id,
+        &path.to_string_lossy(),
+        &content_hash(content),
+        file_mtime(path),
+        note_updated_at,
+    )
+}
+
+/// Keep the in-memory state map in step so later notes in the same sync see taken paths
+fn insert_state(states: &mut HashMap<i32, SyncState>, note_id: i32, path: &Path, hash: &str) {
+    states.insert(note_id, SyncState {
+        file_path: path.to_path_buf(),
+        content_hash: hash.to_string(),
+        file_mtime: file_mtime(path),
+        note_updated_at: 0,
+    });
+}
+
+fn load_states() -> Result<HashMap<i32, SyncState>, String> {
+    Ok(NoteSyncRepository::get_all_states()?
+        .into_iter()
+        .map(|(note_id, file_path, content_hash, file_mtime, note_updated_at)| {
+            (note_id, SyncState {
+                file_path: PathBuf::from(file_path),
+                content_hash,
+                file_mtime,
+                note_updated_at,
+            })
+        })
+        .collect())
+}
+
+fn conflicted_note_ids() -> Result<HashSet<i32>, String> {
+    Ok(NoteSyncRepository::get_conflicts()?
+        .into_iter()
+        .map(|(note_id, _, _, _)| note_id)
+        .collect())
+}
+
+/// <root>/<notebook>/<slug>.md, with the note id appended if another note already owns the name
+fn note_path(root: &Path, note: &Note, states: &HashMap<i32, SyncState>) -> PathBuf {
+    let mut dir = root.to_path_buf();
+    for part in note.notebook.split('/').map(sanitize_path_part).filter(|p| !p.is_empty()) {
+        dir.push(part);
+    }
+
+    let slug = slugify(&note.title);
+    let path = dir.join(format!("{}.md", slug));
+    let taken = states
+        .iter()
+        .any(|(id, state)| *id != note.id && state.file_path == path);
+
+    if taken {
+        dir.join(format!("{}-{}.md", slug, note.id))
+    } else {
+        path
+    }
+}
+
+/// Notebook name from the folder a file sits in (files in the root go to the default notebook)
+fn notebook_for(root: &Path, path: &Path) -> String {
+    let notebook = path
+        .parent()
+        .and_then(|parent| parent.strip_prefix(root).ok())
+        .map(|relative| {
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .unwrap_or_default();
+
+    if notebook.is_empty() {
+        Note::DEFAULT_NOTEBOOK.to_string()
+    } else {
+        notebook
+    }
+}
+
+/// Lowercase, dash separated file name for a title
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.trim().chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if !slug.ends_with('-') && !slug.is_empty() {
+            slug.push('-');
+        }
+    }
+
+    let slug = slug.trim_end_matches('-').to_string();
+    if slug.is_empty() { "untitled".to_string() } else { slug }
+}
+
+/// Strip characters that are not allowed in folder names
+fn sanitize_path_part(part: &str) -> String {
+    part.chars()
+        .map(|c| if matches!(c, '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') { '-' } else { c })
+        .collect::<String>()
+        .trim()
+        .trim_matches('.')
+        .to_string()
+}
+
+/// Every .md file under the mirror folder (hidden folders such as .git are skipped)
+fn collect_markdown_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else { continue };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if hidden {
+                continue;
+            }
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("md")) {
+                files.push(path);
+            }
+        }
+    }
+
+    files
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("Failed to read '{}': {}", path.display(), e))
+}
+
+fn hash_file(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path).ok().map(|content| content_hash(&content))
+}
+
+fn content_hash(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn file_mtime(path: &Path) -> i64 {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0)
+}
@@ -1,6 +1,8 @@
 // src/services/note_service.rs
-use crate::dal::NotesRepository;
-use crate::models::Note;
+use crate::dal::{DraftsRepository, NotesRepository};
+use crate::dal::repositories::notes_repository::{NoteCursor, NoteRow};
+use crate::models::{Note, NoteSort, NoteFilter};
+use super::{log_service, note_crypto_service, note_mirror_service, tag_service};
 
 /// Note Service - Business Logic Layer for Notes
 /// This layer sits between the UI and the Data Access Layer (Repository)
@@ -10,78 +12,235 @@ pub struct NoteService;
 impl NoteService {
     /// Create a new note
     /// Validates input and delegates to repository
-    pub fn create_note(title: &str, details: &str) -> Result<(), String> {
+    pub fn create_note(title: &str, details: &str, notebook: &str, tags: &str) -> Result<(), String> {
         // BLL: Validate inputs
         if title.trim().is_empty() {
             return Err("Note title cannot be empty".to_string());
         }
-        
+
         if details.trim().is_empty() {
             return Err("Note details cannot be empty".to_string());
         }
-        
+
         // Delegate to repository
-        NotesRepository::create(title, details)
+        let note_id = NotesRepository::create(title, details, &Self::normalize_notebook(notebook))?;
+        NotesRepository::set_tags(note_id, &tag_service::normalize_tags(tags))?;
+
+        // BLL: Keep the markdown mirror up to date (if enabled)
+        Self::push_to_mirror(note_id);
+        Ok(())
     }
-    
+
     /// Delete a note by ID
     pub fn delete_note(note_id: i32) -> Result<(), String> {
-        // BLL: Could add authorization checks, logging, etc. here
-        NotesRepository::delete(note_id)
+        Self::delete_notes(&[note_id])
     }
-    
-    /// Get all notes as a list of Note models
-    /// Transforms repository data into domain models
-    pub fn get_all_notes() -> Result<Vec<Note>, String> {
+
+    /// Get one page of notes as Note models, continuing after the last note already loaded
+    /// An empty query pages through every note in the filter, otherwise only matching notes
+    pub fn get_notes_page(query: &str, filter: NoteFilter, sort: NoteSort, after: Option<&Note>, limit: i64) -> Result<Vec<Note>, String> {
+        // BLL: The cursor carries every column the sorts order by
+        let cursor: Option<NoteCursor> = after.map(|note| {
+            (note.is_pinned, note.created_at, note.last_modified(), note.title.clone(), note.id)
+        });
+
         // Get raw data from repository
-        let raw_notes = NotesRepository::get_all()?;
-        
+        let raw_notes = NotesRepository::get_page(query.trim(), filter, sort, cursor.as_ref(), limit)?;
+
         // BLL: Transform tuples into Note models
         let notes = raw_notes
             .into_iter()
-            .map(|(id, title, details, created_at, updated_at)| {
-                Note::new(id, title, details, created_at, updated_at)
-            })
+            .map(Self::to_note)
             .collect();
-        
+
         Ok(notes)
     }
-    
-    /// Search notes by title or content
-    /// BLL: Implements search logic
-    pub fn search_notes(query: &str) -> Result<Vec<Note>, String> {
-        let all_notes = Self::get_all_notes()?;
-        
-        let query_lower = query.to_lowercase();
-        let filtered = all_notes
-            .into_iter()
-            .filter(|note| {
-                note.title.to_lowercase().contains(&query_lower) 
-                || note.details.to_lowercase().contains(&query_lower)
-            })
-            .collect();
-        
-        Ok(filtered)
+
+    /// Get every note as Note models
+    pub fn get_all_notes() -> Result<Vec<Note>, String> {
+        let raw_notes = NotesRepository::get_all()?;
+        Ok(raw_notes.into_iter().map(Self::to_note).collect())
+    }
+
+    /// Count notes for the list view
+    /// BLL: Search matches title or content, same as get_notes_page
+    pub fn count_notes(query: &str, filter: NoteFilter) -> Result<i64, String> {
+        NotesRepository::count(query.trim(), filter)
+    }
+
+    /// Pin or unpin notes so they float to the top of the list
+    pub fn pin_notes(note_ids: &[i32], pinned: bool) -> Result<(), String> {
+        if note_ids.is_empty() {
+            return Ok(());
+        }
+        NotesRepository::set_pinned(note_ids, pinned)
+    }
+
+    /// Archive or restore notes
+    /// BLL: Archived notes leave the main list but stay searchable
+    pub fn archive_notes(note_ids: &[i32], archived: bool) -> Result<(), String> {
+        if note_ids.is_empty() {
+            return Ok(());
+        }
+        NotesRepository::set_archived(note_ids, archived)
+    }
+
+    /// Delete several notes at once
+    pub fn delete_notes(note_ids: &[i32]) -> Result<(), String> {
+        if note_ids.is_empty() {
+            return Ok(());
+        }
+
+        // BLL: Remove mirrored files first, the sync state goes away with the note
+        for note_id in note_ids {
+            if let Err(e) = note_mirror_service::remove_note_file(*note_id) {
+                log_service::add_log_entry("ERROR", &format!("Failed to remove mirrored file for note {}: {}", note_id, e));
+            }
+        }
+
+        NotesRepository::delete_many(note_ids)
     }
 
     /// Get a note by ID
     pub fn get_note_by_id(note_id: i32) -> Result<Note, String> {
-        let (id, title, details, created_at, updated_at) = NotesRepository::get_by_id(note_id)?;
-        Ok(Note::new(id, title, details, created_at, updated_at))
+        Ok(Self::to_note(NotesRepository::get_by_id(note_id)?))
     }
 
     /// Update a note
-    pub fn update_note(note_id: i32, title: &str, details: &str) -> Result<(), String> {
+    pub fn update_note(note_id: i32, title: &str, details: &str, notebook: &str, tags: &str) -> Result<(), String> {
         // BLL: Validate inputs
         if title.trim().is_empty() {
             return Err("Note title cannot be empty".to_string());
         }
-        
+
         if details.trim().is_empty() {
             return Err("Note details cannot be empty".to_string());
         }
-        
+
+        // BLL: Locked notes must go through update_locked_note so details stay encrypted
+        if Self::get_note_by_id(note_id)?.is_locked {
+            return Err("Note is locked".to_string());
+        }
+
         // Delegate to repository
-        NotesRepository::update(note_id, title, details)
+        NotesRepository::update(note_id, title, details, &Self::normalize_notebook(notebook))?;
+        NotesRepository::set_tags(note_id, &tag_service::normalize_tags(tags))?;
+
+        // BLL: Keep the markdown mirror up to date (if enabled)
+        Self::push_to_mirror(note_id);
+        Ok(())
+    }
+
+    /// Lock a note with a passphrase, only the ciphertext is kept in the database
+    pub fn lock_note(note_id: i32, passphrase: &str) -> Result<(), String> {
+        // BLL: Validate inputs
+        if passphrase.chars().count() < note_crypto_service::MIN_PASSPHRASE_LEN {
+            return Err(format!("Passphrase must be at least {} characters", note_crypto_service::MIN_PASSPHRASE_LEN));
+        }
+
+        let note = Self::get_note_by_id(note_id)?;
+        if note.is_locked {
+            return Err("Note is already locked".to_string());
+        }
+
+        // BLL: Locked notes never live on disk in plain text, and drafts would hold the plain text too
+        note_mirror_service::remove_note_file(note_id)?;
+        DraftsRepository::delete_for_note(note_id)?;
+
+        let lock = note_crypto_service::encrypt(&note.details, passphrase)?;
+        NotesRepository::set_locked(note_id, &note.title, &note.notebook, &lock)?;
+        log_service::add_log_entry("INFO", &format!("Note {} locked.", note_id));
+        Ok(())
+    }
+
+    /// Decrypt a locked note's details for viewing, nothing is written back
+    pub fn unlock_note(note_id: i32, passphrase: &str) -> Result<String, String> {
+        note_crypto_service::decrypt(&NotesRepository::get_lock(note_id)?, passphrase)
+    }
+
+    /// Remove the lock from a note and store its details in plain text again
+    pub fn remove_lock(note_id: i32, passphrase: &str) -> Result<(), String> {
+        let details = Self::unlock_note(note_id, passphrase)?;
+        NotesRepository::set_unlocked(note_id, &details)?;
+        log_service::add_log_entry("INFO", &format!("Note {} unlocked.", note_id));
+
+        // BLL: Back to a normal note, so it is mirrored again
+        Self::push_to_mirror(note_id);
+        Ok(())
+    }
+
+    /// Update a locked note, re-encrypting the new details with the same passphrase
+    pub fn update_locked_note(note_id: i32, title: &str, details: &str, notebook: &str, tags: &str, passphrase: &str) -> Result<(), String> {
+        // BLL: Validate inputs
+        if title.trim().is_empty() {
+            return Err("Note title cannot be empty".to_string());
+        }
+
+        if details.trim().is_empty() {
+            return Err("Note details cannot be empty".to_string());
+        }
+
+        // BLL: Make sure the passphrase still opens the note before replacing its content
+        Self::unlock_note(note_id, passphrase)?;
+
+        let lock = note_crypto_service::encrypt(details, passphrase)?;
+        NotesRepository::set_locked(note_id, title, &Self::normalize_notebook(notebook), &lock)?;
+        NotesRepository::set_tags(note_id, &tag_service::normalize_tags(tags))
+    }
+
+    /// Get the notebook names currently in use
+    pub fn get_notebooks() -> Result<Vec<String>, String> {
+        NotesRepository::get_notebooks()
+    }
+
+    /// Get every note in a notebook, oldest first
+    pub fn get_notes_in_notebook(notebook: &str) -> Result<Vec<Note>, String> {
+        let raw_notes = NotesRepository::get_by_notebook(notebook)?;
+        Ok(raw_notes.into_iter().map(Self::to_note).collect())
+    }
+
+    /// Get every note carrying a tag
+    pub fn get_notes_with_tag(tag: &str) -> Result<Vec<Note>, String> {
+        let tag = tag_service::normalize_tag(tag);
+        if tag.is_empty() {
+            return Err("Tag cannot be empty".to_string());
+        }
+
+        let raw_notes = NotesRepository::get_by_tag(&tag)?;
+        Ok(raw_notes.into_iter().map(Self::to_note).collect())
+    }
+
+    /// BLL: Blank notebook names fall back to the default notebook
+    fn normalize_notebook(notebook: &str) -> String {
+        let notebook = notebook.trim();
+        if notebook.is_empty() {
+            Note::DEFAULT_NOTEBOOK.to_string()
+        } else {
+            notebook.to_string()
+        }
+    }
+
+    /// Write a note to the markdown mirror, failures are logged but never block saving
+    fn push_to_mirror(note_id: i32) {
+        if let Err(e) = note_mirror_service::push_note(note_id) {
+            log_service::add_log_entry("ERROR", &format!("Failed to mirror note {}: {}", note_id, e));
+        }
+    }
+
+    /// Transform a repository tuple into a Note model
+    fn to_note(row: NoteRow) -> Note {
+        let (id, title, details, notebook, created_at, updated_at, is_pinned, is_archived, is_locked, tags) = row;
+        Note {
+            id,
+            title,
+            details,
+            notebook,
+            created_at,
+            updated_at,
+            is_pinned,
+            is_archived,
+            is_locked,
+            tags: tag_service::split_tags(&tags),
+        }
     }
 }
@@ -0,0 +1,380 @@
+// src/services/publish_service.rs
+
+// Publish notes carrying the publish tag (default `public`) as a static website.
+// Everything is written to a local folder, deploying it is up to the user.
+//   index.html    every published note, most recently updated first
+//   <slug>.html   one page per note, [[Wiki Links]] between published notes become real links
+//   feed.xml      Atom feed of the most recently updated notes
+//   style.css
+//   media/        local images referenced by the notes
+// A manifest lists the files written last time so pages of notes that are no longer published
+// get removed. Files the publisher didn't write are never touched.
+// Locked and archived notes are never published.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use pulldown_cmark::{CowStr, Event, Parser, Tag, TagEnd, html};
+use sha2::{Digest, Sha256};
+use crate::models::{Note, PublishSettings};
+use super::{export_service, log_service, note_mirror_service, NoteService};
+
+/// Lists the files written by the last publish, relative to the output folder
+const MANIFEST_FILE: &str = ".dry-dock-site";
+/// How many notes the feed carries
+const FEED_ENTRIES: usize = 20;
+/// Max characters of the summary shown on the index page
+const SUMMARY_CHARS: usize = 200;
+
+const SITE_STYLE: &str = "
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; line-height: 1.6; color: #1f2328; max-width: 760px; margin: 0 auto; padding: 24px; }
+header.site { border-bottom: 1px solid #d0d7de; margin-bottom: 24px; padding-bottom: 8px; }
+header.site a { color: inherit; text-decoration: none; font-weight: 600; font-size: 1.2em; }
+a { color: #0969da; }
+.meta { color: #656d76; font-size: .9em; }
+.tag { background: #ddf4ff; border-radius: 10px; padding: 0 8px; margin-right: 4px; font-size: .85em; }
+.missing-link { color: #cf222e; border-bottom: 1px dashed #cf222e; }
+ul.notes { list-style: none; padding: 0; }
+ul.notes li { margin-bottom: 20px; }
+pre { background: #f6f8fa; border-radius: 6px; padding: 12px; overflow-x: auto; }
+code { font-family: ui-monospace, Menlo, Consolas, monospace; font-size: .9em; }
+blockquote { color: #656d76; border-left: 4px solid #d0d7de; margin-left: 0; padding-left: 16px; }
+img { max-width: 100%; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #d0d7de; padding: 6px 12px; }
+";
+
+/// Outcome of a publish run
+#[derive(Debug, Default)]
+pub struct PublishReport {
+    pub pages: usize,
+    pub images: usize,
+    /// Files from the previous publish that are gone now
+    pub removed: usize,
+    /// [[Wiki Links]] pointing at notes that aren't published
+    pub broken_links: usize,
+}
+
+/// A note that is being published and the page it ends up on
+struct Page<'a> {
+    note: &'a Note,
+    file_name: String,
+}
+
+/// Render every published note into `settings.directory`
+pub fn publish(settings: &PublishSettings) -> Result<PublishReport, String> {
+    let notes: Vec<Note> = NoteService::get_notes_with_tag(&settings.tag)?
+        .into_iter()
+        .filter(|note| !note.is_locked && !note.is_archived)
+        .collect();
+    if notes.is_empty() {
+        return Err(format!("No notes are tagged '{}'", settings.tag.trim()));
+    }
+
+    let root = PathBuf::from(settings.directory.trim());
+    std::fs::create_dir_all(&root)
+        .map_err(|e| format!("Failed to create folder '{}': {}", root.display(), e))?;
+
+    let pages = assign_pages(&notes);
+    // Wiki links match note titles without regard to case
+    let links: HashMap<String, String> = pages
+        .iter()
+        .map(|page| (page.note.title.trim().to_lowercase(), page.file_name.clone()))
+        .collect();
+
+    let mut report = PublishReport::default();
+    let mut written: Vec<String> = Vec::new();
+    let mut bodies: HashMap<i32, String> = HashMap::new();
+    let site_title = if settings.site_title.trim().is_empty() { "Notes" } else { settings.site_title.trim() };
+
+    for page in &pages {
+        let body = render_body(page.note, &links, &root, &mut written, &mut report)?;
+        let html = page_html(site_title, &page.note.title, &format!("{}{}", note_meta(page.note), body));
+        write_file(&root, &page.file_name, html.as_bytes(), &mut written)?;
+        bodies.insert(page.note.id, body);
+        report.pages += 1;
+    }
+
+    // Most recently updated first, for the index and the feed
+    let mut recent: Vec<&Page> = pages.iter().collect();
+    recent.sort_by_key(|page| std::cmp::Reverse(page.note.last_modified()));
+
+    write_file(&root, "index.html", index_html(site_title, &recent).as_bytes(), &mut written)?;
+    write_file(&root, "feed.xml", feed_xml(settings, site_title, &recent, &bodies).as_bytes(), &mut written)?;
+    write_file(&root, "style.css", SITE_STYLE.trim_start().as_bytes(), &mut written)?;
+
+    report.removed = remove_stale_files(&root, &written)?;
+    std::fs::write(root.join(MANIFEST_FILE), written.join("\n"))
+        .map_err(|e| format!("Failed to write site manifest: {}", e))?;
+
+    log_service::add_log_entry("INFO", &format!(
+        "Published {} note(s) to {} ({} removed, {} broken wiki link(s))",
+        report.pages, root.display(), report.removed, report.broken_links
+    ));
+    Ok(report)
+}
+
+/// Give every note a file name, notes sharing a title get their id appended
+fn assign_pages(notes: &[Note]) -> Vec<Page<'_>> {
+    let mut used: HashSet<String> = ["index".to_string(), "feed".to_string(), "style".to_string()].into();
+
+    notes
+        .iter()
+        .map(|note| {
+            let mut slug = note_mirror_service::slugify(&note.title);
+            if !used.insert(slug.clone()) {
+                slug = format!("{}-{}", slug, note.id);
+                used.insert(slug.clone());
+            }
+            Page { note, file_name: format!("{}.html", slug) }
+        })
+        .collect()
+}
+
+/// Note markdown as HTML: headings move down a level (the page title is the h1),
+/// wiki links are resolved and local images copied into media/
+fn render_body(
+    note: &Note,
+    links: &HashMap<String, String>,
+    root: &Path,
+    written: &mut Vec<String>,
+    report: &mut PublishReport,
+) -> Result<String, String> {
+    let mut events: Vec<Event> = Vec::new();
+    let mut text = String::new();
+    let mut in_code_block = false;
+
+    for event in Parser::new_ext(&note.details, export_service::markdown_options()) {
+        // Brackets split text into several events, join them back up so [[links]] can be found
+        if let Event::Text(value) = &event
+            && !in_code_block
+        {
+            text.push_str(value);
+            continue;
+        }
+        if !text.is_empty() {
+            events.extend(wiki_link_events(&std::mem::take(&mut text), links, report));
+        }
+
+        let event = match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                in_code_block = true;
+                Event::Start(Tag::CodeBlock(kind))
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                in_code_block = false;
+                Event::End(TagEnd::CodeBlock)
+            }
+            Event::Start(Tag::Heading { level, id, classes, attrs }) => Event::Start(Tag::Heading {
+                level: export_service::shift_heading(level),
+                id,
+                classes,
+                attrs,
+            }),
+            Event::End(TagEnd::Heading(level)) => Event::End(TagEnd::Heading(export_service::shift_heading(level))),
+            Event::Start(Tag::Image { link_type, dest_url, title, id }) => {
+                let dest_url = match export_service::resolve_image(note, &dest_url) {
+                    Some(path) => {
+                        report.images += 1;
+                        CowStr::from(copy_image(root, &path, written)?)
+                    }
+                    None => dest_url,
+                };
+                Event::Start(Tag::Image { link_type, dest_url, title, id })
+            }
+            event => event,
+        };
+        events.push(event);
+    }
+    if !text.is_empty() {
+        events.extend(wiki_link_events(&text, links, report));
+    }
+
+    let mut body = String::new();
+    html::push_html(&mut body, events.into_iter());
+    Ok(body)
+}
+
+/// Split text on [[Title]] and [[Title|label]], linking titles of published notes
+fn wiki_link_events<'a>(text: &str, links: &HashMap<String, String>, report: &mut PublishReport) -> Vec<Event<'a>> {
+    let mut events = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("[[") {
+        let Some(length) = rest[start + 2..].find("]]") else { break };
+        let inner = &rest[start + 2..start + 2 + length];
+        let (target, label) = inner.split_once('|').unwrap_or((inner, inner));
+
+        if start > 0 {
+            events.push(Event::Text(CowStr::from(rest[..start].to_string())));
+        }
+        let label = export_service::escape_html(label.trim());
+        let html = match links.get(&target.trim().to_lowercase()) {
+            Some(file_name) => format!("<a href=\"{}\">{}</a>", file_name, label),
+            None => {
+                report.broken_links += 1;
+                format!("<span class=\"missing-link\" title=\"Not published\">{}</span>", label)
+            }
+        };
+        events.push(Event::InlineHtml(CowStr::from(html)));
+        rest = &rest[start + 2 + length + 2..];
+    }
+
+    if !rest.is_empty() {
+        events.push(Event::Text(CowStr::from(rest.to_string())));
+    }
+    events
+}
+
+/// Copy an image into media/, named after its content so unchanged images keep their URL
+fn copy_image(root: &Path, path: &Path, written: &mut Vec<String>) -> Result<String, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("Failed to read image '{}': {}", path.display(), e))?;
+    let hash = format!("{:x}", Sha256::digest(&bytes));
+    let extension = path.extension().map(|ext| ext.to_string_lossy().to_lowercase()).unwrap_or_default();
+    let relative = format!("media/{}.{}", &hash[..16], extension);
+
+    if !written.contains(&relative) {
+        write_file(root, &relative, &bytes, written)?;
+    }
+    Ok(relative)
+}
+
+/// Date and tags line shown under a note title
+fn note_meta(note: &Note) -> String {
+    let tags: String = note.tags.iter().map(|tag| format!("<span class=\"tag\">{}</span>", export_service::escape_html(tag))).collect();
+    format!("<p class=\"meta\">Updated {} {}</p>\n", export_service::format_date(note.last_modified()), tags)
+}
+
+fn page_html(site_title: &str, title: &str, content: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{title}</title>\n<link rel=\"stylesheet\" href=\"style.css\">\n\
+         <link rel=\"alternate\" type=\"application/atom+xml\" title=\"{site}\" href=\"feed.xml\">\n</head>\n<body>\n\
+         <header class=\"site\"><a href=\"index.html\">{site}</a></header>\n<main>\n<h1>{title}</h1>\n{content}</main>\n</body>\n</html>\n",
+        title = export_service::escape_html(title),
+        site = export_service::escape_html(site_title),
+        content = content,
+    )
+}
+
+fn index_html(site_title: &str, pages: &[&Page]) -> String {
+    let mut list = String::from("<ul class=\"notes\">\n");
+    for page in pages {
+        list.push_str(&format!(
+            "<li><a href=\"{}\"><strong>{}</strong></a>\n{}<div>{}</div></li>\n",
+            page.file_name,
+            export_service::escape_html(&page.note.title),
+            note_meta(page.note),
+            export_service::escape_html(&summary(page.note)),
+        ));
+    }
+    list.push_str("</ul>\n");
+
+    page_html(site_title, site_title, &list)
+}
+
+/// First words of a note as plain text, wiki links reduced to their label
+fn summary(note: &Note) -> String {
+    let mut text = String::new();
+    for event in Parser::new_ext(&note.details, export_service::markdown_options()) {
+        match event {
+            Event::Text(value) | Event::Code(value) => text.push_str(&value),
+            Event::End(TagEnd::Paragraph) | Event::End(TagEnd::Heading(_)) => text = strip_wiki_links(&text) + " ",
+            Event::SoftBreak | Event::HardBreak | Event::End(_) if !text.ends_with(' ') => text.push(' '),
+            _ => {}
+        }
+        if text.chars().count() > SUMMARY_CHARS {
+            let cut: String = strip_wiki_links(&text).chars().take(SUMMARY_CHARS).collect();
+            return format!("{}...", cut.trim_end());
+        }
+    }
+    strip_wiki_links(text.trim())
+}
+
+fn strip_wiki_links(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("[[") {
+        let Some(length) = rest[start + 2..].find("]]") else { break };
+        let inner = &rest[start + 2..start + 2 + length];
+        stripped.push_str(&rest[..start]);
+        stripped.push_str(inner.split_once('|').map(|(_, label)| label).unwrap_or(inner).trim());
+        rest = &rest[start + 2 + length + 2..];
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+fn feed_xml(settings: &PublishSettings, site_title: &str, pages: &[&Page], bodies: &HashMap<i32, String>) -> String {
+    use atom_syndication::{Content, Entry, Feed, FixedDateTime, Link};
+
+    let base_url = settings.base_url.trim().trim_end_matches('/');
+    let url = |file_name: &str| if base_url.is_empty() { file_name.to_string() } else { format!("{}/{}", base_url, file_name) };
+    let date = |timestamp: i64| -> FixedDateTime {
+        chrono::DateTime::from_timestamp(timestamp, 0).unwrap_or_default().fixed_offset()
+    };
+    let link = |href: String, rel: &str| {
+        let mut link = Link::default();
+        link.set_href(href);
+        link.set_rel(rel);
+        link
+    };
+
+    let entries: Vec<Entry> = pages
+        .iter()
+        .take(FEED_ENTRIES)
+        .map(|page| {
+            let mut content = Content::default();
+            content.set_content_type(Some("html".to_string()));
+            content.set_value(bodies.get(&page.note.id).cloned());
+
+            let mut entry = Entry::default();
+            entry.set_title(page.note.title.as_str());
+            entry.set_id(url(&page.file_name));
+            entry.set_updated(date(page.note.last_modified()));
+            entry.set_published(Some(date(page.note.created_at)));
+            entry.set_links(vec![link(url(&page.file_name), "alternate")]);
+            entry.set_content(Some(content));
+            entry
+        })
+        .collect();
+
+    let mut feed = Feed::default();
+    feed.set_title(site_title);
+    feed.set_id(url("index.html"));
+    feed.set_updated(date(pages.first().map(|page| page.note.last_modified()).unwrap_or_default()));
+    feed.set_links(vec![link(url("index.html"), "alternate"), link(url("feed.xml"), "self")]);
+    feed.set_entries(entries);
+    feed.to_string()
+}
+
+/// Write a file below the output folder and remember it for the manifest
+fn write_file(root: &Path, relative: &str, bytes: &[u8], written: &mut Vec<String>) -> Result<(), String> {
+    let path = root.join(relative);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create folder '{}': {}", parent.display(), e))?;
+    }
+    std::fs::write(&path, bytes).map_err(|e| format!("Failed to write '{}': {}", path.display(), e))?;
+    written.push(relative.to_string());
+    Ok(())
+}
+
+/// Remove files the last publish wrote that aren't part of the site anymore
+fn remove_stale_files(root: &Path, written: &[String]) -> Result<usize, String> {
+    let Ok(manifest) = std::fs::read_to_string(root.join(MANIFEST_FILE)) else {
+        return Ok(0);
+    };
+
+    let mut removed = 0;
+    for relative in manifest.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        // Only ever plain names inside the output folder
+        if written.iter().any(|file| file == relative) || relative.contains("..") || Path::new(relative).is_absolute() {
+            continue;
+        }
+        let path = root.join(relative);
+        if path.is_file() {
+            std::fs::remove_file(&path).map_err(|e| format!("Failed to remove '{}': {}", path.display(), e))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
@@ -1,6 +1,19 @@
 use crate::dal::{FeedsRepository, FeedItemsRepository};
+use std::collections::HashSet;
+use std::path::Path;
+use crate::common::html::{decode_entities, find_tag_end, parse_tag};
+use crate::dal::repositories::feeds_repository::{FeedItemRow, NewFeedRow};
+use crate::models::{Feed, FeedImportReport, FeedItem, FeedItemFilter, FeedItemPage};
 use chrono::{DateTime, Utc};
+use super::export_service::escape_html;
 use super::log_service;
+use reqwest::header::{HeaderMap, CACHE_CONTROL, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED, RETRY_AFTER};
+use reqwest::StatusCode;
+
+/// Longest a server can keep us from fetching a feed, through Cache-Control or Retry-After
+const MAX_FETCH_DELAY_SECONDS: i64 = 24 * 60 * 60;
+/// Wait after a 429 or 503 that does not say for how long
+const DEFAULT_RETRY_SECONDS: i64 = 60 * 60;
 
 pub async fn fetch_and_store_feed(feed_id: i32, feed_url: &str) -> Result<usize, String> {
     // Trim and validate URL
@@ -19,7 +32,10 @@ pub async fn fetch_and_store_feed(feed_id: i32, feed_url: &str) -> Result<usize,
     
     log_service::add_log_entry("INFO", &format!("Fetching feed from: {}", feed_url));
     
-    // Create a client with proper configuration
+    // Validators from the last response, so an unchanged feed comes back as an empty 304
+    let (etag, last_modified, ..) = FeedsRepository::get_fetch_state(feed_id)?;
+    
+    // Create a client with proper configuration (gzip and brotli bodies are decoded transparently)
     let client = reqwest::Client::builder()
         .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
         .redirect(reqwest::redirect::Policy::limited(10))
@@ -28,16 +44,48 @@ pub async fn fetch_and_store_feed(feed_id: i32, feed_url: &str) -> Result<usize,
         .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
     
     // Fetch the RSS feed
-    let response = client.get(&feed_url)
+    let mut request = client.get(&feed_url);
+    if let Some(etag) = &etag {
+        request = request.header(IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = &last_modified {
+        request = request.header(IF_MODIFIED_SINCE, last_modified);
+    }
+    let response = request
         .send()
         .await
         .map_err(|e| format!("Failed to fetch feed from '{}': {}", feed_url, e))?;
     
+    let now = Utc::now().timestamp();
+    
+    // Rate limited or down for maintenance, stay away for as long as the server asks
+    if response.status() == StatusCode::TOO_MANY_REQUESTS || response.status() == StatusCode::SERVICE_UNAVAILABLE {
+        let retry_at = retry_after(response.headers(), now).unwrap_or(now + DEFAULT_RETRY_SECONDS);
+        FeedsRepository::set_retry_at(feed_id, retry_at)?;
+        log_service::add_log_entry("WARNING", &format!("HTTP {} for URL: {}, retrying in {} minutes", response.status(), feed_url, (retry_at - now) / 60));
+        return Err(format!("HTTP error: {} for URL: {}", response.status(), feed_url));
+    }
+    
+    if response.status() == StatusCode::NOT_MODIFIED {
+        // A 304 may carry fresh validators, otherwise the stored ones stay valid
+        let etag = header_value(response.headers(), ETAG.as_str()).or(etag);
+        let last_modified = header_value(response.headers(), LAST_MODIFIED.as_str()).or(last_modified);
+        let next_fetch_at = cache_expiry(response.headers(), now);
+        FeedsRepository::update_fetch_state(feed_id, etag.as_deref(), last_modified.as_deref(), next_fetch_at)?;
+        FeedsRepository::update_last_updated(feed_id, now)?;
+        log_service::add_log_entry("INFO", &format!("Feed not modified: {}", feed_url));
+        return Ok(0);
+    }
+    
     if !response.status().is_success() {
         log_service::add_log_entry("ERROR", &format!("HTTP error {} for URL: {}", response.status(), feed_url));
         return Err(format!("HTTP error: {} for URL: {}", response.status(), feed_url));
     }
     
+    let new_etag = header_value(response.headers(), ETAG.as_str());
+    let new_last_modified = header_value(response.headers(), LAST_MODIFIED.as_str());
+    let next_fetch_at = cache_expiry(response.headers(), now);
+    
     let content = response.text()
         .await
         .map_err(|e| format!("Failed to read feed content: {}", e))?;
@@ -52,14 +100,50 @@ pub async fn fetch_and_store_feed(feed_id: i32, feed_url: &str) -> Result<usize,
         return Err("Failed to parse feed as RSS or Atom".to_string());
     };
     
-    // Update last_updated timestamp for the feed using repository
-    let now = Utc::now().timestamp();
+    // Update last_updated timestamp and caching state for the feed using repository
     FeedsRepository::update_last_updated(feed_id, now)?;
+    FeedsRepository::update_fetch_state(feed_id, new_etag.as_deref(), new_last_modified.as_deref(), next_fetch_at)?;
     
     Ok(items_added)
 }
 
-struct FeedItem {
+/// A response header as text, None when missing or not valid UTF-8
+fn header_value(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// When Cache-Control max-age lets us fetch again, None when the response may not be reused
+fn cache_expiry(headers: &HeaderMap, now: i64) -> Option<i64> {
+    let cache_control = header_value(headers, CACHE_CONTROL.as_str())?.to_ascii_lowercase();
+    let directives: Vec<&str> = cache_control.split(',').map(str::trim).collect();
+    if directives.iter().any(|directive| matches!(*directive, "no-cache" | "no-store")) {
+        return None;
+    }
+
+    let max_age = directives
+        .iter()
+        .find_map(|directive| directive.strip_prefix("max-age="))
+        .and_then(|seconds| seconds.trim_matches('"').parse::<i64>().ok())
+        .filter(|seconds| *seconds > 0)?;
+    Some(now + max_age.min(MAX_FETCH_DELAY_SECONDS))
+}
+
+/// When Retry-After lets us fetch again, given either as seconds or as an HTTP date
+fn retry_after(headers: &HeaderMap, now: i64) -> Option<i64> {
+    let value = header_value(headers, RETRY_AFTER.as_str())?;
+    let seconds = match value.parse::<i64>() {
+        Ok(seconds) => seconds,
+        Err(_) => DateTime::parse_from_rfc2822(&value).ok()?.timestamp() - now,
+    };
+    Some(now + seconds.clamp(0, MAX_FETCH_DELAY_SECONDS))
+}
+
+/// An item as read from the feed, before it is stored
+struct ParsedItem {
     title: String,
     link: String,
     description: String,
@@ -67,7 +151,7 @@ struct FeedItem {
     guid: String,
 }
 
-fn parse_rss(content: &str) -> Result<Vec<FeedItem>, String> {
+fn parse_rss(content: &str) -> Result<Vec<ParsedItem>, String> {
     let channel = rss::Channel::read_from(content.as_bytes())
         .map_err(|e| format!("RSS parse error: {}", e))?;
     
@@ -95,7 +179,7 @@ fn parse_rss(content: &str) -> Result<Vec<FeedItem>, String> {
                 .map(|g| g.value().to_string())
                 .unwrap_or_else(|| link.clone());
             
-            FeedItem {
+            ParsedItem {
                 title,
                 link,
                 description,
@@ -108,7 +192,7 @@ fn parse_rss(content: &str) -> Result<Vec<FeedItem>, String> {
     Ok(items)
 }
 
-fn parse_atom(content: &str) -> Result<Vec<FeedItem>, String> {
+fn parse_atom(content: &str) -> Result<Vec<ParsedItem>, String> {
     let feed = atom_syndication::Feed::read_from(content.as_bytes())
         .map_err(|e| format!("Atom parse error: {}", e))?;
     
@@ -134,7 +218,7 @@ fn parse_atom(content: &str) -> Result<Vec<FeedItem>, String> {
             
             let guid = entry.id().to_string();
             
-            FeedItem {
+            ParsedItem {
                 title,
                 link,
                 description,
@@ -147,7 +231,7 @@ fn parse_atom(content: &str) -> Result<Vec<FeedItem>, String> {
     Ok(items)
 }
 
-fn store_feed_items(feed_id: i32, items: Vec<FeedItem>) -> Result<usize, String> {
+fn store_feed_items(feed_id: i32, items: Vec<ParsedItem>) -> Result<usize, String> {
     let now = Utc::now().timestamp();
     let mut items_added = 0;
     
@@ -171,7 +255,9 @@ fn store_feed_items(feed_id: i32, items: Vec<FeedItem>) -> Result<usize, String>
     Ok(items_added)
 }
 
-pub async fn refresh_all_feeds() -> Result<String, String> {
+/// Fetch every feed that is due. Forced (manual) refreshes don't wait for Cache-Control max-age,
+/// but still send the validators and leave feeds alone that asked to be retried later.
+pub async fn refresh_all_feeds(force: bool) -> Result<String, String> {
     // Get all feeds from repository
     let feeds = FeedsRepository::get_all()?;
     
@@ -179,8 +265,28 @@ pub async fn refresh_all_feeds() -> Result<String, String> {
     
     let mut total_items = 0;
     let mut errors = Vec::new();
+    let mut skipped = 0;
+    let now = Utc::now().timestamp();
     
     for (feed_id, feed_url, feed_title) in feeds {
+        let (_, _, next_fetch_at, retry_at) = match FeedsRepository::get_fetch_state(feed_id) {
+            Ok(state) => state,
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Feed {} ({}) error: {}", feed_id, feed_title, e));
+                errors.push(format!("{}: {}", feed_title, e));
+                continue;
+            }
+        };
+        // The server asked us to back off, or the last response is still fresh per Cache-Control
+        let wait_until = retry_at.into_iter().chain(next_fetch_at.filter(|_| !force)).max();
+        if let Some(wait_until) = wait_until
+            && wait_until > now
+        {
+            log_service::add_log_entry("INFO", &format!("Skipping feed {} ({}) until {}", feed_id, feed_title, wait_until));
+            skipped += 1;
+            continue;
+        }
+        
         log_service::add_log_entry("INFO", &format!("Processing feed {}: {} ({})", feed_id, feed_title, feed_url));
         // Small delay to avoid overwhelming servers
         tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
@@ -198,13 +304,214 @@ pub async fn refresh_all_feeds() -> Result<String, String> {
     }
     
     if errors.is_empty() {
-        Ok(format!("Successfully refreshed feeds. Added {} new items.", total_items))
+        Ok(format!("Successfully refreshed feeds. Added {} new items, {} feeds not due yet.", total_items, skipped))
     } else {
         Ok(format!(
-            "Refreshed feeds with {} errors. Added {} items.\nErrors:\n{}",
+            "Refreshed feeds with {} errors. Added {} items, {} feeds not due yet.\nErrors:\n{}",
             errors.len(),
             total_items,
+            skipped,
             errors.join("\n")
         ))
     }
-}
\ No newline at end of file
+}
+
+/////
+/// Read and starred state
+/////
+/// Subscriptions by title with their unread counts
+pub fn get_feeds() -> Result<Vec<Feed>, String> {
+    Ok(FeedsRepository::get_all_with_unread_counts()?
+        .into_iter()
+        .map(|(id, url, title, category, unread_count)| Feed { id, url, title, category, unread_count })
+        .collect())
+}
+
+/// One page of the items matching the filter, newest first, continuing after the item given as
+/// (pub_date, id) or from the newest one
+pub fn get_feed_items_page(filter: FeedItemFilter, after: Option<(i64, i32)>, page_size: usize) -> Result<FeedItemPage, String> {
+    let total = FeedItemsRepository::count_filtered(filter.feed_id, filter.unread_only, filter.starred_only)? as usize;
+    // One extra row tells whether there is a next page
+    let mut items: Vec<FeedItem> = FeedItemsRepository::get_filtered(
+        filter.feed_id,
+        filter.unread_only,
+        filter.starred_only,
+        after,
+        page_size as i64 + 1,
+    )?
+    .into_iter()
+    .map(to_feed_item)
+    .collect();
+    let has_more = items.len() > page_size;
+    items.truncate(page_size);
+    Ok(FeedItemPage { items, total, has_more })
+}
+
+pub fn set_read(item_id: i32, is_read: bool) -> Result<(), String> {
+    FeedItemsRepository::set_read(item_id, is_read)
+}
+
+pub fn set_starred(item_id: i32, is_starred: bool) -> Result<(), String> {
+    FeedItemsRepository::set_starred(item_id, is_starred)
+}
+
+/// Mark everything read, in one feed or in all of them (None), returns how many items changed
+pub fn mark_all_read(feed_id: Option<i32>) -> Result<usize, String> {
+    let count = FeedItemsRepository::mark_all_read(feed_id)?;
+    log_service::add_log_entry("INFO", &format!("Marked {} feed item(s) read.", count));
+    Ok(count)
+}
+
+fn to_feed_item(row: FeedItemRow) -> FeedItem {
+    let (id, feed_id, feed_title, title, link, description, pub_date, is_read, is_starred) = row;
+    FeedItem { id, feed_id, feed_title, title, link, description, pub_date, is_read, is_starred }
+}
+
+/////
+/// OPML import and export
+/// OPML is the subscription list format every feed reader imports and exports.
+/////
+/// Subscribe to the feeds listed in an OPML file, the outlines they sit in become their category
+pub fn import_opml(path: &Path) -> Result<FeedImportReport, String> {
+    let xml = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    if !xml.to_ascii_lowercase().contains("<opml") {
+        return Err(format!("{} is not an OPML file", path.display()));
+    }
+
+    let feeds = parse_opml(&xml);
+    let added = FeedsRepository::create_many(&feeds)?;
+    let report = FeedImportReport { duplicates: feeds.len() - added.len(), added };
+
+    log_service::add_log_entry("INFO", &format!(
+        "Imported {} feed(s) from {} ({} already subscribed).",
+        report.added.len(), path.display(), report.duplicates
+    ));
+    Ok(report)
+}
+
+/// Fetch feeds that were just imported, one after the other like refresh_all_feeds
+pub async fn fetch_imported_feeds(feeds: Vec<(i32, String)>) -> String {
+    let mut total_items = 0;
+    let mut errors = 0;
+
+    for (feed_id, feed_url) in &feeds {
+        match fetch_and_store_feed(*feed_id, feed_url).await {
+            Ok(count) => total_items += count,
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Feed {} ({}) error: {}", feed_id, feed_url, e));
+                errors += 1;
+            }
+        }
+        // Small delay to avoid overwhelming servers
+        tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
+    }
+
+    format!("Fetched {} imported feed(s): {} new items, {} error(s).", feeds.len(), total_items, errors)
+}
+
+/// Write every subscription to an OPML 2.0 file, returns the feed count
+pub fn export_opml(path: &Path) -> Result<usize, String> {
+    let feeds = get_feeds()?;
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opml version=\"2.0\">\n  <head>\n");
+    xml.push_str("    <title>Dry Dock feeds</title>\n");
+    xml.push_str(&format!("    <dateCreated>{}</dateCreated>\n", Utc::now().to_rfc2822()));
+    xml.push_str("  </head>\n  <body>\n");
+
+    // Feeds come sorted by category, so each category's outline is opened once
+    let mut open: Vec<&str> = Vec::new();
+    for feed in &feeds {
+        let categories: Vec<&str> = feed.category.split(" / ").filter(|part| !part.is_empty()).collect();
+        let shared = open.iter().zip(&categories).take_while(|(open, part)| open == part).count();
+        while open.len() > shared {
+            open.pop();
+            xml.push_str(&format!("{}</outline>\n", "  ".repeat(open.len() + 2)));
+        }
+        for part in &categories[shared..] {
+            xml.push_str(&format!("{}<outline text=\"{}\" title=\"{}\">\n", "  ".repeat(open.len() + 2), escape_html(part), escape_html(part)));
+            open.push(part);
+        }
+
+        xml.push_str(&format!(
+            "{}<outline type=\"rss\" text=\"{}\" title=\"{}\" xmlUrl=\"{}\"/>\n",
+            "  ".repeat(open.len() + 2),
+            escape_html(&feed.title),
+            escape_html(&feed.title),
+            escape_html(&feed.url)
+        ));
+    }
+    while open.pop().is_some() {
+        xml.push_str(&format!("{}</outline>\n", "  ".repeat(open.len() + 2)));
+    }
+    xml.push_str("  </body>\n</opml>\n");
+
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(path, xml).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+    log_service::add_log_entry("INFO", &format!("Exported {} feed(s) to {}.", feeds.len(), path.display()));
+    Ok(feeds.len())
+}
+
+/// (url, title, category) of every outline with an xmlUrl, each URL once
+fn parse_opml(xml: &str) -> Vec<NewFeedRow> {
+    let mut feeds: Vec<NewFeedRow> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    // One entry per open <outline>, the title of those that are categories
+    let mut outlines: Vec<Option<String>> = Vec::new();
+
+    let mut rest = xml;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+
+        // Skip comments and the <?xml ...?> declaration whole
+        if rest.starts_with("<!--") {
+            rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+            continue;
+        }
+        if rest.starts_with("<?") {
+            rest = rest.find("?>").map_or("", |end| &rest[end + 2..]);
+            continue;
+        }
+
+        let Some(end) = find_tag_end(rest) else { break };
+        let tag = &rest[1..end];
+        let self_closing = tag.trim_end().ends_with('/');
+        let (name, closing, mut attributes) = parse_tag(tag);
+        rest = &rest[end + 1..];
+
+        if name != "OUTLINE" {
+            continue;
+        }
+        if closing {
+            outlines.pop();
+            continue;
+        }
+
+        let title = ["TITLE", "TEXT"]
+            .iter()
+            .filter_map(|key| attributes.get(*key))
+            .map(|value| decode_entities(value.trim()))
+            .find(|value| !value.is_empty());
+
+        match attributes.remove("XMLURL").map(|url| decode_entities(url.trim())).filter(|url| !url.is_empty()) {
+            Some(url) => {
+                if seen.insert(url.clone()) {
+                    let category = outlines.iter().flatten().cloned().collect::<Vec<_>>().join(" / ");
+                    feeds.push((url.clone(), title.unwrap_or(url), category));
+                }
+                if !self_closing {
+                    outlines.push(None);
+                }
+            }
+            None if !self_closing => outlines.push(title),
+            None => {}
+        }
+    }
+
+    feeds
+}
@@ -0,0 +1,157 @@
+// src/services/settings_service.rs
+
+// Settings that the user can change from inside the app live in the settings table.
+// AppConfig.json stays read-only and only holds what is needed before the DB is up.
+
+use crate::dal::SettingsRepository;
+use crate::models::{NoteMirrorSettings, PublishSettings};
+
+const NOTE_MIRROR_ENABLED_KEY: &str = "notes.mirror.enabled";
+const NOTE_MIRROR_DIRECTORY_KEY: &str = "notes.mirror.directory";
+const PUBLISH_TAG_KEY: &str = "notes.publish.tag";
+const PUBLISH_DIRECTORY_KEY: &str = "notes.publish.directory";
+const PUBLISH_SITE_TITLE_KEY: &str = "notes.publish.site_title";
+const PUBLISH_BASE_URL_KEY: &str = "notes.publish.base_url";
+/// Followed by the browser profile's bookmark file path
+const BOOKMARK_IMPORT_PREFIX: &str = "bookmarks.import.last_added.";
+const LINK_CHECK_LAST_RUN_KEY: &str = "bookmarks.link_check.last_run";
+const SNAPSHOT_INTERVAL_DAYS_KEY: &str = "bookmarks.snapshot.interval_days";
+const SNAPSHOT_KEEP_VERSIONS_KEY: &str = "bookmarks.snapshot.keep_versions";
+const EDITOR_COMMAND_KEY: &str = "bookmarks.editor_command";
+
+/// Load the markdown mirror settings (disabled if never saved)
+pub fn get_note_mirror_settings() -> NoteMirrorSettings {
+    let enabled = SettingsRepository::get(NOTE_MIRROR_ENABLED_KEY)
+        .ok()
+        .flatten()
+        .map(|value| value == "true")
+        .unwrap_or(false);
+
+    let directory = SettingsRepository::get(NOTE_MIRROR_DIRECTORY_KEY)
+        .ok()
+        .flatten()
+        .unwrap_or_else(default_note_mirror_directory);
+
+    NoteMirrorSettings { enabled, directory }
+}
+
+/// Save the markdown mirror settings
+pub fn save_note_mirror_settings(settings: &NoteMirrorSettings) -> Result<(), String> {
+    if settings.enabled && settings.directory.trim().is_empty() {
+        return Err("Mirror folder cannot be empty".to_string());
+    }
+
+    SettingsRepository::set(NOTE_MIRROR_ENABLED_KEY, if settings.enabled { "true" } else { "false" })?;
+    SettingsRepository::set(NOTE_MIRROR_DIRECTORY_KEY, settings.directory.trim())
+}
+
+/// Load the static site publishing settings
+pub fn get_publish_settings() -> PublishSettings {
+    let get = |key: &str| SettingsRepository::get(key).ok().flatten();
+
+    PublishSettings {
+        tag: get(PUBLISH_TAG_KEY).unwrap_or_else(|| "public".to_string()),
+        directory: get(PUBLISH_DIRECTORY_KEY).unwrap_or_else(default_publish_directory),
+        site_title: get(PUBLISH_SITE_TITLE_KEY).unwrap_or_else(|| "Notes".to_string()),
+        base_url: get(PUBLISH_BASE_URL_KEY).unwrap_or_default(),
+    }
+}
+
+/// Save the static site publishing settings
+pub fn save_publish_settings(settings: &PublishSettings) -> Result<(), String> {
+    if settings.tag.trim().is_empty() {
+        return Err("Tag cannot be empty".to_string());
+    }
+    if settings.directory.trim().is_empty() {
+        return Err("Output folder cannot be empty".to_string());
+    }
+
+    SettingsRepository::set(PUBLISH_TAG_KEY, settings.tag.trim())?;
+    SettingsRepository::set(PUBLISH_DIRECTORY_KEY, settings.directory.trim())?;
+    SettingsRepository::set(PUBLISH_SITE_TITLE_KEY, settings.site_title.trim())?;
+    SettingsRepository::set(PUBLISH_BASE_URL_KEY, settings.base_url.trim())
+}
+
+/// Newest "date added" seen the last time bookmarks were imported from a browser profile
+pub fn get_bookmark_import_mark(profile_path: &str) -> Option<i64> {
+    SettingsRepository::get(&format!("{}{}", BOOKMARK_IMPORT_PREFIX, profile_path))
+        .ok()
+        .flatten()
+        .and_then(|value| value.parse().ok())
+}
+
+/// Remember the newest "date added" imported from a browser profile, for the next re-sync
+pub fn save_bookmark_import_mark(profile_path: &str, added_at: i64) -> Result<(), String> {
+    SettingsRepository::set(&format!("{}{}", BOOKMARK_IMPORT_PREFIX, profile_path), &added_at.to_string())
+}
+
+/// When the dead-link checker last went through every bookmark
+pub fn get_link_check_last_run() -> Option<i64> {
+    SettingsRepository::get(LINK_CHECK_LAST_RUN_KEY)
+        .ok()
+        .flatten()
+        .and_then(|value| value.parse().ok())
+}
+
+pub fn save_link_check_last_run(timestamp: i64) -> Result<(), String> {
+    SettingsRepository::set(LINK_CHECK_LAST_RUN_KEY, &timestamp.to_string())
+}
+
+/// Days between automatic re-snapshots of archived pages, 0 = never (default 30)
+pub fn get_snapshot_interval_days() -> u32 {
+    SettingsRepository::get(SNAPSHOT_INTERVAL_DAYS_KEY)
+        .ok()
+        .flatten()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(30)
+}
+
+pub fn save_snapshot_interval_days(days: u32) -> Result<(), String> {
+    SettingsRepository::set(SNAPSHOT_INTERVAL_DAYS_KEY, &days.to_string())
+}
+
+/// Snapshot versions kept per bookmark, older ones are deleted after archiving, 0 = all (default 10)
+pub fn get_snapshot_keep_versions() -> u32 {
+    SettingsRepository::get(SNAPSHOT_KEEP_VERSIONS_KEY)
+        .ok()
+        .flatten()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(10)
+}
+
+pub fn save_snapshot_keep_versions(versions: u32) -> Result<(), String> {
+    SettingsRepository::set(SNAPSHOT_KEEP_VERSIONS_KEY, &versions.to_string())
+}
+
+/// Command that opens a folder bookmark in an editor, the path is appended (default "code")
+pub fn get_editor_command() -> String {
+    SettingsRepository::get(EDITOR_COMMAND_KEY)
+        .ok()
+        .flatten()
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "code".to_string())
+}
+
+pub fn save_editor_command(command: &str) -> Result<(), String> {
+    SettingsRepository::set(EDITOR_COMMAND_KEY, command.trim())
+}
+
+/// Default mirror folder: ~/Documents/DryDock Notes
+fn default_note_mirror_directory() -> String {
+    dirs::document_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join("DryDock Notes")
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Default publish folder: ~/Documents/DryDock Site
+fn default_publish_directory() -> String {
+    dirs::document_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join("DryDock Site")
+        .to_string_lossy()
+        .to_string()
+}
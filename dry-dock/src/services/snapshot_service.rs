@@ -0,0 +1,438 @@
+// src/services/snapshot_service.rs
+
+// Pages disappear or change, so a web bookmark can be archived: the page's HTML is saved with its
+// stylesheets, images and icons inlined as data URIs, giving one self-contained file that opens
+// offline in the default browser. Scripts are dropped, the copy is what the page looked like.
+// Each archive run adds a new version; bookmarks that have been archived once are re-archived
+// every few days in the background (see BackgroundServiceManager). Only the newest few versions
+// are kept, older ones are deleted after each archive.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use crate::common::helper::get_data_path;
+use crate::common::html::{decode_entities, find_tag_end, parse_tag};
+use crate::common::http::{content_type, read_body_limited};
+use crate::dal::{BookmarkSnapshotsRepository, BookmarksRepository};
+use crate::dal::repositories::bookmark_snapshots_repository::SnapshotRow;
+use crate::models::BookmarkSnapshot;
+use super::export_service::escape_html;
+use super::{log_service, settings_service};
+
+/// Stop inlining after this many stylesheets/images, the rest stay links to the live site
+const MAX_RESOURCES: usize = 300;
+/// Pages and resources are downloaded no further than these limits
+const MAX_PAGE_BYTES: usize = 10 * 1024 * 1024;
+const MAX_RESOURCE_BYTES: usize = 5 * 1024 * 1024;
+const MAX_TOTAL_BYTES: usize = 50 * 1024 * 1024;
+
+/// Archive a bookmarked page as a new snapshot version
+pub async fn archive_page(bookmark_id: i32) -> Result<BookmarkSnapshot, String> {
+    let (_, _, location, ..) = BookmarksRepository::get_by_id(bookmark_id)?;
+    if !location.starts_with("http://") && !location.starts_with("https://") {
+        return Err("Only web bookmarks can be archived".to_string());
+    }
+
+    let client = reqwest::Client::builder()
+        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
+        .redirect(reqwest::redirect::Policy::limited(10))
+        .timeout(std::time::Duration::from_secs(30))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client
+        .get(&location)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch {}: {}", location, e))?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP error {} for {}", response.status(), location));
+    }
+
+    let page_url = response.url().clone();
+    let (bytes, truncated) = read_body_limited(response, MAX_PAGE_BYTES)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", location, e))?;
+    if truncated {
+        return Err(format!("{} is larger than {} MB", location, MAX_PAGE_BYTES / (1024 * 1024)));
+    }
+    let html = String::from_utf8_lossy(&bytes).to_string();
+
+    let mut archiver = Archiver { client, resources: HashMap::new(), total_bytes: 0 };
+    let archived = archiver.archive(&html, &page_url).await;
+
+    let now = chrono::Utc::now();
+    let path = snapshot_dir(bookmark_id).join(format!("{}.html", now.format("%Y%m%d-%H%M%S")));
+    std::fs::write(&path, &archived)
+        .map_err(|e| format!("Failed to save snapshot: {}", e))?;
+
+    let size_bytes = archived.len() as i64;
+    let id = BookmarkSnapshotsRepository::create(bookmark_id, &path.to_string_lossy(), size_bytes, now.timestamp())?;
+    log_service::add_log_entry("INFO", &format!(
+        "Archived {} ({} KB, {} resource(s) inlined).",
+        location, size_bytes / 1024, archiver.resources.values().filter(|uri| uri.is_some()).count()
+    ));
+    prune_snapshots(bookmark_id);
+
+    Ok(BookmarkSnapshot { id, path, size_bytes, created_at: now.timestamp() })
+}
+
+/// Snapshot versions of a bookmark, newest first
+pub fn get_snapshots(bookmark_id: i32) -> Result<Vec<BookmarkSnapshot>, String> {
+    Ok(BookmarkSnapshotsRepository::get_for_bookmark(bookmark_id)?.into_iter().map(to_snapshot).collect())
+}
+
+/// Open a snapshot in the default browser
+pub fn open_snapshot(snapshot_id: i32) -> Result<(), String> {
+    let snapshot = to_snapshot(BookmarkSnapshotsRepository::get_by_id(snapshot_id)?);
+    if !snapshot.path.is_file() {
+        return Err(format!("Snapshot file is missing: {}", snapshot.path.display()));
+    }
+    opener::open(&snapshot.path).map_err(|e| format!("Failed to open snapshot: {}", e))
+}
+
+/// Delete a snapshot version and its file
+pub fn delete_snapshot(snapshot_id: i32) -> Result<(), String> {
+    let snapshot = to_snapshot(BookmarkSnapshotsRepository::get_by_id(snapshot_id)?);
+    if snapshot.path.is_file() {
+        std::fs::remove_file(&snapshot.path)
+            .map_err(|e| format!("Failed to delete snapshot file: {}", e))?;
+    }
+    BookmarkSnapshotsRepository::delete(snapshot_id)
+}
+
+/// Delete the versions of a bookmark beyond the configured number to keep, oldest first
+fn prune_snapshots(bookmark_id: i32) {
+    let keep = settings_service::get_snapshot_keep_versions() as usize;
+    if keep == 0 {
+        return;
+    }
+
+    let snapshots = match get_snapshots(bookmark_id) {
+        Ok(snapshots) => snapshots,
+        Err(e) => {
+            log_service::add_log_entry("ERROR", &format!("Failed to prune snapshots of bookmark {}: {}", bookmark_id, e));
+            return;
+        }
+    };
+    for snapshot in snapshots.into_iter().skip(keep) {
+        if let Err(e) = delete_snapshot(snapshot.id) {
+            log_service::add_log_entry("WARNING", &format!("Failed to delete old snapshot {}: {}", snapshot.path.display(), e));
+        }
+    }
+}
+
+/// Remove every snapshot file of a bookmark before it is deleted, the rows go with the bookmark.
+/// Merged duplicates bring snapshots saved under their own folder, so files are removed one by one.
+pub fn delete_snapshot_files(bookmark_id: i32) {
+    let paths = get_snapshots(bookmark_id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|snapshot| snapshot.path);
+    for path in paths.filter(|path| path.is_file()) {
+        if let Err(e) = std::fs::remove_file(&path) {
+            log_service::add_log_entry("WARNING", &format!("Failed to remove snapshot {}: {}", path.display(), e));
+        }
+    }
+
+    // Empty by now unless something else was put there
+    let _ = std::fs::remove_dir(snapshot_dir(bookmark_id));
+}
+
+/// Re-archive every archived bookmark whose newest snapshot is older than the configured interval.
+/// Returns how many new snapshots were taken.
+pub async fn refresh_due_snapshots() -> Result<usize, String> {
+    let interval_days = settings_service::get_snapshot_interval_days();
+    if interval_days == 0 {
+        return Ok(0);
+    }
+
+    let cutoff = chrono::Utc::now().timestamp() - i64::from(interval_days) * 24 * 60 * 60;
+    let mut taken = 0;
+    for (bookmark_id, latest) in BookmarkSnapshotsRepository::get_latest_per_bookmark()? {
+        if latest > cutoff {
+            continue;
+        }
+        match archive_page(bookmark_id).await {
+            Ok(_) => taken += 1,
+            Err(e) => log_service::add_log_entry("ERROR", &format!("Failed to re-archive bookmark {}: {}", bookmark_id, e)),
+        }
+    }
+    Ok(taken)
+}
+
+fn snapshot_dir(bookmark_id: i32) -> PathBuf {
+    let dir = get_data_path("DryDock", "snapshots").join(bookmark_id.to_string());
+    std::fs::create_dir_all(&dir).ok();
+    dir
+}
+
+fn to_snapshot((id, _, file_path, size_bytes, created_at): SnapshotRow) -> BookmarkSnapshot {
+    BookmarkSnapshot { id, path: PathBuf::from(file_path), size_bytes, created_at }
+}
+
+/// Fetches a page's resources once each and turns them into data URIs
+struct Archiver {
+    client: reqwest::Client,
+    /// Resolved URL -> data URI, None when it couldn't be fetched
+    resources: HashMap<String, Option<String>>,
+    total_bytes: usize,
+}
+
+impl Archiver {
+    /// Rewrite a page so it stands on its own. Tags we don't care about are copied as they are.
+    async fn archive(&mut self, html: &str, page_url: &reqwest::Url) -> String {
+        let mut output = String::with_capacity(html.len());
+        // A <base> keeps whatever wasn't inlined (links, iframes, skipped images) pointing at the live site
+        let preamble = format!(
+            "<meta charset=\"utf-8\">\n<base href=\"{}\">\n",
+            escape_html(page_url.as_str())
+        );
+        let mut preamble_written = false;
+
+        let mut rest = html;
+        while let Some(start) = rest.find('<') {
+            output.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            if rest.starts_with("<!") {
+                let end = if rest.starts_with("<!--") { rest.find("-->").map(|end| end + 3) } else { rest.find('>').map(|end| end + 1) };
+                let end = end.unwrap_or(rest.len());
+                output.push_str(&rest[..end]);
+                rest = &rest[end..];
+                continue;
+            }
+
+            let Some(end) = find_tag_end(rest) else { break };
+            let (name, closing, mut attributes) = parse_tag(&rest[1..end]);
+            let original = &rest[..=end];
+            rest = &rest[end + 1..];
+
+            if closing {
+                if name != "NOSCRIPT" {
+                    output.push_str(original);
+                }
+                continue;
+            }
+
+            let attribute = |attributes: &HashMap<String, String>, key: &str| {
+                attributes.get(key).map(|value| decode_entities(value).trim().to_string())
+            };
+
+            match name.as_str() {
+                "HEAD" => {
+                    output.push_str(original);
+                    output.push_str(&preamble);
+                    preamble_written = true;
+                }
+                // Their contents may hold '<', find the closing tag before going on
+                "SCRIPT" | "STYLE" => {
+                    let close = format!("</{}", name.to_ascii_lowercase());
+                    let content_end = rest.to_ascii_lowercase().find(&close).unwrap_or(rest.len());
+                    if name == "STYLE" {
+                        output.push_str(original);
+                        output.push_str(&self.inline_css(&rest[..content_end], page_url).await);
+                        rest = &rest[content_end..];
+                    } else {
+                        rest = &rest[content_end..];
+                        rest = rest.find('>').map_or("", |index| &rest[index + 1..]);
+                    }
+                }
+                // Scripts are gone, so show what the page shows without them
+                "NOSCRIPT" | "BASE" => {}
+                "META" => {
+                    let http_equiv = attribute(&attributes, "HTTP-EQUIV").unwrap_or_default().to_lowercase();
+                    // Charset is ours now, and a CSP or refresh would break the offline copy
+                    if attributes.contains_key("CHARSET") || matches!(http_equiv.as_str(), "content-type" | "content-security-policy" | "refresh") {
+                        continue;
+                    }
+                    output.push_str(original);
+                }
+                "LINK" => {
+                    let rel = attribute(&attributes, "REL").unwrap_or_default().to_lowercase();
+                    let href = attribute(&attributes, "HREF").and_then(|href| page_url.join(&href).ok());
+                    let rels: Vec<&str> = rel.split_whitespace().collect();
+
+                    if rels.iter().any(|rel| matches!(*rel, "preload" | "prefetch" | "modulepreload" | "dns-prefetch" | "preconnect")) {
+                        continue;
+                    }
+                    if rels.contains(&"stylesheet") && let Some(href) = href {
+                        match self.fetch_text(&href).await {
+                            Some(css) => {
+                                let media = attribute(&attributes, "MEDIA")
+                                    .map(|media| format!(" media=\"{}\"", escape_html(&media)))
+                                    .unwrap_or_default();
+                                output.push_str(&format!("<style{}>\n{}\n</style>", media, self.inline_css(&css, &href).await));
+                            }
+                            None => output.push_str(original),
+                        }
+                        continue;
+                    }
+                    if rels.iter().any(|rel| rel.contains("icon")) && let Some(href) = href
+                        && let Some(data_uri) = self.data_uri(&href).await
+                    {
+                        attributes.insert("HREF".to_string(), data_uri);
+                        output.push_str(&write_tag(&name, &attributes, false));
+                        continue;
+                    }
+                    output.push_str(original);
+                }
+                "IMG" | "SOURCE" | "INPUT" => {
+                    // Lazy loaders keep the real address in data-src until a script swaps it in
+                    let src = attribute(&attributes, "DATA-SRC")
+                        .or_else(|| attribute(&attributes, "SRC"))
+                        .filter(|src| !src.starts_with("data:"))
+                        .and_then(|src| page_url.join(&src).ok());
+                    if name == "SOURCE" && src.is_none() {
+                        // <picture> sources only have srcset, the <img> fallback is archived instead
+                        continue;
+                    }
+                    if let Some(src) = src
+                        && let Some(data_uri) = self.data_uri(&src).await
+                    {
+                        attributes.insert("SRC".to_string(), data_uri);
+                        attributes.retain(|key, _| !matches!(key.as_str(), "SRCSET" | "SIZES" | "DATA-SRC" | "DATA-SRCSET" | "LOADING"));
+                        attributes.retain(|key, _| !key.starts_with("ON"));
+                        output.push_str(&write_tag(&name, &attributes, false));
+                        continue;
+                    }
+                    self.write_rewritten(&mut output, original, &name, attributes, page_url).await;
+                }
+                _ => self.write_rewritten(&mut output, original, &name, attributes, page_url).await,
+            }
+        }
+        output.push_str(rest);
+
+        let saved_from = format!(
+            "<!-- Saved by Dry Dock from {} on {} -->\n",
+            page_url.as_str().replace("--", "%2D%2D"),
+            chrono::Local::now().format("%Y-%m-%d %H:%M")
+        );
+        if !preamble_written {
+            output.insert_str(0, &preamble);
+        }
+        output.insert_str(0, &saved_from);
+        output
+    }
+
+    /// Write a tag without event handlers, inlining images in its style attribute.
+    /// Tags that need neither are copied untouched (inline SVG attributes are case sensitive).
+    async fn write_rewritten(&mut self, output: &mut String, original: &str, name: &str, mut attributes: HashMap<String, String>, page_url: &reqwest::Url) {
+        let style = attributes.get("STYLE").filter(|style| style.contains("url(")).cloned();
+        let has_handlers = attributes.keys().any(|key| key.starts_with("ON"));
+        if style.is_none() && !has_handlers {
+            output.push_str(original);
+            return;
+        }
+
+        attributes.retain(|key, _| !key.starts_with("ON"));
+        if let Some(style) = style {
+            let style = self.inline_css(&decode_entities(&style), page_url).await;
+            attributes.insert("STYLE".to_string(), style.replace('&', "&amp;"));
+        }
+        output.push_str(&write_tag(name, &attributes, original.ends_with("/>")));
+    }
+
+    /// Replace every url(...) in a stylesheet with a data URI, relative to the stylesheet's address.
+    /// Imported stylesheets are inlined as they are, their own relative urls aren't followed.
+    async fn inline_css(&mut self, css: &str, base_url: &reqwest::Url) -> String {
+        let mut output = String::with_capacity(css.len());
+        let mut rest = css;
+        while let Some(start) = rest.find("url(") {
+            output.push_str(&rest[..start]);
+            rest = &rest[start + 4..];
+            let Some(end) = rest.find(')') else {
+                output.push_str("url(");
+                break;
+            };
+
+            let target = rest[..end].trim().trim_matches(|c| c == '"' || c == '\'');
+            let data_uri = match base_url.join(target) {
+                Ok(url) if !target.starts_with("data:") && !target.starts_with('#') => self.data_uri(&url).await,
+                _ => None,
+            };
+            match data_uri {
+                Some(data_uri) => output.push_str(&format!("url(\"{}\")", data_uri)),
+                None => output.push_str(&format!("url({})", &rest[..end])),
+            }
+            rest = &rest[end + 1..];
+        }
+        output.push_str(rest);
+        output
+    }
+
+    async fn fetch_text(&mut self, url: &reqwest::Url) -> Option<String> {
+        let (_, bytes) = self.fetch(url).await?;
+        Some(String::from_utf8_lossy(&bytes).to_string())
+    }
+
+    async fn data_uri(&mut self, url: &reqwest::Url) -> Option<String> {
+        if let Some(cached) = self.resources.get(url.as_str()) {
+            return cached.clone();
+        }
+        let data_uri = self
+            .fetch(url)
+            .await
+            .map(|(mime, bytes)| format!("data:{};base64,{}", mime, BASE64.encode(bytes)));
+        self.resources.insert(url.to_string(), data_uri.clone());
+        data_uri
+    }
+
+    /// (mime type, body) of a resource, None once the limits are reached or when the fetch fails
+    async fn fetch(&mut self, url: &reqwest::Url) -> Option<(String, Vec<u8>)> {
+        if !matches!(url.scheme(), "http" | "https")
+            || self.resources.len() >= MAX_RESOURCES
+            || self.total_bytes >= MAX_TOTAL_BYTES
+        {
+            return None;
+        }
+
+        let response = self.client.get(url.clone()).send().await.ok()?;
+        if !response.status().is_success() {
+            return None;
+        }
+        let mime = content_type(&response).unwrap_or_else(|| guess_mime(url.path()).to_string());
+
+        // Never read past what the archive still has room for, a cut off resource is left out
+        let limit = MAX_RESOURCE_BYTES.min(MAX_TOTAL_BYTES - self.total_bytes);
+        let (bytes, truncated) = read_body_limited(response, limit).await.ok()?;
+        if truncated {
+            return None;
+        }
+
+        self.total_bytes += bytes.len();
+        Some((mime, bytes))
+    }
+}
+
+/// Serialize a tag parsed by parse_tag. Attribute values are kept encoded as they were read.
+fn write_tag(name: &str, attributes: &HashMap<String, String>, self_closing: bool) -> String {
+    let mut keys: Vec<&String> = attributes.keys().collect();
+    keys.sort();
+
+    let mut tag = format!("<{}", name.to_ascii_lowercase());
+    for key in keys {
+        tag.push_str(&format!(" {}=\"{}\"", key.to_ascii_lowercase(), attributes[key].replace('"', "&quot;")));
+    }
+    tag.push_str(if self_closing { "/>" } else { ">" });
+    tag
+}
+
+fn guess_mime(path: &str) -> &'static str {
+    let extension = path.rsplit('.').next().unwrap_or_default().to_lowercase();
+    match extension.as_str() {
+        "css" => "text/css",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "avif" => "image/avif",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        _ => "application/octet-stream",
+    }
+}
@@ -0,0 +1,35 @@
+// src/services/tag_service.rs
+
+// Tags are shared by notes and bookmarks. They are lowercase words (letters, digits, - and _)
+// and stored in a single column as ",a,b," so a LIKE '%,tag,%' finds one tag.
+
+/// A single tag, "#Public" and "public" are the same tag
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim()
+        .trim_start_matches('#')
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Tags typed by the user ("Public, rust #gui") in their stored form, without duplicates
+pub fn normalize_tags(tags: &str) -> String {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags.split([',', ' ']).map(normalize_tag) {
+        if !tag.is_empty() && !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+
+    if normalized.is_empty() {
+        String::new()
+    } else {
+        format!(",{},", normalized.join(","))
+    }
+}
+
+/// Stored tags back into a list
+pub fn split_tags(stored: &str) -> Vec<String> {
+    stored.split(',').filter(|tag| !tag.is_empty()).map(str::to_string).collect()
+}
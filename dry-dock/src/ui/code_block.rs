@@ -0,0 +1,158 @@
+// src/ui/code_block.rs
+use eframe::egui;
+use egui::text::{LayoutJob, TextFormat};
+use once_cell::sync::Lazy;
+use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag, TagEnd};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{FontStyle, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+use crate::ui::styles::Theme;
+
+// Loading the bundled grammars takes a moment, so do it once on first use
+static SYNTAX_SET: Lazy<SyntaxSet> = Lazy::new(SyntaxSet::load_defaults_newlines);
+static THEME_SET: Lazy<ThemeSet> = Lazy::new(ThemeSet::load_defaults);
+
+/// Bundled syntect theme that matches the dark UI
+const CODE_THEME: &str = "base16-ocean.dark";
+
+/// Fenced code block with syntax highlighting, a language label and a Copy button
+/// Long lines scroll horizontally instead of wrapping
+pub fn render_code_block(ui: &mut egui::Ui, id_salt: impl std::hash::Hash, language: &str, code: &str) {
+    let code = code.trim_end_matches('\n');
+
+    egui::Frame::new()
+        .fill(Theme::BG_DARKER)
+        .stroke(egui::Stroke::new(1.0, Theme::BORDER_COLOR))
+        .corner_radius(6.0)
+        .inner_margin(egui::Margin::same(8))
+        .show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let label = if language.is_empty() { "text" } else { language };
+                ui.label(egui::RichText::new(label)
+                    .size(Theme::FONT_SIZE_SMALL)
+                    .color(Theme::TEXT_MUTED));
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.small_button("Copy").on_hover_text("Copy to clipboard").clicked() {
+                        ui.ctx().copy_text(code.to_string());
+                    }
+                });
+            });
+
+            ui.add_space(4.0);
+
+            // Highlighting is cached across frames while the block stays on screen, so scrolling a
+            // long note doesn't re-run syntect
+            let job = ui.ctx().memory_mut(|mem| {
+                mem.caches.cache::<HighlightCache>().get((code, language))
+            });
+
+            egui::ScrollArea::horizontal()
+                .id_salt(("code_block", id_salt))
+                .show(ui, |ui| {
+                    ui.add(egui::Label::new(job).wrap_mode(egui::TextWrapMode::Extend));
+                });
+        });
+}
+
+/// Language of a code block from the first word of its fence info ("rust ignore" -> "rust")
+pub fn code_block_language(kind: &CodeBlockKind) -> String {
+    match kind {
+        CodeBlockKind::Fenced(info) => info.split_whitespace().next().unwrap_or("").to_string(),
+        CodeBlockKind::Indented => String::new(),
+    }
+}
+
+/// Plain text with fenced code blocks drawn by render_code_block (used for assistant replies)
+pub fn render_text_with_code_blocks(ui: &mut egui::Ui, id_salt: impl std::hash::Hash + Copy, text: &str, text_color: egui::Color32) {
+    let mut last = 0;
+    let mut code_block: Option<(String, String)> = None;
+    let mut code_block_index = 0;
+
+    for (event, range) in Parser::new(text).into_offset_iter() {
+        match event {
+            // Indented blocks stay plain text, chat replies indent prose far more often than code
+            Event::Start(Tag::CodeBlock(kind @ CodeBlockKind::Fenced(_))) => {
+                // Everything between code blocks is shown as is
+                label_between(ui, &text[last..range.start], text_color);
+                code_block = Some((code_block_language(&kind), String::new()));
+                last = range.end;
+            }
+            Event::Text(code) => {
+                if let Some((_, buffer)) = code_block.as_mut() {
+                    buffer.push_str(&code);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                if let Some((language, code)) = code_block.take() {
+                    render_code_block(ui, (id_salt, code_block_index), &language, &code);
+                    code_block_index += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    label_between(ui, &text[last.min(text.len())..], text_color);
+}
+
+fn label_between(ui: &mut egui::Ui, text: &str, text_color: egui::Color32) {
+    let text = text.trim_matches('\n');
+    if !text.trim().is_empty() {
+        ui.label(egui::RichText::new(text)
+            .size(Theme::FONT_SIZE_BODY)
+            .color(text_color));
+    }
+}
+
+#[derive(Default)]
+struct Highlighter;
+
+impl egui::cache::ComputerMut<(&str, &str), LayoutJob> for Highlighter {
+    fn compute(&mut self, (code, language): (&str, &str)) -> LayoutJob {
+        highlight(code, language)
+    }
+}
+
+type HighlightCache = egui::cache::FrameCache<LayoutJob, Highlighter>;
+
+/// Turn code into a LayoutJob colored by syntect (unknown languages fall back to plain text)
+fn highlight(code: &str, language: &str) -> LayoutJob {
+    let font_id = egui::FontId::monospace(Theme::FONT_SIZE_SMALL);
+    let mut job = LayoutJob::default();
+
+    let syntax = SYNTAX_SET
+        .find_syntax_by_token(language)
+        .unwrap_or_else(|| SYNTAX_SET.find_syntax_plain_text());
+    let Some(theme) = THEME_SET.themes.get(CODE_THEME) else {
+        job.append(code, 0.0, TextFormat::simple(font_id, Theme::TEXT_PRIMARY));
+        return job;
+    };
+
+    let mut highlighter = HighlightLines::new(syntax, theme);
+    for line in LinesWithEndings::from(code) {
+        match highlighter.highlight_line(line, &SYNTAX_SET) {
+            Ok(ranges) => {
+                for (style, text) in ranges {
+                    let color = egui::Color32::from_rgb(style.foreground.r, style.foreground.g, style.foreground.b);
+                    let format = TextFormat {
+                        font_id: font_id.clone(),
+                        color,
+                        italics: style.font_style.contains(FontStyle::ITALIC),
+                        underline: if style.font_style.contains(FontStyle::UNDERLINE) {
+                            egui::Stroke::new(1.0, color)
+                        } else {
+                            egui::Stroke::NONE
+                        },
+                        ..Default::default()
+                    };
+                    job.append(text, 0.0, format);
+                }
+            }
+            Err(_) => job.append(line, 0.0, TextFormat::simple(font_id.clone(), Theme::TEXT_PRIMARY)),
+        }
+    }
+
+    job
+}
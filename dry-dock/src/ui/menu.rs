@@ -11,10 +11,15 @@ pub fn render_menu(ui: &mut egui::Ui, config: &Config, state: &mut crate::app::A
     Theme::apply_menu_style(ui);
 
     ui.menu_button(&config.app_name, |ui| {
+        let launcher_button = Theme::button("Open Bookmark...").shortcut_text("Ctrl+K");
         let settings_button = Theme::button("Settings");
         let logs_button = Theme::button("View Logs");
         let exit_button = Theme::button("Exit");
         
+        if ui.add(launcher_button).clicked() {
+            state.open_modal(ActiveModal::QuickLauncher);
+        }
+
         if ui.add(settings_button).clicked() {
             log_service::add_log_entry("INFO", "Loading Settings...");
             state.open_modal(ActiveModal::Settings);
@@ -29,7 +34,8 @@ pub fn render_menu(ui: &mut egui::Ui, config: &Config, state: &mut crate::app::A
         }
         
         if ui.add(exit_button).clicked() {
-            std::process::exit(0);
+            // Close through eframe so on_exit still saves drafts
+            ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
         }
     });
 
@@ -4,3 +4,4 @@ pub mod modals;
 pub mod styles;
 pub mod menu;
 pub mod home;
+pub mod code_block;
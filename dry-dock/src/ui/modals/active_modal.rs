@@ -9,9 +9,19 @@ pub enum ActiveModal {
     CreateNote,
     AddBookmark,
     UpdateNote(i32),        // Note ID
+    RestoreDraft(i32),      // Draft ID, opens the create/update note modal with it
     UpdateBookmark(i32),    // Bookmark ID
     ViewNote(i32),          // Note ID for viewing
+    LockNote(i32),          // Note ID to lock or unlock
+    ExportNotes(Vec<i32>),  // Selected note IDs, empty to pick a notebook
     LogModal,
     ManageFeeds,
     Settings,
+    NoteConflicts,
+    PublishSite,
+    ImportBookmarks,
+    BookmarkSnapshots(i32), // Bookmark ID
+    BookmarkDuplicates,
+    QuickLauncher,
+    FilePreview(std::path::PathBuf), // File from a folder bookmark's file tree
 }
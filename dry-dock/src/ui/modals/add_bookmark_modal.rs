@@ -1,6 +1,7 @@
 // src/ui/modals/add_bookmark_modal.rs
 use eframe::egui;
 use crate::ui::modals::modal_trait::Modal;
+use crate::ui::modals::bookmark_fields::{render_description_field, render_folder_picker, render_location_kind, render_tags_field, DuplicateWarning, PageDetailsFetcher};
 use crate::services::bookmark_service;
 use crate::services::log_service;
 use crate::ui::styles::Theme;
@@ -9,6 +10,15 @@ use crate::ui::styles::Theme;
 pub struct AddBookmarkModal {
     name: String,
     location: String,
+    description: String,
+    folder_id: Option<i32>,
+    tags: String,
+    folder_paths: Vec<(i32, String)>,
+    /// Title and description come from the page when a URL is pasted
+    page_details: PageDetailsFetcher,
+    duplicate_warning: DuplicateWarning,
+    error: Option<String>,
+    loaded: bool,
 }
 
 impl Modal for AddBookmarkModal {
@@ -19,6 +29,29 @@ impl Modal for AddBookmarkModal {
     fn render(&mut self, ui: &mut egui::Ui) -> bool {
         let mut should_close = false;
 
+        // Load folders on first render
+        if !self.loaded {
+            match bookmark_service::get_folders() {
+                Ok(folders) => self.folder_paths = bookmark_service::folder_paths(&folders),
+                Err(e) => log_service::add_log_entry("ERROR", &format!("Error loading bookmark folders: {}", e)),
+            }
+            self.loaded = true;
+        }
+
+        // Fill in what the user hasn't typed yet
+        if let Some(details) = self.page_details.poll(ui.ctx()) {
+            if let Some(title) = details.title
+                && self.name.trim().is_empty()
+            {
+                self.name = title;
+            }
+            if let Some(description) = details.description
+                && self.description.trim().is_empty()
+            {
+                self.description = description;
+            }
+        }
+
         Theme::apply_body_style(ui);
         
         ui.add_space(Theme::SPACING_MEDIUM);
@@ -36,19 +69,46 @@ impl Modal for AddBookmarkModal {
         ui.label(egui::RichText::new("Location").size(Theme::FONT_SIZE_BODY).strong());
         ui.add_space(Theme::SPACING_SMALL);
         let location_edit = egui::TextEdit::singleline(&mut self.location)
-            .hint_text("URL or file path")
+            .hint_text("URL, path, mailto:, ssh://, cmd: or app:")
             .desired_width(f32::INFINITY)
             .margin(egui::vec2(8.0, 8.0));
-        ui.add(location_edit);
+        let location_response = ui.add(location_edit);
+
+        // Fetch the page when a URL is pasted or the field is left
+        let pasted = location_response.changed() && ui.input(|i| i.events.iter().any(|event| matches!(event, egui::Event::Paste(_))));
+        if pasted || location_response.lost_focus() {
+            self.page_details.fetch_if_new(&self.location);
+        }
+        render_location_kind(ui, &self.location);
+        self.duplicate_warning.render(ui, &self.location, None);
+        self.page_details.render_status(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        render_description_field(ui, &mut self.description);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        render_folder_picker(ui, &self.folder_paths, &mut self.folder_id);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        render_tags_field(ui, &mut self.tags);
         
         ui.add_space(Theme::SPACING_LARGE);
 
+        // Stay open so the location can be fixed
+        if let Some(error) = &self.error {
+            ui.colored_label(Theme::DANGER_COLOR, error);
+            ui.add_space(Theme::SPACING_SMALL);
+        }
+
         ui.horizontal(|ui| {
             if ui.add(Theme::primary_button("Add Bookmark")).clicked() {
-                if let Err(e) = bookmark_service::add_new_bookmark(&self.name, &self.location) {
-                    log_service::add_log_entry("ERROR", &format!("Error adding bookmark: {}", e));
+                match bookmark_service::add_new_bookmark(&self.name, &self.location, &self.description, self.folder_id, &self.tags) {
+                    Ok(_) => should_close = true,
+                    Err(e) => {
+                        log_service::add_log_entry("ERROR", &format!("Error adding bookmark: {}", e));
+                        self.error = Some(e);
+                    }
                 }
-                should_close = true;
             }
             if ui.add(Theme::button("Cancel")).clicked() {
                 should_close = true;
@@ -59,6 +119,9 @@ impl Modal for AddBookmarkModal {
         if should_close {
             self.name.clear();
             self.location.clear();
+            self.description.clear();
+            self.tags.clear();
+            self.error = None;
         }
 
         should_close
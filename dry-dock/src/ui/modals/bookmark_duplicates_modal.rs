@@ -0,0 +1,136 @@
+// src/ui/modals/bookmark_duplicates_modal.rs
+use eframe::egui;
+use crate::ui::modals::modal_trait::Modal;
+use crate::models::Bookmark;
+use crate::services::{bookmark_service, log_service};
+use crate::ui::styles::Theme;
+
+/// Bookmarks that point at the same page, pick the one to keep and merge the rest into it
+#[derive(Default)]
+pub struct BookmarkDuplicatesModal {
+    groups: Vec<Vec<Bookmark>>,
+    /// Index of the bookmark to keep in each group
+    keep: Vec<usize>,
+    loaded: bool,
+    status: Option<Result<String, String>>,
+}
+
+impl BookmarkDuplicatesModal {
+    fn load(&mut self) {
+        match bookmark_service::find_duplicate_groups() {
+            Ok(groups) => {
+                // Groups come with the best one to keep first
+                self.keep = vec![0; groups.len()];
+                self.groups = groups;
+            }
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Error finding duplicate bookmarks: {}", e));
+                self.status = Some(Err(e));
+            }
+        }
+        self.loaded = true;
+    }
+
+    /// Merge the given groups, returns how many bookmarks were merged away
+    fn merge(&mut self, group_indexes: &[usize]) {
+        let mut merged = 0;
+        for &index in group_indexes {
+            let group = &self.groups[index];
+            let keep_id = group[self.keep[index]].id;
+            let remove_ids: Vec<i32> = group.iter().map(|bookmark| bookmark.id).filter(|id| *id != keep_id).collect();
+
+            if let Err(e) = bookmark_service::merge_duplicates(keep_id, &remove_ids) {
+                log_service::add_log_entry("ERROR", &format!("Error merging bookmarks: {}", e));
+                self.status = Some(Err(e));
+                self.loaded = false;
+                return;
+            }
+            merged += remove_ids.len();
+        }
+
+        self.status = Some(Ok(format!("Merged {} duplicate(s)", merged)));
+        self.loaded = false;
+    }
+}
+
+impl Modal for BookmarkDuplicatesModal {
+    fn title(&self) -> &str {
+        "Duplicate Bookmarks"
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut should_close = false;
+
+        if !self.loaded {
+            self.load();
+        }
+
+        Theme::apply_body_style(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Addresses that only differ in http/https, www., a trailing slash, tracking parameters or the #fragment are treated as the same page. The kept bookmark gets the others' tags, usage and snapshots.")
+            .color(Theme::TEXT_MUTED));
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        let mut to_merge: Vec<usize> = Vec::new();
+        if self.groups.is_empty() {
+            ui.label(egui::RichText::new("No duplicates found.").color(Theme::TEXT_SECONDARY));
+        } else {
+            if ui.add(Theme::primary_button(&format!("Merge all ({} groups)", self.groups.len()))).clicked() {
+                to_merge = (0..self.groups.len()).collect();
+            }
+            ui.add_space(Theme::SPACING_MEDIUM);
+
+            egui::ScrollArea::vertical()
+                .max_height(420.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    for (index, group) in self.groups.iter().enumerate() {
+                        Theme::card_frame().show(ui, |ui| {
+                            for (position, bookmark) in group.iter().enumerate() {
+                                ui.horizontal(|ui| {
+                                    ui.radio_value(&mut self.keep[index], position, "")
+                                        .on_hover_text("Keep this one");
+                                    ui.vertical(|ui| {
+                                        ui.label(egui::RichText::new(&bookmark.name).strong());
+                                        ui.label(egui::RichText::new(format!(
+                                            "{} | added {} | opened {} time(s)",
+                                            bookmark.path, bookmark.created_at, bookmark.open_count
+                                        ))
+                                        .size(Theme::FONT_SIZE_SMALL)
+                                        .color(Theme::TEXT_MUTED));
+                                    });
+                                });
+                            }
+                            ui.add_space(Theme::SPACING_SMALL);
+                            if ui.add(Theme::button("Merge")).clicked() {
+                                to_merge.push(index);
+                            }
+                        });
+                        ui.add_space(Theme::SPACING_SMALL);
+                    }
+                });
+        }
+
+        if !to_merge.is_empty() {
+            self.merge(&to_merge);
+        }
+
+        ui.add_space(Theme::SPACING_LARGE);
+
+        if let Some(status) = &self.status {
+            match status {
+                Ok(message) => ui.colored_label(Theme::SUCCESS_COLOR, message),
+                Err(error) => ui.colored_label(Theme::DANGER_COLOR, error),
+            };
+            ui.add_space(Theme::SPACING_SMALL);
+        }
+
+        if ui.add(Theme::button("Close")).clicked() {
+            should_close = true;
+        }
+
+        should_close
+    }
+}
@@ -0,0 +1,181 @@
+// src/ui/modals/bookmark_fields.rs
+use eframe::egui;
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
+use crate::models::{BookmarkKind, PageMetadata};
+use crate::services::{bookmark_metadata_service, bookmark_opener_service, log_service};
+use crate::services::bookmark_service::DuplicateIndex;
+use crate::ui::styles::Theme;
+
+/// Folder picker shared by the add/update bookmark modals
+/// `folder_paths` comes from bookmark_service::folder_paths
+pub fn render_folder_picker(ui: &mut egui::Ui, folder_paths: &[(i32, String)], folder_id: &mut Option<i32>) {
+    ui.label(egui::RichText::new("Folder").size(Theme::FONT_SIZE_BODY).strong());
+    ui.add_space(Theme::SPACING_SMALL);
+
+    let selected_text = folder_id
+        .and_then(|id| folder_paths.iter().find(|(folder, _)| *folder == id))
+        .map(|(_, path)| path.as_str())
+        .unwrap_or("No folder");
+
+    egui::ComboBox::from_id_salt("bookmark_folder")
+        .selected_text(selected_text)
+        .width(ui.available_width())
+        .show_ui(ui, |ui| {
+            ui.selectable_value(folder_id, None, "No folder");
+            for (id, path) in folder_paths {
+                ui.selectable_value(folder_id, Some(*id), path);
+            }
+        });
+}
+
+/// Comma separated tags field shared by the add/update bookmark modals
+pub fn render_tags_field(ui: &mut egui::Ui, tags: &mut String) {
+    ui.label(egui::RichText::new("Tags").size(Theme::FONT_SIZE_BODY).strong());
+    ui.add_space(Theme::SPACING_SMALL);
+    ui.add(
+        egui::TextEdit::singleline(tags)
+            .hint_text("Comma separated, e.g. docs, rust")
+            .desired_width(f32::INFINITY)
+            .margin(egui::vec2(8.0, 8.0))
+    );
+}
+
+/// What kind of bookmark the location is, or why it can't be opened
+pub fn render_location_kind(ui: &mut egui::Ui, location: &str) {
+    if location.trim().is_empty() {
+        return;
+    }
+
+    let kind = bookmark_opener_service::kind_of(location);
+    // Drawn every frame, so a path isn't checked on disk for being a folder
+    let label = if kind == BookmarkKind::File { "File or folder" } else { kind.label() };
+    match bookmark_opener_service::validate(location) {
+        Ok(_) => ui.label(egui::RichText::new(format!("{} {}", kind.icon(), label))
+            .size(Theme::FONT_SIZE_SMALL)
+            .color(Theme::TEXT_MUTED)),
+        Err(e) => ui.label(egui::RichText::new(e)
+            .size(Theme::FONT_SIZE_SMALL)
+            .color(Theme::DANGER_COLOR)),
+    };
+}
+
+/// Warns when the location looks like a page that is already bookmarked
+#[derive(Default)]
+pub struct DuplicateWarning {
+    /// Saved web bookmarks, loaded once when the modal is first drawn
+    index: Option<DuplicateIndex>,
+    checked_location: String,
+    /// (name, location) of the look-alikes
+    duplicates: Vec<(String, String)>,
+}
+
+impl DuplicateWarning {
+    /// `exclude_id` is the bookmark being edited
+    pub fn render(&mut self, ui: &mut egui::Ui, location: &str, exclude_id: Option<i32>) {
+        let location = location.trim();
+        if location != self.checked_location {
+            self.checked_location = location.to_string();
+            let index = self.index.get_or_insert_with(|| {
+                DuplicateIndex::load().unwrap_or_else(|e| {
+                    log_service::add_log_entry("ERROR", &format!("Error loading bookmarks: {}", e));
+                    DuplicateIndex::new(Vec::new())
+                })
+            });
+            self.duplicates = index
+                .find(location, exclude_id)
+                .into_iter()
+                .map(|bookmark| (bookmark.name.clone(), bookmark.path.clone()))
+                .collect();
+        }
+
+        for (name, path) in &self.duplicates {
+            ui.label(egui::RichText::new(format!("⚠ Looks like '{}' ({})", name, path))
+                .size(Theme::FONT_SIZE_SMALL)
+                .color(Theme::WARNING_COLOR));
+        }
+    }
+}
+
+/// Optional description field shared by the add/update bookmark modals
+pub fn render_description_field(ui: &mut egui::Ui, description: &mut String) {
+    ui.label(egui::RichText::new("Description").size(Theme::FONT_SIZE_BODY).strong());
+    ui.add_space(Theme::SPACING_SMALL);
+    ui.add(
+        egui::TextEdit::multiline(description)
+            .hint_text("Optional")
+            .desired_rows(2)
+            .desired_width(f32::INFINITY)
+            .margin(egui::vec2(8.0, 8.0))
+    );
+}
+
+/// Fetches a page's title, description and favicon on a background thread for the bookmark modals
+#[derive(Default)]
+pub struct PageDetailsFetcher {
+    rx: Option<Receiver<Result<PageMetadata, String>>>,
+    /// Last URL details were asked for, so the same address isn't fetched twice
+    requested_url: String,
+    error: Option<String>,
+}
+
+impl PageDetailsFetcher {
+    /// Start fetching unless this URL was already fetched (or isn't a web address)
+    pub fn fetch_if_new(&mut self, url: &str) {
+        let url = url.trim();
+        if url != self.requested_url && (url.starts_with("http://") || url.starts_with("https://")) {
+            self.fetch(url);
+        }
+    }
+
+    pub fn fetch(&mut self, url: &str) {
+        let (tx, rx) = channel();
+        let url = url.trim().to_string();
+        self.requested_url = url.clone();
+        self.rx = Some(rx);
+        self.error = None;
+
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().unwrap();
+            let _ = tx.send(runtime.block_on(bookmark_metadata_service::fetch_page_metadata(&url)));
+        });
+    }
+
+    /// The fetched details, once they arrive
+    pub fn poll(&mut self, ctx: &egui::Context) -> Option<PageMetadata> {
+        let rx = self.rx.as_ref()?;
+
+        match rx.try_recv() {
+            Ok(result) => {
+                self.rx = None;
+                match result {
+                    Ok(metadata) => Some(metadata),
+                    Err(e) => {
+                        log_service::add_log_entry("WARNING", &format!("Could not fetch page details: {}", e));
+                        self.error = Some(e);
+                        None
+                    }
+                }
+            }
+            Err(TryRecvError::Empty) => {
+                ctx.request_repaint_after(std::time::Duration::from_millis(250));
+                None
+            }
+            Err(TryRecvError::Disconnected) => {
+                self.rx = None;
+                None
+            }
+        }
+    }
+
+    /// Spinner while fetching, the error if it failed
+    pub fn render_status(&self, ui: &mut egui::Ui) {
+        if self.rx.is_some() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(egui::RichText::new("Fetching page details...").size(Theme::FONT_SIZE_SMALL).color(Theme::TEXT_SECONDARY));
+            });
+        } else if let Some(error) = &self.error {
+            ui.label(egui::RichText::new(error).size(Theme::FONT_SIZE_SMALL).color(Theme::TEXT_MUTED));
+        }
+    }
+}
@@ -0,0 +1,246 @@
+// src/ui/modals/bookmark_snapshots_modal.rs
+use eframe::egui;
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
+use crate::ui::modals::modal_trait::Modal;
+use crate::models::{Bookmark, BookmarkSnapshot};
+use crate::services::{bookmark_service, log_service, settings_service, snapshot_service};
+use crate::ui::styles::Theme;
+
+/// Offline snapshots of a bookmarked page: archive a new version, open or delete old ones
+pub struct BookmarkSnapshotsModal {
+    bookmark_id: i32,
+    bookmark: Option<Bookmark>,
+    snapshots: Vec<BookmarkSnapshot>,
+    loaded: bool,
+    archive_rx: Option<Receiver<Result<BookmarkSnapshot, String>>>,
+    interval_days: String,
+    keep_versions: String,
+    status: Option<Result<String, String>>,
+}
+
+impl BookmarkSnapshotsModal {
+    pub fn new(bookmark_id: i32) -> Self {
+        Self {
+            bookmark_id,
+            bookmark: None,
+            snapshots: Vec::new(),
+            loaded: false,
+            archive_rx: None,
+            interval_days: settings_service::get_snapshot_interval_days().to_string(),
+            keep_versions: settings_service::get_snapshot_keep_versions().to_string(),
+            status: None,
+        }
+    }
+
+    fn load(&mut self) {
+        self.bookmark = bookmark_service::get_bookmark(self.bookmark_id).ok();
+        match snapshot_service::get_snapshots(self.bookmark_id) {
+            Ok(snapshots) => self.snapshots = snapshots,
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Error loading snapshots: {}", e));
+                self.status = Some(Err(e));
+            }
+        }
+        self.loaded = true;
+    }
+
+    /// Archive the page on a background thread, the new snapshot arrives through archive_rx
+    fn start_archive(&mut self) {
+        let (tx, rx) = channel();
+        let bookmark_id = self.bookmark_id;
+        self.archive_rx = Some(rx);
+        self.status = None;
+
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().unwrap();
+            let _ = tx.send(runtime.block_on(snapshot_service::archive_page(bookmark_id)));
+        });
+    }
+
+    fn poll_archive(&mut self, ctx: &egui::Context) {
+        let Some(rx) = &self.archive_rx else { return };
+
+        match rx.try_recv() {
+            Ok(result) => {
+                self.status = Some(match result {
+                    Ok(snapshot) => Ok(format!("Page archived ({})", Self::format_size(snapshot.size_bytes))),
+                    Err(e) => {
+                        log_service::add_log_entry("ERROR", &format!("Archiving page failed: {}", e));
+                        Err(e)
+                    }
+                });
+                self.archive_rx = None;
+                self.loaded = false;
+            }
+            Err(TryRecvError::Empty) => ctx.request_repaint_after(std::time::Duration::from_millis(250)),
+            Err(TryRecvError::Disconnected) => self.archive_rx = None,
+        }
+    }
+
+    fn save_interval(&mut self) {
+        self.status = Some(match self.interval_days.trim().parse::<u32>() {
+            Ok(days) => settings_service::save_snapshot_interval_days(days).map(|_| {
+                if days == 0 {
+                    "Automatic re-archiving turned off".to_string()
+                } else {
+                    format!("Archived pages will be re-archived every {} day(s)", days)
+                }
+            }),
+            Err(_) => Err("Enter a number of days (0 to turn off)".to_string()),
+        });
+    }
+
+    fn save_keep_versions(&mut self) {
+        self.status = Some(match self.keep_versions.trim().parse::<u32>() {
+            Ok(versions) => settings_service::save_snapshot_keep_versions(versions).map(|_| {
+                if versions == 0 {
+                    "All snapshot versions will be kept".to_string()
+                } else {
+                    format!("The newest {} version(s) will be kept after each archive", versions)
+                }
+            }),
+            Err(_) => Err("Enter a number of versions (0 to keep all)".to_string()),
+        });
+    }
+
+    fn format_size(bytes: i64) -> String {
+        if bytes >= 1024 * 1024 {
+            format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+        } else {
+            format!("{} KB", (bytes / 1024).max(1))
+        }
+    }
+}
+
+impl Modal for BookmarkSnapshotsModal {
+    fn title(&self) -> &str {
+        "Page Snapshots"
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut should_close = false;
+
+        if !self.loaded {
+            self.load();
+        }
+        self.poll_archive(ui.ctx());
+
+        Theme::apply_body_style(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        if let Some(bookmark) = &self.bookmark {
+            ui.label(egui::RichText::new(&bookmark.name).size(Theme::FONT_SIZE_BODY).strong());
+            ui.label(egui::RichText::new(&bookmark.path)
+                .size(Theme::FONT_SIZE_SMALL)
+                .color(Theme::TEXT_MUTED));
+            ui.add_space(Theme::SPACING_MEDIUM);
+        }
+
+        ui.label(egui::RichText::new("Snapshots keep the page with its styles and images in a single file you can open offline.")
+            .color(Theme::TEXT_MUTED));
+        ui.add_space(Theme::SPACING_SMALL);
+
+        ui.horizontal(|ui| {
+            let archiving = self.archive_rx.is_some();
+            if ui.add_enabled(!archiving, Theme::primary_button("Archive page")).clicked() {
+                self.start_archive();
+            }
+            if archiving {
+                ui.spinner();
+                ui.label(egui::RichText::new("Downloading page...").color(Theme::TEXT_MUTED));
+            }
+        });
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        ui.separator();
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new(format!("Versions ({})", self.snapshots.len())).size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+
+        let mut id_to_delete = None;
+        egui::ScrollArea::vertical()
+            .max_height(260.0)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                if self.snapshots.is_empty() {
+                    ui.label(egui::RichText::new("No snapshots yet.")
+                        .size(Theme::FONT_SIZE_SMALL)
+                        .color(Theme::TEXT_MUTED));
+                }
+
+                for snapshot in &self.snapshots {
+                    ui.horizontal(|ui| {
+                        let date = chrono::DateTime::from_timestamp(snapshot.created_at, 0)
+                            .map(|dt| dt.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+                            .unwrap_or_default();
+                        ui.label(date);
+                        ui.label(egui::RichText::new(Self::format_size(snapshot.size_bytes))
+                            .size(Theme::FONT_SIZE_SMALL)
+                            .color(Theme::TEXT_MUTED));
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.add(Theme::danger_button("Delete")).clicked() {
+                                id_to_delete = Some(snapshot.id);
+                            }
+                            ui.add_space(Theme::SPACING_SMALL);
+                            if ui.add(Theme::success_button("Open")).clicked()
+                                && let Err(e) = snapshot_service::open_snapshot(snapshot.id)
+                            {
+                                log_service::add_log_entry("ERROR", &format!("Error opening snapshot: {}", e));
+                                self.status = Some(Err(e));
+                            }
+                        });
+                    });
+                }
+            });
+
+        if let Some(id) = id_to_delete {
+            match snapshot_service::delete_snapshot(id) {
+                Ok(_) => self.snapshots.retain(|snapshot| snapshot.id != id),
+                Err(e) => {
+                    log_service::add_log_entry("ERROR", &format!("Error deleting snapshot: {}", e));
+                    self.status = Some(Err(e));
+                }
+            }
+        }
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        ui.separator();
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.horizontal(|ui| {
+            ui.label("Re-archive every");
+            ui.add(egui::TextEdit::singleline(&mut self.interval_days).desired_width(40.0));
+            ui.label("days (0 = never, applies to all archived bookmarks)");
+            if ui.add(Theme::button("Save")).clicked() {
+                self.save_interval();
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Keep the newest");
+            ui.add(egui::TextEdit::singleline(&mut self.keep_versions).desired_width(40.0));
+            ui.label("versions (0 = all, older ones are deleted after archiving)");
+            if ui.add(Theme::button("Save")).clicked() {
+                self.save_keep_versions();
+            }
+        });
+
+        ui.add_space(Theme::SPACING_LARGE);
+
+        if let Some(status) = &self.status {
+            match status {
+                Ok(message) => ui.colored_label(Theme::SUCCESS_COLOR, message),
+                Err(error) => ui.colored_label(Theme::DANGER_COLOR, error),
+            };
+            ui.add_space(Theme::SPACING_SMALL);
+        }
+
+        if ui.add(Theme::button("Close")).clicked() {
+            should_close = true;
+        }
+
+        should_close
+    }
+}
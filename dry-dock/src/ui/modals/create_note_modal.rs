@@ -1,13 +1,54 @@
 // src/ui/modals/create_note_modal.rs
 use eframe::egui;
 use crate::ui::modals::modal_trait::Modal;
+use crate::ui::modals::draft_banner::{render_draft_banner, DraftChoice};
+use crate::models::NoteDraft;
 use crate::services::NoteService;
+use crate::services::{draft_service, log_service};
+use crate::services::draft_service::DraftSession;
 use crate::ui::styles::Theme;
 
-#[derive(Default)]
 pub struct CreateNoteModal {
     title: String,
+    notebook: String,
+    tags: String,
     details: String,
+    draft: DraftSession,
+    // Leftover draft offered for restore when the modal opens
+    pending_draft: Option<NoteDraft>,
+    loaded: bool,
+}
+
+impl Default for CreateNoteModal {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            notebook: String::new(),
+            tags: String::new(),
+            details: String::new(),
+            draft: DraftSession::new(None),
+            pending_draft: None,
+            loaded: false,
+        }
+    }
+}
+
+impl CreateNoteModal {
+    /// Open the modal with a draft already restored (from the Drafts list)
+    pub fn from_draft(draft: NoteDraft) -> Self {
+        let mut modal = Self::default();
+        modal.restore(draft);
+        modal.loaded = true;
+        modal
+    }
+
+    fn restore(&mut self, draft: NoteDraft) {
+        self.draft.resume(&draft);
+        self.title = draft.title;
+        self.notebook = draft.notebook;
+        self.tags = draft.tags;
+        self.details = draft.details;
+    }
 }
 
 impl Modal for CreateNoteModal {
@@ -17,10 +58,37 @@ impl Modal for CreateNoteModal {
     
     fn render(&mut self, ui: &mut egui::Ui) -> bool {
         let mut should_close = false;
+
+        // Look for a draft left behind last time on first render
+        if !self.loaded {
+            match draft_service::find_draft(None) {
+                Ok(draft) => self.pending_draft = draft,
+                Err(e) => log_service::add_log_entry("ERROR", &format!("Error loading drafts: {}", e)),
+            }
+            self.loaded = true;
+        }
         
         Theme::apply_body_style(ui);
         
         ui.add_space(Theme::SPACING_MEDIUM);
+
+        if let Some(draft) = &self.pending_draft {
+            match render_draft_banner(ui, draft) {
+                Some(DraftChoice::Restore) => {
+                    if let Some(draft) = self.pending_draft.take() {
+                        self.restore(draft);
+                    }
+                }
+                Some(DraftChoice::Discard) => {
+                    if let Some(draft) = self.pending_draft.take()
+                        && let Err(e) = draft_service::discard_draft(draft.id)
+                    {
+                        log_service::add_log_entry("ERROR", &format!("Error discarding draft: {}", e));
+                    }
+                }
+                None => {}
+            }
+        }
         
         ui.label(egui::RichText::new("Note Title").size(Theme::FONT_SIZE_BODY).strong());
         ui.add_space(Theme::SPACING_SMALL);
@@ -28,10 +96,36 @@ impl Modal for CreateNoteModal {
             .hint_text("Enter a title for your note")
             .desired_width(f32::INFINITY)
             .margin(egui::vec2(8.0, 8.0));
-        ui.add(title_edit);
+        if ui.add(title_edit).changed() {
+            self.draft.mark_dirty();
+        }
         
         ui.add_space(Theme::SPACING_MEDIUM);
 
+        ui.label(egui::RichText::new("Notebook").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        let notebook_edit = egui::TextEdit::singleline(&mut self.notebook)
+            .hint_text("Notes")
+            .desired_width(f32::INFINITY)
+            .margin(egui::vec2(8.0, 8.0));
+        if ui.add(notebook_edit).changed() {
+            self.draft.mark_dirty();
+        }
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Tags").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        let tags_edit = egui::TextEdit::singleline(&mut self.tags)
+            .hint_text("Comma separated, e.g. public, rust")
+            .desired_width(f32::INFINITY)
+            .margin(egui::vec2(8.0, 8.0));
+        if ui.add(tags_edit).changed() {
+            self.draft.mark_dirty();
+        }
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
         // Note Details.
         ui.label(egui::RichText::new("Note Details").size(Theme::FONT_SIZE_BODY).strong());
         ui.add_space(Theme::SPACING_SMALL);
@@ -39,21 +133,25 @@ impl Modal for CreateNoteModal {
             .id_salt("note_details_scroll")
             .max_height(ui.available_height() - 80.0) 
             .show(ui, |ui| {
-                ui.add(
+                let response = ui.add(
                     egui::TextEdit::multiline(&mut self.details)
                         .hint_text("Write your note here...")
                         .desired_width(f32::INFINITY)
                         .desired_rows(20)
                         .font(egui::TextStyle::Body)
                 );
+                if response.changed() {
+                    self.draft.mark_dirty();
+                }
             });
         
         ui.add_space(Theme::SPACING_LARGE);
         
         ui.horizontal(|ui| {
             if ui.add(Theme::primary_button("Create Note")).clicked() {
-                if let Err(e) = NoteService::create_note(&self.title, &self.details) {
-                    println!("Error creating note: {}", e);
+                match NoteService::create_note(&self.title, &self.details, &self.notebook, &self.tags) {
+                    Ok(_) => self.draft.discard(),
+                    Err(e) => println!("Error creating note: {}", e),
                 }
                 should_close = true;
             }
@@ -62,12 +160,28 @@ impl Modal for CreateNoteModal {
             }
         });
 
+        // Keep typed text as a draft in case the modal was closed by accident
+        if should_close {
+            self.draft.save(&self.title, &self.details, &self.notebook, &self.tags);
+        } else {
+            self.draft.autosave(&self.title, &self.details, &self.notebook, &self.tags);
+            if self.draft.is_dirty() {
+                ui.ctx().request_repaint_after(draft_service::DRAFT_SAVE_INTERVAL);
+            }
+        }
+
         // Clean up fields if closing
         if should_close {
             self.title.clear();
+            self.notebook.clear();
+            self.tags.clear();
             self.details.clear();
         }
         
         should_close
     }
+
+    fn save_draft(&mut self) {
+        self.draft.save(&self.title, &self.details, &self.notebook, &self.tags);
+    }
 }
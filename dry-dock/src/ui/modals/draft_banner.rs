@@ -0,0 +1,37 @@
+// src/ui/modals/draft_banner.rs
+use eframe::egui;
+use crate::models::NoteDraft;
+use crate::ui::styles::Theme;
+
+/// What the user picked on the restore banner
+pub enum DraftChoice {
+    Restore,
+    Discard,
+}
+
+/// Banner offering to restore an autosaved draft, shared by the create/update note modals
+pub fn render_draft_banner(ui: &mut egui::Ui, draft: &NoteDraft) -> Option<DraftChoice> {
+    let mut choice = None;
+
+    let saved = chrono::DateTime::from_timestamp(draft.updated_at, 0)
+        .map(|dt| dt.with_timezone(&chrono::Local).format("%b %d, %Y %H:%M").to_string())
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    Theme::card_frame().show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(format!("You have an unsaved draft from {}", saved))
+                .color(Theme::TEXT_PRIMARY));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button("Discard").clicked() {
+                    choice = Some(DraftChoice::Discard);
+                }
+                if ui.button("Restore").clicked() {
+                    choice = Some(DraftChoice::Restore);
+                }
+            });
+        });
+    });
+    ui.add_space(Theme::SPACING_SMALL);
+
+    choice
+}
@@ -0,0 +1,208 @@
+// src/ui/modals/export_notes_modal.rs
+use eframe::egui;
+use std::path::PathBuf;
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
+use crate::ui::modals::modal_trait::Modal;
+use crate::models::{ExportFormat, Note};
+use crate::services::NoteService;
+use crate::services::{export_service, log_service};
+use crate::ui::styles::Theme;
+
+/// What gets exported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportScope {
+    Selected,
+    Notebook,
+}
+
+/// Export the selected notes, or a whole notebook, to a single HTML or PDF file
+pub struct ExportNotesModal {
+    note_ids: Vec<i32>,
+    scope: ExportScope,
+    notebooks: Vec<String>,
+    notebook: String,
+    format: ExportFormat,
+    directory: String,
+    status: Option<Result<String, String>>,
+    /// Path of the written file, sent back by the export thread
+    export_rx: Option<Receiver<Result<PathBuf, String>>>,
+    loaded: bool,
+}
+
+impl ExportNotesModal {
+    pub fn new(note_ids: Vec<i32>) -> Self {
+        let scope = if note_ids.is_empty() { ExportScope::Notebook } else { ExportScope::Selected };
+        let directory = dirs::document_dir()
+            .or_else(dirs::home_dir)
+            .map(|dir| dir.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        Self {
+            note_ids,
+            scope,
+            notebooks: Vec::new(),
+            notebook: Note::DEFAULT_NOTEBOOK.to_string(),
+            format: ExportFormat::default(),
+            directory,
+            status: None,
+            export_rx: None,
+            loaded: false,
+        }
+    }
+
+    /// Notes in scope and the title of the exported document
+    fn notes_to_export(scope: ExportScope, note_ids: &[i32], notebook: &str) -> Result<(Vec<Note>, String), String> {
+        match scope {
+            ExportScope::Selected => {
+                let mut notes = note_ids
+                    .iter()
+                    .map(|id| NoteService::get_note_by_id(*id))
+                    .collect::<Result<Vec<Note>, String>>()?;
+                notes.sort_by_key(|note| note.created_at);
+
+                let title = match notes.as_slice() {
+                    [note] => note.title.clone(),
+                    _ => "Notes".to_string(),
+                };
+                Ok((notes, title))
+            }
+            ExportScope::Notebook => Ok((NoteService::get_notes_in_notebook(notebook)?, notebook.to_string())),
+        }
+    }
+
+    /// Render and write the file on a background thread, the result arrives through export_rx
+    fn start_export(&mut self) {
+        let (tx, rx) = channel();
+        let (scope, note_ids, notebook, format) = (self.scope, self.note_ids.clone(), self.notebook.clone(), self.format);
+        let directory = PathBuf::from(self.directory.trim());
+        self.export_rx = Some(rx);
+        self.status = None;
+
+        std::thread::spawn(move || {
+            let result = Self::notes_to_export(scope, &note_ids, &notebook)
+                .and_then(|(notes, title)| export_service::export_notes(&notes, &title, format, &directory));
+            let _ = tx.send(result);
+        });
+    }
+
+    fn poll_export(&mut self, ctx: &egui::Context) {
+        let Some(rx) = &self.export_rx else { return };
+
+        match rx.try_recv() {
+            Ok(result) => {
+                self.status = Some(match result {
+                    Ok(path) => Ok(format!("Exported to {}", path.display())),
+                    Err(e) => {
+                        log_service::add_log_entry("ERROR", &format!("Note export failed: {}", e));
+                        Err(e)
+                    }
+                });
+                self.export_rx = None;
+            }
+            Err(TryRecvError::Empty) => ctx.request_repaint_after(std::time::Duration::from_millis(250)),
+            Err(TryRecvError::Disconnected) => self.export_rx = None,
+        }
+    }
+}
+
+impl Modal for ExportNotesModal {
+    fn title(&self) -> &str {
+        "Export Notes"
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut should_close = false;
+
+        self.poll_export(ui.ctx());
+
+        // Load notebooks on first render
+        if !self.loaded {
+            match NoteService::get_notebooks() {
+                Ok(notebooks) => {
+                    if let Some(first) = notebooks.first()
+                        && !notebooks.contains(&self.notebook)
+                    {
+                        self.notebook = first.clone();
+                    }
+                    self.notebooks = notebooks;
+                }
+                Err(e) => log_service::add_log_entry("ERROR", &format!("Error loading notebooks: {}", e)),
+            }
+            self.loaded = true;
+        }
+
+        Theme::apply_body_style(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Notes").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        if !self.note_ids.is_empty() {
+            ui.radio_value(&mut self.scope, ExportScope::Selected, format!("Selected notes ({})", self.note_ids.len()));
+        }
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut self.scope, ExportScope::Notebook, "Notebook");
+            ui.add_enabled_ui(self.scope == ExportScope::Notebook, |ui| {
+                egui::ComboBox::from_id_salt("export_notebook")
+                    .selected_text(&self.notebook)
+                    .show_ui(ui, |ui| {
+                        for notebook in &self.notebooks {
+                            ui.selectable_value(&mut self.notebook, notebook.clone(), notebook);
+                        }
+                    });
+            });
+        });
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Format").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.horizontal(|ui| {
+            for format in ExportFormat::ALL {
+                ui.radio_value(&mut self.format, format, format.label());
+            }
+        });
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Save to folder").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.add(
+            egui::TextEdit::singleline(&mut self.directory)
+                .hint_text("/path/to/folder")
+                .desired_width(f32::INFINITY)
+                .margin(egui::vec2(8.0, 8.0)),
+        );
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.label(egui::RichText::new("Locked notes are left out. Local images referenced in the notes are embedded.")
+            .size(Theme::FONT_SIZE_SMALL)
+            .color(Theme::TEXT_MUTED));
+
+        ui.add_space(Theme::SPACING_LARGE);
+
+        if let Some(status) = &self.status {
+            match status {
+                Ok(message) => ui.colored_label(Theme::SUCCESS_COLOR, message),
+                Err(error) => ui.colored_label(Theme::DANGER_COLOR, error),
+            };
+            ui.add_space(Theme::SPACING_SMALL);
+        }
+
+        ui.horizontal(|ui| {
+            let exporting = self.export_rx.is_some();
+            let can_export = !self.directory.trim().is_empty() && !exporting;
+            if ui.add_enabled(can_export, Theme::primary_button("Export")).clicked() {
+                self.start_export();
+            }
+            if exporting {
+                ui.spinner();
+                ui.label(egui::RichText::new("Exporting...").color(Theme::TEXT_MUTED));
+            }
+            if ui.add(Theme::button("Close")).clicked() {
+                should_close = true;
+            }
+        });
+
+        should_close
+    }
+}
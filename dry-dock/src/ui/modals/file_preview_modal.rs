@@ -0,0 +1,113 @@
+// src/ui/modals/file_preview_modal.rs
+use eframe::egui;
+use std::path::PathBuf;
+use crate::ui::modals::modal_trait::Modal;
+use crate::models::FilePreview;
+use crate::services::{file_browser_service, log_service};
+use crate::ui::code_block::{render_code_block, render_text_with_code_blocks};
+use crate::ui::styles::Theme;
+
+/// Read-only look at a text or markdown file from a folder bookmark's file tree
+pub struct FilePreviewModal {
+    path: PathBuf,
+    preview: Option<Result<FilePreview, String>>,
+    error: Option<String>,
+}
+
+impl FilePreviewModal {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            preview: None,
+            error: None,
+        }
+    }
+
+    fn render_preview(&self, ui: &mut egui::Ui, preview: &FilePreview) {
+        let Some(text) = &preview.text else {
+            ui.label(egui::RichText::new("This looks like a binary file, use Open to view it.").color(Theme::TEXT_SECONDARY));
+            return;
+        };
+
+        egui::ScrollArea::vertical()
+            .max_height(ui.available_height() - 80.0)
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if file_browser_service::is_markdown(&self.path) {
+                    render_text_with_code_blocks(ui, "file_preview", text, Theme::TEXT_SECONDARY);
+                } else {
+                    // Highlighted by extension, unknown ones fall back to plain text
+                    let language = self.path.extension().map(|extension| extension.to_string_lossy().to_string()).unwrap_or_default();
+                    render_code_block(ui, "file_preview", &language, text);
+                }
+            });
+
+        if preview.truncated {
+            ui.add_space(Theme::SPACING_SMALL);
+            ui.label(egui::RichText::new("Only the start of the file is shown.")
+                .size(Theme::FONT_SIZE_SMALL)
+                .color(Theme::TEXT_MUTED));
+        }
+    }
+}
+
+impl Modal for FilePreviewModal {
+    fn title(&self) -> &str {
+        "File Preview"
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut should_close = false;
+
+        if self.preview.is_none() {
+            self.preview = Some(file_browser_service::read_preview(&self.path));
+        }
+
+        Theme::apply_body_style(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        let name = self.path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        ui.label(egui::RichText::new(name).size(Theme::FONT_SIZE_BODY).strong());
+        let size = match &self.preview {
+            Some(Ok(preview)) => format!(" | {}", file_browser_service::format_size(preview.size_bytes)),
+            _ => String::new(),
+        };
+        ui.label(egui::RichText::new(format!("{}{}", self.path.display(), size))
+            .size(Theme::FONT_SIZE_SMALL)
+            .color(Theme::TEXT_MUTED));
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        match &self.preview {
+            Some(Ok(preview)) => self.render_preview(ui, preview),
+            Some(Err(error)) => {
+                ui.colored_label(Theme::DANGER_COLOR, error);
+            }
+            None => {}
+        }
+
+        ui.add_space(Theme::SPACING_LARGE);
+
+        if let Some(error) = &self.error {
+            ui.colored_label(Theme::DANGER_COLOR, error);
+            ui.add_space(Theme::SPACING_SMALL);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.add(Theme::success_button("Open")).clicked() {
+                match file_browser_service::open_path(&self.path) {
+                    Ok(_) => should_close = true,
+                    Err(e) => {
+                        log_service::add_log_entry("ERROR", &format!("Error opening file: {}", e));
+                        self.error = Some(e);
+                    }
+                }
+            }
+            if ui.add(Theme::button("Close")).clicked() {
+                should_close = true;
+            }
+        });
+
+        should_close
+    }
+}
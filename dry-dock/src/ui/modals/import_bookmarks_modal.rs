@@ -0,0 +1,214 @@
+// src/ui/modals/import_bookmarks_modal.rs
+use eframe::egui;
+use std::path::PathBuf;
+use crate::ui::modals::modal_trait::Modal;
+use crate::models::{BookmarkImportReport, BrowserProfile, ImportFolderMode};
+use crate::services::{bookmark_service, browser_bookmarks_service, log_service};
+use crate::ui::styles::Theme;
+
+/// Import bookmarks from an installed browser or a bookmarks.html, or export Dry Dock's as bookmarks.html
+pub struct ImportBookmarksModal {
+    profiles: Vec<BrowserProfile>,
+    profile_index: usize,
+    /// Last import mark of each profile, see browser_bookmarks_service::last_import
+    last_imports: Vec<Option<i64>>,
+    /// Only read bookmarks added since the last import from the profile
+    resync: bool,
+    loaded: bool,
+    import_path: String,
+    folder_mode: ImportFolderMode,
+    export_path: String,
+    status: Option<Result<String, String>>,
+}
+
+impl Default for ImportBookmarksModal {
+    fn default() -> Self {
+        let in_dir = |dir: Option<PathBuf>, file: &str| {
+            dir.or_else(dirs::home_dir)
+                .map(|dir| dir.join(file).to_string_lossy().to_string())
+                .unwrap_or_default()
+        };
+
+        Self {
+            profiles: Vec::new(),
+            profile_index: 0,
+            last_imports: Vec::new(),
+            resync: true,
+            loaded: false,
+            import_path: in_dir(dirs::download_dir(), "bookmarks.html"),
+            folder_mode: ImportFolderMode::default(),
+            export_path: in_dir(dirs::document_dir(), "dry-dock-bookmarks.html"),
+            status: None,
+        }
+    }
+}
+
+impl ImportBookmarksModal {
+    fn import_profile(&mut self) {
+        let Some(profile) = self.profiles.get(self.profile_index) else { return };
+        let resync = self.resync && self.last_imports.get(self.profile_index).copied().flatten().is_some();
+
+        self.status = Some(match browser_bookmarks_service::import_profile(profile, self.folder_mode, resync) {
+            Ok(report) => {
+                let message = format!("{} from {}", Self::describe(&report), profile.label());
+                self.last_imports[self.profile_index] = browser_bookmarks_service::last_import(profile);
+                Ok(message)
+            }
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Browser bookmark import failed: {}", e));
+                Err(e)
+            }
+        });
+    }
+
+    fn import(&mut self) {
+        let path = PathBuf::from(self.import_path.trim());
+        self.status = Some(match bookmark_service::import_netscape_html(&path, self.folder_mode) {
+            Ok(report) => Ok(Self::describe(&report)),
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Bookmark import failed: {}", e));
+                Err(e)
+            }
+        });
+    }
+
+    fn export(&mut self) {
+        let path = PathBuf::from(self.export_path.trim());
+        self.status = Some(match bookmark_service::export_netscape_html(&path) {
+            Ok(count) => Ok(format!("Exported {} bookmark(s) to {}", count, path.display())),
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Bookmark export failed: {}", e));
+                Err(e)
+            }
+        });
+    }
+
+    fn describe(report: &BookmarkImportReport) -> String {
+        let mut message = format!("Imported {} bookmark(s)", report.imported);
+        if report.folders_created > 0 {
+            message.push_str(&format!(" into {} new folder(s)", report.folders_created));
+        }
+        if report.duplicates > 0 {
+            message.push_str(&format!(", skipped {} already saved", report.duplicates));
+        }
+        if report.skipped > 0 {
+            message.push_str(&format!(", ignored {} browser-only link(s)", report.skipped));
+        }
+        message
+    }
+
+    fn path_field(ui: &mut egui::Ui, label: &str, value: &mut String) {
+        ui.label(egui::RichText::new(label).size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.add(
+            egui::TextEdit::singleline(value)
+                .hint_text("/path/to/bookmarks.html")
+                .desired_width(f32::INFINITY)
+                .margin(egui::vec2(8.0, 8.0)),
+        );
+        ui.add_space(Theme::SPACING_SMALL);
+    }
+}
+
+impl Modal for ImportBookmarksModal {
+    fn title(&self) -> &str {
+        "Import & Export Bookmarks"
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut should_close = false;
+
+        // Look for browser profiles on first render
+        if !self.loaded {
+            self.profiles = browser_bookmarks_service::detect_profiles();
+            self.last_imports = self.profiles.iter().map(browser_bookmarks_service::last_import).collect();
+            self.loaded = true;
+        }
+
+        Theme::apply_body_style(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Links that are already saved are skipped.")
+            .color(Theme::TEXT_MUTED));
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.horizontal(|ui| {
+            ui.label("Browser folders:");
+            for mode in ImportFolderMode::ALL {
+                ui.radio_value(&mut self.folder_mode, mode, mode.label());
+            }
+        });
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Import from a browser").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        if self.profiles.is_empty() {
+            ui.label(egui::RichText::new("No Firefox or Chromium based browser profiles found.")
+                .size(Theme::FONT_SIZE_SMALL)
+                .color(Theme::TEXT_MUTED));
+        } else {
+            let selected = self.profiles.get(self.profile_index).map(BrowserProfile::label).unwrap_or_default();
+            egui::ComboBox::from_id_salt("import_browser_profile")
+                .selected_text(selected)
+                .width(ui.available_width() - 16.0)
+                .show_ui(ui, |ui| {
+                    for (index, profile) in self.profiles.iter().enumerate() {
+                        ui.selectable_value(&mut self.profile_index, index, profile.label());
+                    }
+                });
+            ui.add_space(Theme::SPACING_SMALL);
+
+            match self.last_imports.get(self.profile_index).copied().flatten() {
+                Some(mark) => {
+                    let date = chrono::DateTime::from_timestamp(mark, 0)
+                        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+                        .unwrap_or_default();
+                    ui.checkbox(&mut self.resync, format!("Re-sync: only bookmarks added after {}", date));
+                }
+                None => {
+                    ui.label(egui::RichText::new("Not imported before, every bookmark will be read.")
+                        .size(Theme::FONT_SIZE_SMALL)
+                        .color(Theme::TEXT_MUTED));
+                }
+            }
+            ui.add_space(Theme::SPACING_SMALL);
+            if ui.add(Theme::primary_button("Import from Browser")).clicked() {
+                self.import_profile();
+            }
+        }
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        ui.separator();
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        Self::path_field(ui, "Import from bookmarks.html (your browser's \"Export bookmarks\" file)", &mut self.import_path);
+        if ui.add_enabled(!self.import_path.trim().is_empty(), Theme::primary_button("Import")).clicked() {
+            self.import();
+        }
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        ui.separator();
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        Self::path_field(ui, "Export to file", &mut self.export_path);
+        if ui.add_enabled(!self.export_path.trim().is_empty(), Theme::primary_button("Export")).clicked() {
+            self.export();
+        }
+
+        ui.add_space(Theme::SPACING_LARGE);
+
+        if let Some(status) = &self.status {
+            match status {
+                Ok(message) => ui.colored_label(Theme::SUCCESS_COLOR, message),
+                Err(error) => ui.colored_label(Theme::DANGER_COLOR, error),
+            };
+            ui.add_space(Theme::SPACING_SMALL);
+        }
+
+        if ui.add(Theme::button("Close")).clicked() {
+            should_close = true;
+        }
+
+        should_close
+    }
+}
@@ -0,0 +1,135 @@
+// src/ui/modals/lock_note_modal.rs
+use eframe::egui;
+use crate::ui::modals::modal_trait::Modal;
+use crate::services::NoteService;
+use crate::services::log_service;
+use crate::ui::styles::Theme;
+
+/// Lock a note with a passphrase, or remove the lock from a locked note
+pub struct LockNoteModal {
+    note_id: i32,
+    title: String,
+    is_locked: bool,
+    passphrase: String,
+    confirm_passphrase: String,
+    error: Option<String>,
+    loaded: bool,
+}
+
+impl LockNoteModal {
+    pub fn new(note_id: i32) -> Self {
+        Self {
+            note_id,
+            title: String::new(),
+            is_locked: false,
+            passphrase: String::new(),
+            confirm_passphrase: String::new(),
+            error: None,
+            loaded: false,
+        }
+    }
+}
+
+impl Modal for LockNoteModal {
+    fn title(&self) -> &str {
+        "Note Lock"
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut should_close = false;
+
+        // Load note data on first render
+        if !self.loaded {
+            match NoteService::get_note_by_id(self.note_id) {
+                Ok(note) => {
+                    self.title = note.title;
+                    self.is_locked = note.is_locked;
+                    self.loaded = true;
+                }
+                Err(e) => {
+                    log_service::add_log_entry("ERROR", &format!("Error loading note: {}", e));
+                    return true; // Close modal on error
+                }
+            }
+        }
+
+        Theme::apply_body_style(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new(&self.title)
+            .size(Theme::FONT_SIZE_SUBHEADING)
+            .strong()
+            .color(Theme::TEXT_PRIMARY));
+        ui.add_space(Theme::SPACING_SMALL);
+
+        if self.is_locked {
+            ui.label(egui::RichText::new("Enter the passphrase to remove the lock. The note will be stored in plain text again.")
+                .color(Theme::TEXT_SECONDARY));
+        } else {
+            ui.label(egui::RichText::new("The note content is encrypted with this passphrase. There is no way to recover it if you forget it.")
+                .color(Theme::TEXT_SECONDARY));
+        }
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Passphrase").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut self.passphrase)
+                .password(true)
+                .desired_width(f32::INFINITY)
+                .margin(egui::vec2(8.0, 8.0))
+        );
+        let mut submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+        if !self.is_locked {
+            ui.add_space(Theme::SPACING_SMALL);
+            ui.label(egui::RichText::new("Confirm passphrase").size(Theme::FONT_SIZE_BODY).strong());
+            ui.add_space(Theme::SPACING_SMALL);
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.confirm_passphrase)
+                    .password(true)
+                    .desired_width(f32::INFINITY)
+                    .margin(egui::vec2(8.0, 8.0))
+            );
+            submitted |= response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+        }
+
+        if let Some(error) = &self.error {
+            ui.add_space(Theme::SPACING_SMALL);
+            ui.colored_label(Theme::DANGER_COLOR, error);
+        }
+
+        ui.add_space(Theme::SPACING_LARGE);
+
+        ui.horizontal(|ui| {
+            let action = if self.is_locked { "Remove Lock" } else { "Lock Note" };
+            if ui.add(Theme::primary_button(action)).clicked() || submitted {
+                let result = if self.is_locked {
+                    NoteService::remove_lock(self.note_id, &self.passphrase)
+                } else if self.passphrase != self.confirm_passphrase {
+                    Err("Passphrases do not match".to_string())
+                } else {
+                    NoteService::lock_note(self.note_id, &self.passphrase)
+                };
+
+                match result {
+                    Ok(_) => should_close = true,
+                    Err(e) => self.error = Some(e),
+                }
+            }
+            if ui.add(Theme::button("Cancel")).clicked() {
+                should_close = true;
+            }
+        });
+
+        // Don't keep the passphrase around once we are done
+        if should_close {
+            self.passphrase.clear();
+            self.confirm_passphrase.clear();
+        }
+
+        should_close
+    }
+}
@@ -1,14 +1,88 @@
 // src/ui/modals/manage_feeds_modal.rs
 use eframe::egui;
+use std::path::PathBuf;
 use crate::ui::modals::modal_trait::Modal;
 use crate::dal::FeedsRepository;
+use crate::models::Feed;
 use crate::ui::styles::Theme;
-use crate::services::log_service;
+use crate::services::{log_service, rss_service};
 
-#[derive(Default)]
 pub struct ManageFeedsModal {
-    feeds: Vec<(i32, String, String)>, // (id, url, title)
+    feeds: Vec<Feed>,
     loaded: bool,
+    import_path: String,
+    /// Fetch the feeds an import added right away instead of waiting for the next refresh
+    fetch_after_import: bool,
+    export_path: String,
+    status: Option<Result<String, String>>,
+}
+
+impl Default for ManageFeedsModal {
+    fn default() -> Self {
+        let in_dir = |dir: Option<PathBuf>, file: &str| {
+            dir.or_else(dirs::home_dir)
+                .map(|dir| dir.join(file).to_string_lossy().to_string())
+                .unwrap_or_default()
+        };
+
+        Self {
+            feeds: Vec::new(),
+            loaded: false,
+            import_path: in_dir(dirs::download_dir(), "feeds.opml"),
+            fetch_after_import: true,
+            export_path: in_dir(dirs::document_dir(), "dry-dock-feeds.opml"),
+            status: None,
+        }
+    }
+}
+
+impl ManageFeedsModal {
+    fn import(&mut self) {
+        let path = PathBuf::from(self.import_path.trim());
+        self.status = Some(match rss_service::import_opml(&path) {
+            Ok(report) => {
+                let mut message = format!("Subscribed to {} feed(s), {} already subscribed", report.added.len(), report.duplicates);
+                if self.fetch_after_import && !report.added.is_empty() {
+                    message.push_str(", fetching them in the background");
+                    let feeds = report.added;
+                    std::thread::spawn(move || {
+                        let runtime = tokio::runtime::Runtime::new().unwrap();
+                        let summary = runtime.block_on(rss_service::fetch_imported_feeds(feeds));
+                        log_service::add_log_entry("INFO", &summary);
+                    });
+                }
+                self.loaded = false;
+                Ok(message)
+            }
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("OPML import failed: {}", e));
+                Err(e)
+            }
+        });
+    }
+
+    fn export(&mut self) {
+        let path = PathBuf::from(self.export_path.trim());
+        self.status = Some(match rss_service::export_opml(&path) {
+            Ok(count) => Ok(format!("Exported {} feed(s) to {}", count, path.display())),
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("OPML export failed: {}", e));
+                Err(e)
+            }
+        });
+    }
+
+    fn path_field(ui: &mut egui::Ui, label: &str, value: &mut String) {
+        ui.label(egui::RichText::new(label).size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.add(
+            egui::TextEdit::singleline(value)
+                .hint_text("/path/to/feeds.opml")
+                .desired_width(f32::INFINITY)
+                .margin(egui::vec2(8.0, 8.0)),
+        );
+        ui.add_space(Theme::SPACING_SMALL);
+    }
 }
 
 impl Modal for ManageFeedsModal {
@@ -21,7 +95,7 @@ impl Modal for ManageFeedsModal {
         
         // Load feeds on first render
         if !self.loaded {
-            match FeedsRepository::get_all() {
+            match rss_service::get_feeds() {
                 Ok(feeds) => {
                     self.feeds = feeds;
                     self.loaded = true;
@@ -58,26 +132,31 @@ impl Modal for ManageFeedsModal {
         let mut id_to_delete: Option<i32> = None;
         
         egui::ScrollArea::vertical()
-            .max_height(400.0)
+            .max_height(280.0)
             .show(ui, |ui| {
-                for (feed_id, url, title) in &self.feeds {
+                for feed in &self.feeds {
                     Theme::card_frame().show(ui, |ui| {
                         ui.horizontal(|ui| {
                             ui.vertical(|ui| {
                                 ui.set_min_width(ui.available_width() - 140.0);
-                                ui.label(egui::RichText::new(title)
+                                ui.label(egui::RichText::new(&feed.title)
                                     .size(Theme::FONT_SIZE_BODY)
                                     .strong()
                                     .color(Theme::TEXT_PRIMARY));
                                 ui.add_space(Theme::SPACING_SMALL);
-                                ui.label(egui::RichText::new(url)
+                                ui.label(egui::RichText::new(&feed.url)
                                     .size(Theme::FONT_SIZE_SMALL)
                                     .color(Theme::TEXT_MUTED));
+                                if !feed.category.is_empty() {
+                                    ui.label(egui::RichText::new(format!("📁 {}", feed.category))
+                                        .size(Theme::FONT_SIZE_SMALL)
+                                        .color(Theme::TEXT_MUTED));
+                                }
                             });
                             
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                 if ui.add(Theme::danger_button("Delete")).clicked() {
-                                    id_to_delete = Some(*feed_id);
+                                    id_to_delete = Some(feed.id);
                                 }
                             });
                         });
@@ -90,7 +169,7 @@ impl Modal for ManageFeedsModal {
             match FeedsRepository::delete(id) {
                 Ok(_) => {
                     log_service::add_log_entry("INFO", "Feed deleted successfully.");
-                    self.feeds.retain(|(feed_id, _, _)| *feed_id != id);
+                    self.feeds.retain(|feed| feed.id != id);
                 }
                 Err(e) => {
                     log_service::add_log_entry("ERROR", &format!("Error deleting feed: {}", e));
@@ -98,7 +177,35 @@ impl Modal for ManageFeedsModal {
             }
         }
 
+        ui.add_space(Theme::SPACING_MEDIUM);
+        ui.separator();
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        // OPML, the subscription list format feed readers share
+        Self::path_field(ui, "Import OPML (folders become categories, feeds you already have are skipped)", &mut self.import_path);
+        ui.horizontal(|ui| {
+            if ui.add_enabled(!self.import_path.trim().is_empty(), Theme::primary_button("Import")).clicked() {
+                self.import();
+            }
+            ui.checkbox(&mut self.fetch_after_import, "Fetch new feeds right away");
+        });
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        Self::path_field(ui, "Export OPML to file", &mut self.export_path);
+        if ui.add_enabled(!self.export_path.trim().is_empty(), Theme::primary_button("Export")).clicked() {
+            self.export();
+        }
+
         ui.add_space(Theme::SPACING_LARGE);
+
+        if let Some(status) = &self.status {
+            match status {
+                Ok(message) => ui.colored_label(Theme::SUCCESS_COLOR, message),
+                Err(error) => ui.colored_label(Theme::DANGER_COLOR, error),
+            };
+            ui.add_space(Theme::SPACING_SMALL);
+        }
         
         ui.horizontal(|ui| {
             if ui.add(Theme::button("Close")).clicked() {
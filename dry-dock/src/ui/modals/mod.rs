@@ -4,12 +4,23 @@ pub mod active_modal;
 pub mod add_feed_modal;
 pub mod create_note_modal;
 pub mod add_bookmark_modal;
+pub mod bookmark_fields;
+pub mod import_bookmarks_modal;
+pub mod bookmark_snapshots_modal;
+pub mod bookmark_duplicates_modal;
+pub mod quick_launcher_modal;
+pub mod file_preview_modal;
 pub mod update_note_modal;
+pub mod draft_banner;
 pub mod update_bookmark_modal;
 pub mod view_note_modal;
+pub mod lock_note_modal;
+pub mod export_notes_modal;
+pub mod publish_site_modal;
 pub mod manage_feeds_modal;
 pub mod log_modal;
 pub mod settings_modal;
+pub mod note_conflicts_modal;
 pub mod modal_factory;
 
 pub use modal_trait::Modal;
@@ -19,8 +30,17 @@ pub use create_note_modal::CreateNoteModal;
 pub use add_bookmark_modal::AddBookmarkModal;
 pub use update_note_modal::UpdateNoteModal;
 pub use update_bookmark_modal::UpdateBookmarkModal;
+pub use import_bookmarks_modal::ImportBookmarksModal;
+pub use bookmark_snapshots_modal::BookmarkSnapshotsModal;
+pub use bookmark_duplicates_modal::BookmarkDuplicatesModal;
+pub use quick_launcher_modal::QuickLauncherModal;
+pub use file_preview_modal::FilePreviewModal;
 pub use view_note_modal::ViewNoteModal;
+pub use lock_note_modal::LockNoteModal;
+pub use export_notes_modal::ExportNotesModal;
+pub use publish_site_modal::PublishSiteModal;
 pub use manage_feeds_modal::ManageFeedsModal;
 pub use settings_modal::SettingsModal;
+pub use note_conflicts_modal::NoteConflictsModal;
 pub use modal_factory::ModalFactory;
 pub use log_modal::LogModal;
\ No newline at end of file
@@ -4,6 +4,7 @@ use crate::ui::modals::*;
 use std::sync::{Arc, Mutex};
 use crate::ui::screens::ScreenFactory;
 use crate::app::ActiveScreen;
+use crate::services::{draft_service, log_service};
 
 /// Type alias for boxed modal trait objects
 type BoxedModal = Box<dyn Modal>;
@@ -32,6 +33,9 @@ impl ModalFactory {
 
     /// Open a modal by type
     pub fn open_modal(&mut self, modal_type: ActiveModal) {
+        // The modal being replaced may hold unsaved note text
+        self.save_drafts();
+
         if modal_type == ActiveModal::None {
             self.active_modal = None;
             return;
@@ -44,17 +48,49 @@ impl ModalFactory {
             ActiveModal::ManageFeeds => Box::new(ManageFeedsModal::default()),
             ActiveModal::UpdateBookmark(id) => Box::new(UpdateBookmarkModal::new(id)),
             ActiveModal::UpdateNote(id) => Box::new(UpdateNoteModal::new(id)),
+            ActiveModal::RestoreDraft(id) => match draft_service::get_draft(id) {
+                Ok(draft) => match draft.note_id {
+                    Some(note_id) => Box::new(UpdateNoteModal::from_draft(note_id, draft)),
+                    None => Box::new(CreateNoteModal::from_draft(draft)),
+                },
+                Err(e) => {
+                    log_service::add_log_entry("ERROR", &format!("Error loading draft: {}", e));
+                    return;
+                }
+            },
             ActiveModal::ViewNote(id) => Box::new(ViewNoteModal::new(id)),
+            ActiveModal::LockNote(id) => Box::new(LockNoteModal::new(id)),
+            ActiveModal::ExportNotes(ref ids) => Box::new(ExportNotesModal::new(ids.clone())),
             ActiveModal::LogModal => Box::new(LogModal::default()),
-            ActiveModal::Settings => Box::new(SettingsModal),
+            ActiveModal::Settings => Box::new(SettingsModal::default()),
+            ActiveModal::NoteConflicts => Box::new(NoteConflictsModal::default()),
+            ActiveModal::PublishSite => Box::new(PublishSiteModal::default()),
+            ActiveModal::ImportBookmarks => Box::new(ImportBookmarksModal::default()),
+            ActiveModal::BookmarkSnapshots(id) => Box::new(BookmarkSnapshotsModal::new(id)),
+            ActiveModal::BookmarkDuplicates => Box::new(BookmarkDuplicatesModal::default()),
+            ActiveModal::QuickLauncher => Box::new(QuickLauncherModal::default()),
+            ActiveModal::FilePreview(ref path) => Box::new(FilePreviewModal::new(path.clone())),
             ActiveModal::None => return,
         };
 
         self.active_modal = Some((modal_type.clone(), modal));
     }
 
+    /// Write the active modal's unsaved input to the drafts table (app exit, modal replaced or closed)
+    pub fn save_drafts(&mut self) {
+        if let Some((_, modal)) = &mut self.active_modal {
+            modal.save_draft();
+        }
+    }
+
+    /// True while a modal is showing
+    pub fn is_open(&self) -> bool {
+        self.active_modal.is_some()
+    }
+
     /// Close the currently active modal
     pub fn close_modal(&mut self) {
+        self.save_drafts();
         self.active_modal = None;
     }
 
@@ -83,10 +119,21 @@ impl ModalFactory {
             let (default_size, resizable) = match modal_type {
                 ActiveModal::CreateNote => ([800.0, 600.0], true),
                 ActiveModal::UpdateNote(_) => ([800.0, 600.0], true),
+                ActiveModal::RestoreDraft(_) => ([800.0, 600.0], true),
                 ActiveModal::ViewNote(_) => ([900.0, 700.0], true),
-                ActiveModal::ManageFeeds => ([600.0, 500.0], true),
+                ActiveModal::ManageFeeds => ([650.0, 760.0], true),
                 ActiveModal::LogModal => ([900.0, 900.0], true),
-                ActiveModal::Settings => ([600.0, 400.0], true),
+                ActiveModal::Settings => ([600.0, 520.0], true),
+                ActiveModal::NoteConflicts => ([900.0, 700.0], true),
+                ActiveModal::ExportNotes(_) => ([600.0, 450.0], true),
+                ActiveModal::PublishSite => ([600.0, 550.0], true),
+                ActiveModal::AddBookmark => ([500.0, 600.0], true),
+                ActiveModal::UpdateBookmark(_) => ([500.0, 600.0], true),
+                ActiveModal::ImportBookmarks => ([600.0, 650.0], true),
+                ActiveModal::BookmarkSnapshots(_) => ([600.0, 600.0], true),
+                ActiveModal::BookmarkDuplicates => ([650.0, 650.0], true),
+                ActiveModal::QuickLauncher => ([600.0, 480.0], true),
+                ActiveModal::FilePreview(_) => ([800.0, 650.0], true),
                 _ => ([400.0, 300.0], false),
             };
 
@@ -105,10 +152,17 @@ impl ModalFactory {
                 let screen_to_reload = match modal_type {
                     ActiveModal::CreateNote => Some(ActiveScreen::Notes),
                     ActiveModal::UpdateNote(_) => Some(ActiveScreen::Notes),
+                    ActiveModal::RestoreDraft(_) => Some(ActiveScreen::Notes),
+                    ActiveModal::NoteConflicts => Some(ActiveScreen::Notes),
+                    ActiveModal::LockNote(_) => Some(ActiveScreen::Notes),
+                    ActiveModal::Settings => Some(ActiveScreen::Notes),
                     ActiveModal::AddFeed => Some(ActiveScreen::Feeds),
                     ActiveModal::ManageFeeds => Some(ActiveScreen::Feeds),
                     ActiveModal::AddBookmark => Some(ActiveScreen::Bookmarks),
                     ActiveModal::UpdateBookmark(_) => Some(ActiveScreen::Bookmarks),
+                    ActiveModal::ImportBookmarks => Some(ActiveScreen::Bookmarks),
+                    ActiveModal::BookmarkDuplicates => Some(ActiveScreen::Bookmarks),
+                    ActiveModal::QuickLauncher => Some(ActiveScreen::Bookmarks),
                     _ => None,
                 };
 
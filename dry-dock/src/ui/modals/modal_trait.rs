@@ -5,4 +5,7 @@ use eframe::egui;
 pub trait Modal {
     fn title(&self) -> &str;
     fn render(&mut self, ui: &mut egui::Ui) -> bool; // Returns true if should close
+
+    /// Persist unsaved input before the app exits (only note modals keep drafts)
+    fn save_draft(&mut self) {}
 }
@@ -0,0 +1,134 @@
+// src/ui/modals/note_conflicts_modal.rs
+use eframe::egui;
+use crate::ui::modals::modal_trait::Modal;
+use crate::models::{ConflictResolution, NoteSyncConflict};
+use crate::services::{log_service, note_mirror_service};
+use crate::ui::styles::Theme;
+
+/// Lists notes that changed both in Dry Dock and on disk and lets the user pick a side
+#[derive(Default)]
+pub struct NoteConflictsModal {
+    conflicts: Vec<NoteSyncConflict>,
+    loaded: bool,
+    error: Option<String>,
+}
+
+impl Modal for NoteConflictsModal {
+    fn title(&self) -> &str {
+        "Mirror Conflicts"
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut should_close = false;
+
+        // Load conflicts on first render
+        if !self.loaded {
+            match note_mirror_service::get_conflicts() {
+                Ok(conflicts) => {
+                    self.conflicts = conflicts;
+                    self.loaded = true;
+                }
+                Err(e) => {
+                    ui.colored_label(Theme::DANGER_COLOR, format!("Error loading conflicts: {}", e));
+                    return true;
+                }
+            }
+        }
+
+        Theme::apply_body_style(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        if self.conflicts.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(Theme::SPACING_XL);
+                ui.label(egui::RichText::new("No conflicts")
+                    .size(Theme::FONT_SIZE_SUBHEADING)
+                    .color(Theme::TEXT_SECONDARY));
+                ui.add_space(Theme::SPACING_SMALL);
+                ui.label(egui::RichText::new("Dry Dock and the mirror folder are in sync")
+                    .color(Theme::TEXT_MUTED));
+            });
+        } else {
+            ui.label(egui::RichText::new(format!("{} note(s) changed in Dry Dock and on disk", self.conflicts.len()))
+                .size(Theme::FONT_SIZE_BODY)
+                .color(Theme::TEXT_SECONDARY));
+            ui.add_space(Theme::SPACING_MEDIUM);
+        }
+
+        if let Some(error) = &self.error {
+            ui.colored_label(Theme::DANGER_COLOR, error);
+            ui.add_space(Theme::SPACING_SMALL);
+        }
+
+        // Track the chosen resolution and apply it after iteration
+        let mut resolution: Option<(i32, ConflictResolution)> = None;
+
+        egui::ScrollArea::vertical()
+            .max_height(ui.available_height() - 60.0)
+            .show(ui, |ui| {
+                for conflict in &self.conflicts {
+                    Theme::card_frame().show(ui, |ui| {
+                        ui.label(egui::RichText::new(&conflict.title)
+                            .size(Theme::FONT_SIZE_BODY)
+                            .strong()
+                            .color(Theme::TEXT_PRIMARY));
+                        let detected = chrono::DateTime::from_timestamp(conflict.detected_at, 0)
+                            .map(|dt| dt.format("%b %d, %Y %H:%M").to_string())
+                            .unwrap_or_else(|| "Unknown".to_string());
+                        ui.label(egui::RichText::new(format!("{}  •  detected {}", conflict.file_path, detected))
+                            .size(Theme::FONT_SIZE_SMALL)
+                            .color(Theme::TEXT_MUTED));
+                        ui.add_space(Theme::SPACING_SMALL);
+
+                        ui.columns(2, |columns| {
+                            columns[0].label(egui::RichText::new("Dry Dock").strong().color(Theme::TEXT_SECONDARY));
+                            columns[1].label(egui::RichText::new("File on disk").strong().color(Theme::TEXT_SECONDARY));
+                            for (column, content) in [(0, &conflict.note_content), (1, &conflict.file_content)] {
+                                egui::ScrollArea::vertical()
+                                    .id_salt(("conflict", conflict.note_id, column))
+                                    .max_height(200.0)
+                                    .show(&mut columns[column], |ui| {
+                                        ui.label(egui::RichText::new(content.as_str()).monospace().size(Theme::FONT_SIZE_SMALL));
+                                    });
+                            }
+                        });
+
+                        ui.add_space(Theme::SPACING_SMALL);
+                        ui.horizontal(|ui| {
+                            if ui.add(Theme::primary_button("Keep Dry Dock version")).clicked() {
+                                resolution = Some((conflict.note_id, ConflictResolution::KeepNote));
+                            }
+                            if ui.add(Theme::button("Keep file version")).clicked() {
+                                resolution = Some((conflict.note_id, ConflictResolution::KeepFile));
+                            }
+                        });
+                    });
+                    ui.add_space(Theme::SPACING_SMALL);
+                }
+            });
+
+        if let Some((note_id, choice)) = resolution {
+            match note_mirror_service::resolve_conflict(note_id, choice) {
+                Ok(_) => {
+                    self.error = None;
+                    self.conflicts.retain(|c| c.note_id != note_id);
+                }
+                Err(e) => {
+                    log_service::add_log_entry("ERROR", &format!("Error resolving conflict: {}", e));
+                    self.error = Some(e);
+                }
+            }
+        }
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.horizontal(|ui| {
+            if ui.add(Theme::button("Close")).clicked() {
+                should_close = true;
+            }
+        });
+
+        should_close
+    }
+}
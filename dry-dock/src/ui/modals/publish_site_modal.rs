@@ -0,0 +1,109 @@
+// src/ui/modals/publish_site_modal.rs
+use eframe::egui;
+use crate::ui::modals::modal_trait::Modal;
+use crate::models::PublishSettings;
+use crate::services::{log_service, publish_service, settings_service};
+use crate::ui::styles::Theme;
+
+/// Publish tagged notes as a static website in a local folder
+#[derive(Default)]
+pub struct PublishSiteModal {
+    settings: PublishSettings,
+    loaded: bool,
+    status: Option<Result<String, String>>,
+}
+
+impl PublishSiteModal {
+    fn publish(&mut self) {
+        let result = settings_service::save_publish_settings(&self.settings)
+            .and_then(|_| publish_service::publish(&self.settings));
+
+        self.status = Some(match result {
+            Ok(report) => {
+                let mut message = format!("Published {} note(s)", report.pages);
+                if report.images > 0 {
+                    message.push_str(&format!(", {} image(s)", report.images));
+                }
+                if report.removed > 0 {
+                    message.push_str(&format!(", removed {} old file(s)", report.removed));
+                }
+                if report.broken_links > 0 {
+                    message.push_str(&format!(". {} wiki link(s) point at notes that aren't published", report.broken_links));
+                }
+                Ok(message)
+            }
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Publishing failed: {}", e));
+                Err(e)
+            }
+        });
+    }
+}
+
+impl Modal for PublishSiteModal {
+    fn title(&self) -> &str {
+        "Publish Notes"
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut should_close = false;
+
+        // Load settings on first render
+        if !self.loaded {
+            self.settings = settings_service::get_publish_settings();
+            self.loaded = true;
+        }
+
+        Theme::apply_body_style(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Renders every note with the tag below to HTML pages with an index, working [[wiki links]] and an Atom feed. Locked and archived notes are left out.")
+            .color(Theme::TEXT_MUTED));
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        let fields = [
+            ("Tag", &mut self.settings.tag, "public"),
+            ("Site title", &mut self.settings.site_title, "Notes"),
+            ("Output folder", &mut self.settings.directory, "/path/to/site"),
+            ("Site address (for feed links)", &mut self.settings.base_url, "https://example.com/notes"),
+        ];
+        for (label, value, hint) in fields {
+            ui.label(egui::RichText::new(label).size(Theme::FONT_SIZE_BODY).strong());
+            ui.add_space(Theme::SPACING_SMALL);
+            ui.add(
+                egui::TextEdit::singleline(value)
+                    .hint_text(hint)
+                    .desired_width(f32::INFINITY)
+                    .margin(egui::vec2(8.0, 8.0)),
+            );
+            ui.add_space(Theme::SPACING_MEDIUM);
+        }
+
+        if let Some(status) = &self.status {
+            match status {
+                Ok(message) => ui.colored_label(Theme::SUCCESS_COLOR, message),
+                Err(error) => ui.colored_label(Theme::DANGER_COLOR, error),
+            };
+            ui.add_space(Theme::SPACING_SMALL);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.add(Theme::primary_button("Publish")).clicked() {
+                self.publish();
+            }
+            if matches!(self.status, Some(Ok(_)))
+                && ui.add(Theme::button("Open Folder")).clicked()
+                && let Err(e) = opener::open(self.settings.directory.trim())
+            {
+                log_service::add_log_entry("ERROR", &format!("Failed to open folder: {}", e));
+            }
+            if ui.add(Theme::button("Close")).clicked() {
+                should_close = true;
+            }
+        });
+
+        should_close
+    }
+}
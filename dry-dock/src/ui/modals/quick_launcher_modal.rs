@@ -0,0 +1,155 @@
+// src/ui/modals/quick_launcher_modal.rs
+use eframe::egui;
+use std::collections::HashMap;
+use crate::ui::modals::modal_trait::Modal;
+use crate::models::{Bookmark, BookmarkKind};
+use crate::services::{bookmark_opener_service, bookmark_service, log_service};
+use crate::ui::styles::Theme;
+
+/// How many matches are listed
+const MAX_RESULTS: usize = 12;
+
+/// Type to find a bookmark, Enter opens it (Ctrl+K anywhere, see AppState::handle_shortcuts)
+#[derive(Default)]
+pub struct QuickLauncherModal {
+    /// Every bookmark, loaded once when the launcher opens and searched in memory
+    bookmarks: Option<Vec<Bookmark>>,
+    /// Kinds of the listed bookmarks, paths are checked on disk for File or Folder once
+    kinds: HashMap<i32, BookmarkKind>,
+    query: String,
+    results: Vec<Bookmark>,
+    selected: usize,
+    /// Query the results are for, None until the first search
+    searched: Option<String>,
+    error: Option<String>,
+}
+
+impl QuickLauncherModal {
+    fn load(&mut self) {
+        match bookmark_service::fetch_all_bookmarks() {
+            Ok(bookmarks) => self.bookmarks = Some(bookmarks),
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Error loading bookmarks: {}", e));
+                self.bookmarks = Some(Vec::new());
+                self.error = Some(e);
+            }
+        }
+    }
+
+    fn search(&mut self) {
+        let bookmarks = self.bookmarks.as_deref().unwrap_or_default();
+        self.results = bookmark_service::search_bookmarks(bookmarks, &self.query, MAX_RESULTS);
+        self.selected = 0;
+        self.searched = Some(self.query.clone());
+    }
+
+    fn open(bookmark: &Bookmark) {
+        bookmark_service::open_bookmark_path(bookmark.id, &bookmark.path);
+    }
+
+    fn copy(ctx: &egui::Context, bookmark: &Bookmark) {
+        ctx.copy_text(bookmark.path.clone());
+        log_service::add_log_entry("INFO", &format!("Copied {} to the clipboard.", bookmark.path));
+    }
+}
+
+impl Modal for QuickLauncherModal {
+    fn title(&self) -> &str {
+        "Open Bookmark"
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut should_close = false;
+
+        if self.bookmarks.is_none() {
+            self.load();
+        }
+        if self.searched.as_ref() != Some(&self.query) {
+            self.search();
+        }
+
+        // Take the keys before the text field sees them
+        let (down, up, copy, open, escape) = ui.input_mut(|i| (
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+            i.consume_key(egui::Modifiers::COMMAND, egui::Key::Enter),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+        ));
+        if down && self.selected + 1 < self.results.len() {
+            self.selected += 1;
+        }
+        if up {
+            self.selected = self.selected.saturating_sub(1);
+        }
+
+        Theme::apply_body_style(ui);
+
+        ui.add_space(Theme::SPACING_SMALL);
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut self.query)
+                .hint_text("Type a name or address...")
+                .desired_width(f32::INFINITY)
+                .margin(egui::vec2(8.0, 8.0)),
+        );
+        response.request_focus();
+        ui.add_space(Theme::SPACING_SMALL);
+
+        ui.label(egui::RichText::new("↑↓ to move · Enter to open · Ctrl+Enter to copy the location · Esc to close")
+            .size(Theme::FONT_SIZE_SMALL)
+            .color(Theme::TEXT_MUTED));
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        if let Some(error) = &self.error {
+            ui.colored_label(Theme::DANGER_COLOR, error);
+        } else if self.results.is_empty() {
+            ui.label(egui::RichText::new("No matching bookmarks").color(Theme::TEXT_SECONDARY));
+        }
+
+        let mut clicked = None;
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                for (index, bookmark) in self.results.iter().enumerate() {
+                    let selected = index == self.selected;
+                    let kind = *self.kinds
+                        .entry(bookmark.id)
+                        .or_insert_with(|| bookmark_opener_service::resolve_kind(bookmark.kind, &bookmark.path));
+                    let response = ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(kind.icon()).size(Theme::FONT_SIZE_BODY));
+                        ui.vertical(|ui| {
+                            let name = egui::RichText::new(&bookmark.name).strong();
+                            ui.selectable_label(selected, if selected { name.color(Theme::PRIMARY_COLOR) } else { name })
+                        })
+                        .inner
+                    });
+                    ui.label(egui::RichText::new(&bookmark.display)
+                        .size(Theme::FONT_SIZE_SMALL)
+                        .color(Theme::TEXT_MUTED));
+                    ui.add_space(Theme::SPACING_SMALL);
+
+                    if selected && (up || down) {
+                        response.inner.scroll_to_me(None);
+                    }
+                    if response.inner.clicked() {
+                        clicked = Some(index);
+                    }
+                }
+            });
+
+        if let Some(index) = clicked {
+            Self::open(&self.results[index]);
+            should_close = true;
+        } else if let Some(bookmark) = self.results.get(self.selected) {
+            if copy {
+                Self::copy(ui.ctx(), bookmark);
+                should_close = true;
+            } else if open {
+                Self::open(bookmark);
+                should_close = true;
+            }
+        }
+
+        should_close || escape
+    }
+}
@@ -1,9 +1,17 @@
 // src/ui/modals/settings_modal.rs
 use eframe::egui;
 use crate::ui::modals::modal_trait::Modal;
+use crate::models::NoteMirrorSettings;
+use crate::services::{log_service, note_mirror_service, settings_service};
+use crate::ui::styles::Theme;
 
 #[derive(Default)]
-pub struct SettingsModal;
+pub struct SettingsModal {
+    note_mirror: NoteMirrorSettings,
+    editor_command: String,
+    loaded: bool,
+    status: Option<Result<String, String>>,
+}
 
 impl Modal for SettingsModal {
     fn title(&self) -> &str {
@@ -12,12 +20,86 @@ impl Modal for SettingsModal {
     
     fn render(&mut self, ui: &mut egui::Ui) -> bool {
         let mut should_close = false;
-        
-        ui.label("Settings modal is under construction.");
-        
-        if ui.button("Close").clicked() {
-            should_close = true;
+
+        // Load settings on first render
+        if !self.loaded {
+            self.note_mirror = settings_service::get_note_mirror_settings();
+            self.editor_command = settings_service::get_editor_command();
+            self.loaded = true;
         }
+
+        Theme::apply_body_style(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        // Notes mirror
+        ui.label(egui::RichText::new("Notes Mirror").size(Theme::FONT_SIZE_SUBHEADING).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.label(egui::RichText::new("Keep every note as a markdown file in a folder. Edits made to the files are pulled back into Dry Dock.")
+            .color(Theme::TEXT_MUTED));
+        ui.add_space(Theme::SPACING_SMALL);
+
+        ui.checkbox(&mut self.note_mirror.enabled, "Mirror notes to markdown files");
+        ui.add_space(Theme::SPACING_SMALL);
+
+        ui.label(egui::RichText::new("Folder").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_enabled(
+            self.note_mirror.enabled,
+            egui::TextEdit::singleline(&mut self.note_mirror.directory)
+                .hint_text("/path/to/notes")
+                .desired_width(f32::INFINITY)
+                .margin(egui::vec2(8.0, 8.0)),
+        );
+
+        ui.add_space(Theme::SPACING_LARGE);
+
+        // Bookmarks
+        ui.label(egui::RichText::new("Bookmarks").size(Theme::FONT_SIZE_SUBHEADING).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.label(egui::RichText::new("Editor command used by the Editor button on folder bookmarks, the folder is added at the end.")
+            .color(Theme::TEXT_MUTED));
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.add(
+            egui::TextEdit::singleline(&mut self.editor_command)
+                .hint_text("code")
+                .desired_width(f32::INFINITY)
+                .margin(egui::vec2(8.0, 8.0)),
+        );
+
+        ui.add_space(Theme::SPACING_LARGE);
+
+        if let Some(status) = &self.status {
+            match status {
+                Ok(message) => ui.colored_label(Theme::SUCCESS_COLOR, message),
+                Err(error) => ui.colored_label(Theme::DANGER_COLOR, error),
+            };
+            ui.add_space(Theme::SPACING_SMALL);
+        }
+
+        ui.horizontal(|ui| {
+            if ui.add(Theme::primary_button("Save")).clicked() {
+                let saved = settings_service::save_note_mirror_settings(&self.note_mirror)
+                    .and_then(|_| settings_service::save_editor_command(&self.editor_command));
+                self.status = Some(match saved {
+                    Ok(_) => {
+                        log_service::add_log_entry("INFO", "Settings saved.");
+                        if self.note_mirror.enabled {
+                            // Run the first sync in the background, large note collections can take a moment
+                            std::thread::spawn(|| {
+                                if let Err(e) = note_mirror_service::sync_all() {
+                                    log_service::add_log_entry("ERROR", &format!("Notes mirror sync failed: {}", e));
+                                }
+                            });
+                        }
+                        Ok("Settings saved".to_string())
+                    }
+                    Err(e) => Err(e),
+                });
+            }
+            if ui.add(Theme::button("Close")).clicked() {
+                should_close = true;
+            }
+        });
         
         should_close
     }
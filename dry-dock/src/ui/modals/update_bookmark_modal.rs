@@ -1,14 +1,21 @@
 // src/ui/modals/update_bookmark_modal.rs
 use eframe::egui;
 use crate::ui::modals::modal_trait::Modal;
-use crate::dal::BookmarksRepository;
+use crate::ui::modals::bookmark_fields::{render_description_field, render_folder_picker, render_location_kind, render_tags_field, DuplicateWarning, PageDetailsFetcher};
 use crate::ui::styles::Theme;
-use crate::services::log_service;
+use crate::services::{bookmark_service, log_service};
 
 pub struct UpdateBookmarkModal {
     bookmark_id: i32,
     name: String,
     location: String,
+    description: String,
+    folder_id: Option<i32>,
+    tags: String,
+    folder_paths: Vec<(i32, String)>,
+    page_details: PageDetailsFetcher,
+    duplicate_warning: DuplicateWarning,
+    error: Option<String>,
     loaded: bool,
 }
 
@@ -18,6 +25,13 @@ impl UpdateBookmarkModal {
             bookmark_id,
             name: String::new(),
             location: String::new(),
+            description: String::new(),
+            folder_id: None,
+            tags: String::new(),
+            folder_paths: Vec::new(),
+            page_details: PageDetailsFetcher::default(),
+            duplicate_warning: DuplicateWarning::default(),
+            error: None,
             loaded: false,
         }
     }
@@ -33,10 +47,13 @@ impl Modal for UpdateBookmarkModal {
         
         // Load bookmark data on first render
         if !self.loaded {
-            match BookmarksRepository::get_by_id(self.bookmark_id) {
-                Ok((_, name, location, _)) => {
-                    self.name = name;
-                    self.location = location;
+            match bookmark_service::get_bookmark(self.bookmark_id) {
+                Ok(bookmark) => {
+                    self.tags = bookmark.tags_text();
+                    self.name = bookmark.name;
+                    self.location = bookmark.path;
+                    self.description = bookmark.description;
+                    self.folder_id = bookmark.folder_id;
                     self.loaded = true;
                 }
                 Err(e) => {
@@ -44,6 +61,24 @@ impl Modal for UpdateBookmarkModal {
                     return true; // Close modal on error
                 }
             }
+            match bookmark_service::get_folders() {
+                Ok(folders) => self.folder_paths = bookmark_service::folder_paths(&folders),
+                Err(e) => log_service::add_log_entry("ERROR", &format!("Error loading bookmark folders: {}", e)),
+            }
+        }
+
+        // Fetched details only fill empty fields
+        if let Some(details) = self.page_details.poll(ui.ctx()) {
+            if let Some(title) = details.title
+                && self.name.trim().is_empty()
+            {
+                self.name = title;
+            }
+            if let Some(description) = details.description
+                && self.description.trim().is_empty()
+            {
+                self.description = description;
+            }
         }
 
         Theme::apply_body_style(ui);
@@ -63,19 +98,45 @@ impl Modal for UpdateBookmarkModal {
         ui.label(egui::RichText::new("Location").size(Theme::FONT_SIZE_BODY).strong());
         ui.add_space(Theme::SPACING_SMALL);
         let location_edit = egui::TextEdit::singleline(&mut self.location)
-            .hint_text("URL or file path")
+            .hint_text("URL, path, mailto:, ssh://, cmd: or app:")
             .desired_width(f32::INFINITY)
             .margin(egui::vec2(8.0, 8.0));
         ui.add(location_edit);
+
+        render_location_kind(ui, &self.location);
+        self.duplicate_warning.render(ui, &self.location, Some(self.bookmark_id));
+        let is_url = self.location.starts_with("http://") || self.location.starts_with("https://");
+        if is_url && ui.small_button("Fetch title, description and icon").clicked() {
+            self.page_details.fetch(&self.location);
+        }
+        self.page_details.render_status(ui);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        render_description_field(ui, &mut self.description);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        render_folder_picker(ui, &self.folder_paths, &mut self.folder_id);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        render_tags_field(ui, &mut self.tags);
         
         ui.add_space(Theme::SPACING_LARGE);
 
+        // Stay open so the location can be fixed
+        if let Some(error) = &self.error {
+            ui.colored_label(Theme::DANGER_COLOR, error);
+            ui.add_space(Theme::SPACING_SMALL);
+        }
+
         ui.horizontal(|ui| {
             if ui.add(Theme::primary_button("Update Bookmark")).clicked() {
-                if let Err(e) = BookmarksRepository::update(self.bookmark_id, &self.name, &self.location) {
-                    log_service::add_log_entry("ERROR", &format!("Error updating bookmark: {}", e));
-                } 
-                should_close = true;
+                match bookmark_service::update_bookmark(self.bookmark_id, &self.name, &self.location, &self.description, self.folder_id, &self.tags) {
+                    Ok(_) => should_close = true,
+                    Err(e) => {
+                        log_service::add_log_entry("ERROR", &format!("Error updating bookmark: {}", e));
+                        self.error = Some(e);
+                    }
+                }
             }
             if ui.add(Theme::button("Cancel")).clicked() {
                 should_close = true;
@@ -1,14 +1,27 @@
 // src/ui/modals/update_note_modal.rs
 use eframe::egui;
 use crate::ui::modals::modal_trait::Modal;
+use crate::ui::modals::draft_banner::{render_draft_banner, DraftChoice};
+use crate::models::NoteDraft;
 use crate::services::NoteService;
-use crate::services::log_service;
+use crate::services::{draft_service, log_service};
+use crate::services::draft_service::DraftSession;
 use crate::ui::styles::Theme;
 
 pub struct UpdateNoteModal {
     note_id: i32,
     title: String,
+    notebook: String,
+    tags: String,
     details: String,
+    is_locked: bool,
+    passphrase: String,
+    unlocked: bool,
+    unlock_error: Option<String>,
+    draft: DraftSession,
+    // Leftover draft offered for restore, or applied right away when opened from the Drafts list
+    pending_draft: Option<NoteDraft>,
+    restore_on_load: bool,
     loaded: bool,
 }
 
@@ -17,10 +30,35 @@ impl UpdateNoteModal {
         Self {
             note_id,
             title: String::new(),
+            notebook: String::new(),
+            tags: String::new(),
             details: String::new(),
+            is_locked: false,
+            passphrase: String::new(),
+            unlocked: false,
+            unlock_error: None,
+            draft: DraftSession::new(Some(note_id)),
+            pending_draft: None,
+            restore_on_load: false,
             loaded: false,
         }
     }
+
+    /// Open the modal with a draft already restored (from the Drafts list)
+    pub fn from_draft(note_id: i32, draft: NoteDraft) -> Self {
+        let mut modal = Self::new(note_id);
+        modal.pending_draft = Some(draft);
+        modal.restore_on_load = true;
+        modal
+    }
+
+    fn restore(&mut self, draft: NoteDraft) {
+        self.draft.resume(&draft);
+        self.title = draft.title;
+        self.notebook = draft.notebook;
+        self.tags = draft.tags;
+        self.details = draft.details;
+    }
 }
 
 impl Modal for UpdateNoteModal {
@@ -35,9 +73,29 @@ impl Modal for UpdateNoteModal {
         if !self.loaded {
             match NoteService::get_note_by_id(self.note_id) {
                 Ok(note) => {
+                    self.tags = note.tags_text();
                     self.title = note.title;
-                    self.details = note.details;
+                    self.notebook = note.notebook;
+                    self.is_locked = note.is_locked;
+                    // Locked notes only hold ciphertext, the content comes from unlock_note
+                    if !note.is_locked {
+                        self.details = note.details;
+                    }
                     self.loaded = true;
+
+                    // Drafts are never kept for locked notes, they would hold the plain text
+                    if note.is_locked {
+                        self.pending_draft = None;
+                    } else if self.restore_on_load {
+                        if let Some(draft) = self.pending_draft.take() {
+                            self.restore(draft);
+                        }
+                    } else {
+                        match draft_service::find_draft(Some(self.note_id)) {
+                            Ok(draft) => self.pending_draft = draft,
+                            Err(e) => log_service::add_log_entry("ERROR", &format!("Error loading drafts: {}", e)),
+                        }
+                    }
                 }
                 Err(e) => {
                     ui.colored_label(Theme::DANGER_COLOR, format!("Error loading note: {}", e));
@@ -50,6 +108,64 @@ impl Modal for UpdateNoteModal {
         Theme::apply_body_style(ui);
         
         ui.add_space(Theme::SPACING_MEDIUM);
+
+        // Locked notes need the passphrase before they can be edited
+        if self.is_locked && !self.unlocked {
+            ui.label(egui::RichText::new("🔒 This note is locked, enter the passphrase to edit it").color(Theme::TEXT_SECONDARY));
+            ui.add_space(Theme::SPACING_SMALL);
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.passphrase)
+                    .password(true)
+                    .hint_text("Passphrase")
+                    .desired_width(f32::INFINITY)
+                    .margin(egui::vec2(8.0, 8.0))
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+            if let Some(error) = &self.unlock_error {
+                ui.add_space(Theme::SPACING_SMALL);
+                ui.colored_label(Theme::DANGER_COLOR, error);
+            }
+
+            ui.add_space(Theme::SPACING_LARGE);
+            ui.horizontal(|ui| {
+                if ui.add(Theme::primary_button("Unlock")).clicked() || submitted {
+                    match NoteService::unlock_note(self.note_id, &self.passphrase) {
+                        Ok(details) => {
+                            self.details = details;
+                            self.unlocked = true;
+                        }
+                        Err(e) => self.unlock_error = Some(e),
+                    }
+                }
+                if ui.add(Theme::button("Cancel")).clicked() {
+                    should_close = true;
+                }
+            });
+
+            if should_close {
+                self.passphrase.clear();
+            }
+            return should_close;
+        }
+
+        if let Some(draft) = &self.pending_draft {
+            match render_draft_banner(ui, draft) {
+                Some(DraftChoice::Restore) => {
+                    if let Some(draft) = self.pending_draft.take() {
+                        self.restore(draft);
+                    }
+                }
+                Some(DraftChoice::Discard) => {
+                    if let Some(draft) = self.pending_draft.take()
+                        && let Err(e) = draft_service::discard_draft(draft.id)
+                    {
+                        log_service::add_log_entry("ERROR", &format!("Error discarding draft: {}", e));
+                    }
+                }
+                None => {}
+            }
+        }
         
         ui.label(egui::RichText::new("Note Title").size(Theme::FONT_SIZE_BODY).strong());
         ui.add_space(Theme::SPACING_SMALL);
@@ -57,10 +173,36 @@ impl Modal for UpdateNoteModal {
             .hint_text("Enter a title for your note")
             .desired_width(f32::INFINITY)
             .margin(egui::vec2(8.0, 8.0));
-        ui.add(title_edit);
+        if ui.add(title_edit).changed() {
+            self.draft.mark_dirty();
+        }
         
         ui.add_space(Theme::SPACING_MEDIUM);
 
+        ui.label(egui::RichText::new("Notebook").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        let notebook_edit = egui::TextEdit::singleline(&mut self.notebook)
+            .hint_text("Notes")
+            .desired_width(f32::INFINITY)
+            .margin(egui::vec2(8.0, 8.0));
+        if ui.add(notebook_edit).changed() {
+            self.draft.mark_dirty();
+        }
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        ui.label(egui::RichText::new("Tags").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        let tags_edit = egui::TextEdit::singleline(&mut self.tags)
+            .hint_text("Comma separated, e.g. public, rust")
+            .desired_width(f32::INFINITY)
+            .margin(egui::vec2(8.0, 8.0));
+        if ui.add(tags_edit).changed() {
+            self.draft.mark_dirty();
+        }
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
         // Note Details
         ui.label(egui::RichText::new("Note Details").size(Theme::FONT_SIZE_BODY).strong());
         ui.add_space(Theme::SPACING_SMALL);
@@ -68,21 +210,30 @@ impl Modal for UpdateNoteModal {
             .id_salt("note_details_scroll")
             .max_height(ui.available_height() - 80.0) 
             .show(ui, |ui| {
-                ui.add(
+                let response = ui.add(
                     egui::TextEdit::multiline(&mut self.details)
                         .hint_text("Write your note here...")
                         .desired_width(f32::INFINITY)
                         .desired_rows(20)
                         .font(egui::TextStyle::Body)
                 );
+                if response.changed() {
+                    self.draft.mark_dirty();
+                }
             });
         
         ui.add_space(Theme::SPACING_LARGE);
         
         ui.horizontal(|ui| {
             if ui.add(Theme::primary_button("Update Note")).clicked() {
-                if let Err(e) = NoteService::update_note(self.note_id, &self.title, &self.details) {
-                    println!("Error updating note: {}", e);
+                let result = if self.is_locked {
+                    NoteService::update_locked_note(self.note_id, &self.title, &self.details, &self.notebook, &self.tags, &self.passphrase)
+                } else {
+                    NoteService::update_note(self.note_id, &self.title, &self.details, &self.notebook, &self.tags)
+                };
+                match result {
+                    Ok(_) => self.draft.discard(),
+                    Err(e) => println!("Error updating note: {}", e),
                 }
                 should_close = true;
             }
@@ -90,7 +241,31 @@ impl Modal for UpdateNoteModal {
                 should_close = true;
             }
         });
+
+        // Keep typed text as a draft in case the modal was closed by accident
+        if !self.is_locked {
+            if should_close {
+                self.draft.save(&self.title, &self.details, &self.notebook, &self.tags);
+            } else {
+                self.draft.autosave(&self.title, &self.details, &self.notebook, &self.tags);
+                if self.draft.is_dirty() {
+                    ui.ctx().request_repaint_after(draft_service::DRAFT_SAVE_INTERVAL);
+                }
+            }
+        }
+
+        // Don't keep the passphrase or decrypted content around once we are done
+        if should_close && self.is_locked {
+            self.passphrase.clear();
+            self.details.clear();
+        }
         
         should_close
     }
+
+    fn save_draft(&mut self) {
+        if !self.is_locked {
+            self.draft.save(&self.title, &self.details, &self.notebook, &self.tags);
+        }
+    }
 }
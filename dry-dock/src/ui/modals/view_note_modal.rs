@@ -1,14 +1,39 @@
 // src/ui/modals/view_note_modal.rs
 use eframe::egui;
 use pulldown_cmark::{Parser, Event, Tag, TagEnd, HeadingLevel};
+use std::time::{Duration, Instant};
 use crate::ui::modals::modal_trait::Modal;
 use crate::services::NoteService;
 use crate::ui::styles::Theme;
+use crate::ui::code_block::{code_block_language, render_code_block};
+
+/// How long a locked note stays readable before it is locked again
+const RELOCK_AFTER: Duration = Duration::from_secs(5 * 60);
+
+/// Average reading speed used for the reading time estimate
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Background of find matches, the current match stands out from the rest
+const FIND_MATCH_BG: egui::Color32 = egui::Color32::from_rgb(250, 204, 21);
+const FIND_CURRENT_BG: egui::Color32 = egui::Color32::from_rgb(251, 146, 60);
 
 pub struct ViewNoteModal {
     note_id: i32,
     title: String,
     details: String,
+    is_locked: bool,
+    passphrase: String,
+    unlocked_at: Option<Instant>,
+    unlock_error: Option<String>,
+    // Outline: heading to bring into view on the next frame
+    scroll_to_heading: Option<usize>,
+    // In-note find (Ctrl+F)
+    find_open: bool,
+    find_query: String,
+    focus_find: bool,
+    current_match: usize,
+    match_count: usize,
+    scroll_to_match: bool,
     loaded: bool,
 }
 
@@ -18,24 +43,211 @@ impl ViewNoteModal {
             note_id,
             title: String::new(),
             details: String::new(),
+            is_locked: false,
+            passphrase: String::new(),
+            unlocked_at: None,
+            unlock_error: None,
+            scroll_to_heading: None,
+            find_open: false,
+            find_query: String::new(),
+            focus_find: false,
+            current_match: 0,
+            match_count: 0,
+            scroll_to_match: false,
             loaded: false,
         }
     }
 
-    fn render_markdown(&self, ui: &mut egui::Ui, markdown: &str) {
+    /// Drop the decrypted content so the note has to be unlocked again
+    fn relock(&mut self) {
+        self.details.clear();
+        self.unlocked_at = None;
+    }
+
+    /// Passphrase prompt shown while a locked note is not unlocked
+    fn render_unlock_prompt(&mut self, ui: &mut egui::Ui) {
+        ui.vertical_centered(|ui| {
+            ui.add_space(Theme::SPACING_XL);
+            ui.label(egui::RichText::new("🔒").size(48.0));
+            ui.add_space(Theme::SPACING_SMALL);
+            ui.label(egui::RichText::new("This note is locked")
+                .size(Theme::FONT_SIZE_SUBHEADING)
+                .color(Theme::TEXT_PRIMARY));
+            ui.add_space(Theme::SPACING_MEDIUM);
+
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.passphrase)
+                    .password(true)
+                    .hint_text("Passphrase")
+                    .desired_width(300.0)
+                    .margin(egui::vec2(8.0, 8.0))
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+            ui.add_space(Theme::SPACING_SMALL);
+            if ui.add(Theme::primary_button("Unlock")).clicked() || submitted {
+                match NoteService::unlock_note(self.note_id, &self.passphrase) {
+                    Ok(details) => {
+                        self.details = details;
+                        self.unlocked_at = Some(Instant::now());
+                        self.unlock_error = None;
+                    }
+                    Err(e) => self.unlock_error = Some(e),
+                }
+                self.passphrase.clear();
+            }
+
+            if let Some(error) = &self.unlock_error {
+                ui.add_space(Theme::SPACING_SMALL);
+                ui.colored_label(Theme::DANGER_COLOR, error);
+            }
+        });
+    }
+
+    /// Table of contents built from the note headings, clicking one scrolls to it
+    fn render_outline(&mut self, ui: &mut egui::Ui, outline: &[(usize, usize, String)]) {
+        ui.label(egui::RichText::new("Contents")
+            .size(Theme::FONT_SIZE_BODY)
+            .strong()
+            .color(Theme::TEXT_PRIMARY));
+        ui.add_space(Theme::SPACING_SMALL);
+
+        egui::ScrollArea::vertical()
+            .id_salt("note_outline_scroll")
+            .show(ui, |ui| {
+                for (index, level, text) in outline {
+                    ui.horizontal(|ui| {
+                        ui.add_space((level - 1) as f32 * 12.0);
+                        let label = egui::RichText::new(text)
+                            .size(Theme::FONT_SIZE_SMALL)
+                            .color(if *level == 1 { Theme::TEXT_PRIMARY } else { Theme::TEXT_SECONDARY });
+                        if ui.add(egui::Label::new(label).truncate().sense(egui::Sense::click()))
+                            .on_hover_cursor(egui::CursorIcon::PointingHand)
+                            .clicked()
+                        {
+                            self.scroll_to_heading = Some(*index);
+                        }
+                    });
+                }
+            });
+    }
+
+    /// Find bar shown after Ctrl+F
+    fn render_find_bar(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Find:").color(Theme::TEXT_PRIMARY));
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.find_query)
+                    .hint_text("Find in note...")
+                    .desired_width(260.0)
+            );
+            if self.focus_find {
+                response.request_focus();
+                self.focus_find = false;
+            }
+            if response.changed() {
+                self.current_match = 0;
+                self.scroll_to_match = true;
+            }
+
+            // Enter jumps to the next match, Shift+Enter to the previous one
+            let enter = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            let shift = ui.input(|i| i.modifiers.shift);
+            if enter {
+                response.request_focus();
+            }
+
+            if self.find_query.is_empty() {
+                // Nothing to count yet
+            } else if self.match_count == 0 {
+                ui.label(egui::RichText::new("No matches").color(Theme::DANGER_COLOR));
+            } else {
+                ui.label(egui::RichText::new(format!("{} of {}", self.current_match + 1, self.match_count))
+                    .color(Theme::TEXT_SECONDARY));
+            }
+
+            if (ui.button("▲").on_hover_text("Previous match").clicked() || (enter && shift)) && self.match_count > 0 {
+                self.current_match = (self.current_match + self.match_count - 1) % self.match_count;
+                self.scroll_to_match = true;
+            }
+            if (ui.button("▼").on_hover_text("Next match").clicked() || (enter && !shift)) && self.match_count > 0 {
+                self.current_match = (self.current_match + 1) % self.match_count;
+                self.scroll_to_match = true;
+            }
+            if ui.button("✕").on_hover_text("Close find (Esc)").clicked() || ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                self.find_open = false;
+                self.find_query.clear();
+            }
+        });
+    }
+
+    /// Label that highlights find matches, returns the response so headings can be scrolled to
+    /// `match_index` counts matches across the whole note so the current one can be found
+    fn find_label(&self, ui: &mut egui::Ui, rich_text: egui::RichText, match_index: &mut usize) -> egui::Response {
+        let matches = find_matches(rich_text.text(), &self.find_query);
+        if matches.is_empty() {
+            return ui.label(rich_text);
+        }
+
+        // Lay the text out once to get its format, then split it into highlighted sections
+        let mut job = egui::text::LayoutJob::default();
+        rich_text.append_to(&mut job, ui.style(), egui::FontSelection::Default, egui::Align::Center);
+        let base = job.sections.first().map(|section| section.format.clone()).unwrap_or_default();
+        let mut sections = Vec::new();
+        let mut current_in_label = false;
+        let mut position = 0;
+
+        for (start, end) in matches {
+            if start > position {
+                sections.push(egui::text::LayoutSection { leading_space: 0.0, byte_range: position..start, format: base.clone() });
+            }
+
+            let is_current = *match_index == self.current_match;
+            current_in_label |= is_current;
+            let mut format = base.clone();
+            format.background = if is_current { FIND_CURRENT_BG } else { FIND_MATCH_BG };
+            format.color = Theme::BG_DARKER;
+            sections.push(egui::text::LayoutSection { leading_space: 0.0, byte_range: start..end, format });
+
+            *match_index += 1;
+            position = end;
+        }
+
+        if position < job.text.len() {
+            sections.push(egui::text::LayoutSection { leading_space: 0.0, byte_range: position..job.text.len(), format: base });
+        }
+        job.sections = sections;
+
+        let response = ui.label(job);
+        if current_in_label && self.scroll_to_match {
+            response.scroll_to_me(Some(egui::Align::Center));
+        }
+        response
+    }
+
+    /// Render the note markdown, returns how many find matches were highlighted
+    fn render_markdown(&self, ui: &mut egui::Ui, markdown: &str) -> usize {
         let parser = Parser::new(markdown);
         let mut in_heading = false;
         let mut heading_level = 1;
-        let mut in_code_block = false;
+        // (language, code) of the fenced block being collected, drawn at its end tag
+        let mut code_block: Option<(String, String)> = None;
+        let mut code_block_index = 0;
         let mut in_emphasis = false;
         let mut in_strong = false;
         let mut list_depth = 0;
         let mut in_list_item = false;
+        // Heading count so far, used to scroll to the heading picked in the outline
+        let mut heading_index = 0;
+        let mut heading_scrolled = false;
+        let mut match_index = 0;
 
         for event in parser {
             match event {
                 Event::Start(Tag::Heading { level, .. }) => {
                     in_heading = true;
+                    heading_index += 1;
+                    heading_scrolled = false;
                     heading_level = match level {
                         HeadingLevel::H1 => 1,
                         HeadingLevel::H2 => 2,
@@ -59,12 +271,15 @@ impl ViewNoteModal {
                         ui.add_space(Theme::SPACING_MEDIUM);
                     }
                 }
-                Event::Start(Tag::CodeBlock(_)) => {
-                    in_code_block = true;
+                Event::Start(Tag::CodeBlock(kind)) => {
+                    code_block = Some((code_block_language(&kind), String::new()));
                     ui.add_space(Theme::SPACING_SMALL);
                 }
                 Event::End(TagEnd::CodeBlock) => {
-                    in_code_block = false;
+                    if let Some((language, code)) = code_block.take() {
+                        render_code_block(ui, (self.note_id, code_block_index), &language, &code);
+                        code_block_index += 1;
+                    }
                     ui.add_space(Theme::SPACING_MEDIUM);
                 }
                 Event::Start(Tag::Emphasis) => {
@@ -99,6 +314,12 @@ impl ViewNoteModal {
                     ui.add_space(Theme::SPACING_SMALL);
                 }
                 Event::Text(text) => {
+                    // Code block text is collected and highlighted as a whole
+                    if let Some((_, code)) = code_block.as_mut() {
+                        code.push_str(&text);
+                        continue;
+                    }
+
                     let text_str = text.to_string();
                     
                     if in_list_item && !in_heading {
@@ -121,7 +342,7 @@ impl ViewNoteModal {
                                 rich_text = rich_text.italics();
                             }
                             
-                            ui.label(rich_text);
+                            self.find_label(ui, rich_text, &mut match_index);
                         });
                     } else {
                         // Regular text rendering
@@ -134,11 +355,6 @@ impl ViewNoteModal {
                                 _ => Theme::FONT_SIZE_BODY + 2.0,
                             };
                             rich_text = rich_text.size(size).strong().color(Theme::TEXT_PRIMARY);
-                        } else if in_code_block {
-                            rich_text = rich_text
-                                .family(egui::FontFamily::Monospace)
-                                .background_color(Theme::CARD_BG)
-                                .color(Theme::SUCCESS_COLOR);
                         } else {
                             rich_text = rich_text.size(Theme::FONT_SIZE_BODY).color(Theme::TEXT_SECONDARY);
                         }
@@ -150,15 +366,21 @@ impl ViewNoteModal {
                             rich_text = rich_text.italics();
                         }
                         
-                        ui.label(rich_text);
+                        let response = self.find_label(ui, rich_text, &mut match_index);
+                        if in_heading && !heading_scrolled && self.scroll_to_heading == Some(heading_index - 1) {
+                            response.scroll_to_me(Some(egui::Align::TOP));
+                            heading_scrolled = true;
+                        }
                     }
                 }
                 Event::Code(code) => {
-                    ui.label(
+                    self.find_label(
+                        ui,
                         egui::RichText::new(code.to_string())
                             .family(egui::FontFamily::Monospace)
                             .background_color(Theme::CARD_BG)
-                            .color(Theme::SUCCESS_COLOR)
+                            .color(Theme::SUCCESS_COLOR),
+                        &mut match_index,
                     );
                 }
                 Event::SoftBreak => {
@@ -177,6 +399,8 @@ impl ViewNoteModal {
                 _ => {}
             }
         }
+
+        match_index
     }
 }
 
@@ -193,7 +417,11 @@ impl Modal for ViewNoteModal {
             match NoteService::get_note_by_id(self.note_id) {
                 Ok(note) => {
                     self.title = note.title;
-                    self.details = note.details;
+                    self.is_locked = note.is_locked;
+                    // Locked notes only hold ciphertext, the content comes from unlock_note
+                    if !note.is_locked {
+                        self.details = note.details;
+                    }
                     self.loaded = true;
                 }
                 Err(e) => {
@@ -213,25 +441,186 @@ impl Modal for ViewNoteModal {
             .strong()
             .color(Theme::TEXT_PRIMARY));
         
+        // Re-lock once the timeout has passed
+        if let Some(unlocked_at) = self.unlocked_at {
+            let elapsed = unlocked_at.elapsed();
+            if elapsed >= RELOCK_AFTER {
+                self.relock();
+            } else {
+                let remaining = (RELOCK_AFTER - elapsed).as_secs();
+                ui.label(egui::RichText::new(format!("🔓 Unlocked, locks again in {}:{:02}", remaining / 60, remaining % 60))
+                    .size(Theme::FONT_SIZE_SMALL)
+                    .color(Theme::TEXT_MUTED));
+                ui.ctx().request_repaint_after(Duration::from_secs(1));
+            }
+        }
+
         ui.add_space(Theme::SPACING_MEDIUM);
         ui.separator();
         ui.add_space(Theme::SPACING_MEDIUM);
 
-        // Render markdown content in a scrollable area
-        egui::ScrollArea::vertical()
-            .max_height(ui.available_height() - 80.0)
-            .show(ui, |ui| {
-                self.render_markdown(ui, &self.details);
+        let content_visible = !self.is_locked || self.unlocked_at.is_some();
+
+        if content_visible {
+            // Ctrl+F (Cmd+F on macOS) opens the find bar
+            let find_shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::F);
+            if ui.input_mut(|i| i.consume_shortcut(&find_shortcut)) {
+                self.find_open = true;
+                self.focus_find = true;
+            }
+            if self.find_open {
+                self.render_find_bar(ui);
+                ui.add_space(Theme::SPACING_SMALL);
+            }
+        }
+
+        if !content_visible {
+            self.render_unlock_prompt(ui);
+        } else {
+            let outline = build_outline(&self.details);
+            let content_height = ui.available_height() - 100.0;
+
+            ui.horizontal_top(|ui| {
+                // Outline side panel, only when the note has headings
+                if !outline.is_empty() {
+                    ui.allocate_ui(egui::vec2(200.0, content_height), |ui| {
+                        ui.vertical(|ui| {
+                            ui.set_width(200.0);
+                            self.render_outline(ui, &outline);
+                        });
+                    });
+                    ui.separator();
+                }
+
+                // Render markdown content in a scrollable area
+                let mut match_count = 0;
+                ui.vertical(|ui| {
+                    egui::ScrollArea::vertical()
+                        .id_salt("note_content_scroll")
+                        .max_height(content_height)
+                        .show(ui, |ui| {
+                            match_count = self.render_markdown(ui, &self.details);
+                        });
+                });
+
+                // Scroll requests only apply to the frame they were made in
+                self.match_count = match_count;
+                if self.current_match >= match_count {
+                    self.current_match = 0;
+                }
+                self.scroll_to_heading = None;
+                self.scroll_to_match = false;
             });
+
+            // Stats footer
+            let stats = NoteStats::from_markdown(&self.details);
+            ui.add_space(Theme::SPACING_SMALL);
+            ui.separator();
+            ui.label(egui::RichText::new(format!(
+                "{} words  •  {} characters  •  {} lines  •  {} min read",
+                stats.words, stats.characters, stats.lines, stats.reading_minutes
+            ))
+            .size(Theme::FONT_SIZE_SMALL)
+            .color(Theme::TEXT_MUTED));
+        }
         
-        ui.add_space(Theme::SPACING_LARGE);
+        ui.add_space(Theme::SPACING_MEDIUM);
         
         ui.horizontal(|ui| {
             if ui.add(Theme::button("Close")).clicked() {
                 should_close = true;
             }
+            if self.unlocked_at.is_some() && ui.add(Theme::button("Lock Now")).clicked() {
+                self.relock();
+            }
         });
+
+        if should_close && self.is_locked {
+            self.relock();
+        }
         
         should_close
     }
 }
+
+/// Word, character and line counts shown in the footer
+struct NoteStats {
+    words: usize,
+    characters: usize,
+    lines: usize,
+    reading_minutes: usize,
+}
+
+impl NoteStats {
+    fn from_markdown(markdown: &str) -> Self {
+        let words = markdown.split_whitespace().count();
+        Self {
+            words,
+            characters: markdown.chars().count(),
+            lines: markdown.lines().count(),
+            // Round up so short notes still read as "1 min"
+            reading_minutes: words.div_ceil(WORDS_PER_MINUTE).max(1),
+        }
+    }
+}
+
+/// Headings as (index, level, text) in document order. The index counts every heading the way
+/// render_markdown does, empty ones included, even though those are left out of the outline.
+fn build_outline(markdown: &str) -> Vec<(usize, usize, String)> {
+    let mut outline = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+    let mut heading_index = 0;
+
+    for event in Parser::new(markdown) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                current = Some((level as usize, String::new()));
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, heading)) = current.as_mut() {
+                    heading.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, heading)) = current.take() {
+                    let heading = heading.trim().to_string();
+                    if !heading.is_empty() {
+                        outline.push((heading_index, level, heading));
+                    }
+                    heading_index += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    outline
+}
+
+/// Byte ranges of case-insensitive matches of `query` in `text`
+fn find_matches(text: &str, query: &str) -> Vec<(usize, usize)> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    // Lowercased chars along with the byte range of the original char they came from
+    let lowered: Vec<(usize, usize, char)> = text
+        .char_indices()
+        .flat_map(|(i, c)| c.to_lowercase().map(move |l| (i, i + c.len_utf8(), l)))
+        .collect();
+
+    let mut matches = Vec::new();
+    let mut i = 0;
+    while i + query.len() <= lowered.len() {
+        let window = &lowered[i..i + query.len()];
+        if window.iter().zip(&query).all(|(lowered, q)| lowered.2 == *q) {
+            matches.push((window[0].0, window[query.len() - 1].1));
+            i += query.len();
+        } else {
+            i += 1;
+        }
+    }
+
+    matches
+}
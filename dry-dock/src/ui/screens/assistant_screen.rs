@@ -4,6 +4,7 @@ use crate::ui::screens::screen_trait::Screen;
 use crate::models::{ChatMessage, MessageRole};
 use crate::services::{AssistantService, log_service};
 use crate::ui::styles::Theme;
+use crate::ui::code_block::render_text_with_code_blocks;
 use crate::ui::modals::ActiveModal;
 use std::sync::mpsc::{channel, Sender, Receiver};
 
@@ -158,8 +159,8 @@ impl AssistantScreen {
                         ui.label(egui::RichText::new("Type a message below to chat with the AI assistant").color(Theme::TEXT_SECONDARY));
                     });
                 } else {
-                    for message in &self.messages {
-                        self.render_message(ui, message);
+                    for (index, message) in self.messages.iter().enumerate() {
+                        self.render_message(ui, index, message);
                         ui.add_space(Theme::SPACING_MEDIUM);
                     }
                     
@@ -213,7 +214,7 @@ impl AssistantScreen {
         });
     }
 
-    fn render_message(&self, ui: &mut egui::Ui, message: &ChatMessage) {
+    fn render_message(&self, ui: &mut egui::Ui, index: usize, message: &ChatMessage) {
         let (icon, bg_color, align) = match message.role {
             MessageRole::User => ("👤", egui::Color32::from_rgb(33, 150, 243), egui::Align::Max),
             MessageRole::Assistant => ("🤖", egui::Color32::from_rgb(76, 175, 80), egui::Align::Min)
@@ -234,9 +235,8 @@ impl AssistantScreen {
                     
                     ui.add_space(Theme::SPACING_SMALL);
                     
-                    ui.label(egui::RichText::new(&message.content)
-                        .size(Theme::FONT_SIZE_BODY)
-                        .color(Theme::TEXT_PRIMARY));
+                    // Fenced code in replies gets the same highlighted blocks as notes
+                    render_text_with_code_blocks(ui, ("chat_message", index), &message.content, Theme::TEXT_PRIMARY);
                 });
         });
     }
@@ -1,14 +1,97 @@
 // src/ui/screens/bookmarks_screen.rs
 use eframe::egui::{self};
-use crate::services::bookmark_service;
-use crate::models::Bookmark;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
+use std::time::{Duration, Instant};
+use crate::services::{bookmark_metadata_service, bookmark_opener_service, bookmark_service, file_browser_service, git_status_service, link_check_service};
+use crate::models::{Bookmark, BookmarkFolder, BookmarkKind, BookmarkSort, FileEntry, GitStatus, LinkCheckReport};
 use crate::ui::modals::ActiveModal;
 use crate::ui::styles::Theme;
 use crate::services::log_service;
 
+/// How often the git status of folder bookmarks is read again while the screen is shown
+const GIT_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Entries shown per folder in the file tree, huge folders are cut off
+const MAX_TREE_ENTRIES: usize = 500;
+
+/// Which part of the folder tree the list shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum FolderView {
+    #[default]
+    All,
+    Unfiled,
+    Folder(i32),
+}
+
+/// Something being dragged onto a folder
+#[derive(Debug, Clone, Copy)]
+enum DragItem {
+    Bookmark(i32),
+    Folder(i32),
+}
+
+/// Quick actions on a bookmark that is a git working tree
+enum GitAction {
+    Terminal,
+    Editor,
+    CopyPath,
+}
+
+/// Something picked in a folder bookmark's file tree
+enum FileAction {
+    Preview(PathBuf),
+    Open(PathBuf),
+    /// Path and the bookmark folder to save it in
+    Bookmark(PathBuf, Option<i32>),
+}
+
+/// Folder and tag changes, applied after the frame is drawn
+enum BookmarkAction {
+    MoveBookmark(i32, Option<i32>),
+    MoveFolder(i32, Option<i32>),
+    CreateFolder(String, Option<i32>),
+    RenameFolder(i32, String),
+    DeleteFolder(i32),
+    RenameTag(String, String),
+    DeleteTag(String),
+}
+
 #[derive(Default)]
 pub struct BookmarksScreen {
     bookmarks: Vec<Bookmark>,
+    folders: Vec<BookmarkFolder>,
+    folder_paths: HashMap<i32, String>,
+    view: FolderView,
+    expanded: HashSet<i32>,
+    /// Only bookmarks carrying every one of these tags are listed
+    tag_filter: BTreeSet<String>,
+    new_folder_name: String,
+    renaming_folder: Option<(i32, String)>,
+    renaming_tag: Option<(String, String)>,
+    /// Only list bookmarks the link checker found broken
+    broken_only: bool,
+    sort: BookmarkSort,
+    /// Result of a link check started from this screen, while it runs
+    link_check_rx: Option<Receiver<Result<LinkCheckReport, String>>>,
+    /// Outcome of the last link check or card action
+    status: Option<Result<String, String>>,
+    /// Favicon textures by bookmark location, None when there is no cached icon
+    favicons: HashMap<String, Option<egui::TextureHandle>>,
+    /// Kinds by bookmark id, paths are checked on disk for File or Folder the first time they're drawn
+    kinds: HashMap<i32, BookmarkKind>,
+    /// Last git status of folder bookmarks that are working trees, by bookmark id
+    git_statuses: HashMap<i32, GitStatus>,
+    /// Statuses being read on a background thread
+    git_rx: Option<Receiver<HashMap<i32, GitStatus>>>,
+    /// When the last read started, None to read again as soon as possible
+    git_refreshed_at: Option<Instant>,
+    /// Folder bookmarks expanded into a file tree
+    browsing: HashSet<i32>,
+    /// Contents of the folders expanded in the file trees, read on first expand
+    listings: HashMap<PathBuf, Result<Vec<FileEntry>, String>>,
+    error: Option<String>,
     loaded: bool,
 }
 
@@ -24,7 +107,7 @@ impl BookmarksScreen {
 
     pub fn render(&mut self, ui: &mut egui::Ui, modal_opener: &mut dyn FnMut(ActiveModal)) {
         Theme::apply_body_style(ui);
-        
+
         // Header
         ui.add_space(Theme::SPACING_MEDIUM);
         ui.heading(egui::RichText::new(self.title()).strong());
@@ -37,25 +120,79 @@ impl BookmarksScreen {
                 modal_opener(ActiveModal::AddBookmark);
             }
 
+            // Import / Export Button
+            if ui.add(Theme::button("Import / Export")).clicked() {
+                modal_opener(ActiveModal::ImportBookmarks);
+            }
+
+            // Find Duplicates Button
+            if ui.add(Theme::button("Find Duplicates")).clicked() {
+                modal_opener(ActiveModal::BookmarkDuplicates);
+            }
+
+            // Check Links Button
+            if self.link_check_rx.is_some() {
+                ui.spinner();
+                ui.label(egui::RichText::new("Checking links...").color(Theme::TEXT_SECONDARY));
+            } else if ui.add(Theme::button("Check Links")).clicked() {
+                self.start_link_check();
+            }
+
+            // Broken links filter
+            let broken = self.bookmarks.iter().filter(|bookmark| bookmark.link_check.as_ref().is_some_and(|check| check.is_broken())).count();
+            ui.toggle_value(&mut self.broken_only, format!("Broken only ({})", broken));
+
+            ui.label(egui::RichText::new("Sort:").color(Theme::TEXT_PRIMARY));
+            egui::ComboBox::from_id_salt("bookmarks_sort")
+                .selected_text(self.sort.label())
+                .show_ui(ui, |ui| {
+                    for sort in BookmarkSort::ALL {
+                        if ui.selectable_value(&mut self.sort, sort, sort.label()).changed() {
+                            bookmark_service::sort_bookmarks(&mut self.bookmarks, self.sort);
+                        }
+                    }
+                });
+
             // Create Refresh Button
             if ui.add(Theme::button("Refresh")).clicked() {
                 self.loaded = false;
             }
         });
 
+        self.poll_link_check(ui.ctx());
+        self.poll_git_statuses(ui.ctx());
+        if let Some(status) = &self.status {
+            ui.add_space(Theme::SPACING_SMALL);
+            match status {
+                Ok(message) => ui.colored_label(Theme::SUCCESS_COLOR, message),
+                Err(error) => ui.colored_label(Theme::DANGER_COLOR, error),
+            };
+        }
+
         ui.add_space(Theme::SPACING_MEDIUM);
         ui.separator();
         ui.add_space(Theme::SPACING_MEDIUM);
-        
-        // Load bookmarks only when not yet loaded
+
+        // Load bookmarks and folders only when not yet loaded
         if !self.loaded {
-            match bookmark_service::fetch_all_bookmarks() {
-                Ok(bookmarks) => {
-                    self.bookmarks = bookmarks.into_iter()
-                        .map(|(id, name, path, created_at)| {
-                            Bookmark::new(id, name, path, created_at)
-                        })
-                        .collect();
+            match bookmark_service::fetch_all_bookmarks().and_then(|bookmarks| Ok((bookmarks, bookmark_service::get_folders()?))) {
+                Ok((mut bookmarks, folders)) => {
+                    bookmark_service::sort_bookmarks(&mut bookmarks, self.sort);
+                    self.bookmarks = bookmarks;
+                    self.favicons.clear();
+                    self.kinds.clear();
+                    self.git_refreshed_at = None;
+                    self.listings.clear();
+                    self.folder_paths = bookmark_service::folder_paths(&folders).into_iter().collect();
+                    self.folders = folders;
+                    // Forget filters pointing at folders or tags that are gone
+                    if let FolderView::Folder(id) = self.view
+                        && !self.folder_paths.contains_key(&id)
+                    {
+                        self.view = FolderView::All;
+                    }
+                    let tags = bookmark_service::count_tags(&self.bookmarks);
+                    self.tag_filter.retain(|tag| tags.iter().any(|(existing, _)| existing == tag));
                     self.loaded = true;
                 }
                 Err(e) => {
@@ -66,51 +203,576 @@ impl BookmarksScreen {
         }
 
         // Show empty state
-        if self.bookmarks.is_empty() {
+        if self.bookmarks.is_empty() && self.folders.is_empty() {
             ui.vertical_centered(|ui| {
                 ui.add_space(Theme::SPACING_XL);
                 ui.label(egui::RichText::new("Bookmarks Manager").size(48.0));
                 ui.add_space(Theme::SPACING_SMALL);
                 ui.label(egui::RichText::new("No bookmarks yet").size(Theme::FONT_SIZE_SUBHEADING).color(Theme::TEXT_PRIMARY));
                 ui.add_space(Theme::SPACING_SMALL);
-                ui.label(egui::RichText::new("Click 'Add Bookmark' to create your first bookmark").color(Theme::TEXT_SECONDARY));
+                ui.label(egui::RichText::new("Click 'Add Bookmark' to create your first bookmark, or 'Import / Export' to bring in your browser's").color(Theme::TEXT_SECONDARY));
+            });
+            return;
+        }
+
+        self.refresh_git_statuses(ui.ctx());
+
+        let mut actions: Vec<BookmarkAction> = Vec::new();
+
+        // Folder tree and tags on the left, bookmarks on the right
+        egui::SidePanel::left("bookmark_folders")
+            .resizable(true)
+            .default_width(240.0)
+            .frame(egui::Frame::NONE)
+            .show_inside(ui, |ui| {
+                egui::ScrollArea::vertical().id_salt("bookmark_folders_scroll").show(ui, |ui| {
+                    self.render_folders(ui, &mut actions);
+                    ui.add_space(Theme::SPACING_LARGE);
+                    self.render_tags(ui, &mut actions);
+                    ui.add_space(Theme::SPACING_LARGE);
+                    self.render_recently_opened(ui);
+                });
+            });
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::NONE.inner_margin(egui::Margin { left: 12, ..Default::default() }))
+            .show_inside(ui, |ui| {
+                self.render_most_used(ui);
+                self.render_bookmarks(ui, modal_opener);
+            });
+
+        if !actions.is_empty() {
+            self.apply_actions(actions);
+        }
+    }
+
+    /// Check every bookmark on a background thread, results arrive through link_check_rx
+    fn start_link_check(&mut self) {
+        let (tx, rx) = channel();
+        self.link_check_rx = Some(rx);
+        self.status = None;
+
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().unwrap();
+            let _ = tx.send(runtime.block_on(link_check_service::check_all_bookmarks()));
+        });
+    }
+
+    /// Read the git status of folder bookmarks on a background thread when the last read is stale
+    fn refresh_git_statuses(&mut self, ctx: &egui::Context) {
+        if self.git_rx.is_some() || self.git_refreshed_at.is_some_and(|at| at.elapsed() < GIT_REFRESH_INTERVAL) {
+            return;
+        }
+
+        let candidates = git_status_service::candidates(&self.bookmarks);
+        self.git_refreshed_at = Some(Instant::now());
+        if candidates.is_empty() {
+            self.git_statuses.clear();
+            return;
+        }
+
+        let (tx, rx) = channel();
+        self.git_rx = Some(rx);
+        std::thread::spawn(move || {
+            let _ = tx.send(git_status_service::read_statuses(&candidates));
+        });
+        // Come back when it is time for the next read
+        ctx.request_repaint_after(GIT_REFRESH_INTERVAL);
+    }
+
+    fn poll_git_statuses(&mut self, ctx: &egui::Context) {
+        let Some(rx) = &self.git_rx else { return };
+
+        match rx.try_recv() {
+            Ok(statuses) => {
+                self.git_statuses = statuses;
+                self.git_rx = None;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => ctx.request_repaint_after(Duration::from_millis(250)),
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => self.git_rx = None,
+        }
+    }
+
+    fn poll_link_check(&mut self, ctx: &egui::Context) {
+        let Some(rx) = &self.link_check_rx else { return };
+
+        match rx.try_recv() {
+            Ok(result) => {
+                self.status = Some(match result {
+                    Ok(report) => Ok(format!(
+                        "Checked {} bookmark(s): {} broken, {} redirected",
+                        report.checked, report.broken, report.redirected
+                    )),
+                    Err(e) => {
+                        log_service::add_log_entry("ERROR", &format!("Bookmark link check failed: {}", e));
+                        Err(e)
+                    }
+                });
+                self.link_check_rx = None;
+                self.loaded = false;
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => ctx.request_repaint_after(std::time::Duration::from_millis(250)),
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => self.link_check_rx = None,
+        }
+    }
+
+    fn render_folders(&mut self, ui: &mut egui::Ui, actions: &mut Vec<BookmarkAction>) {
+        ui.label(egui::RichText::new("Folders").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.spacing_mut().item_spacing.y = 2.0;
+
+        // Dropping onto "All" or "Unfiled" takes things out of their folder
+        let total = self.bookmarks.len();
+        let unfiled = self.bookmarks.iter().filter(|bookmark| bookmark.folder_id.is_none()).count();
+        for (view, label) in [(FolderView::All, format!("All bookmarks ({})", total)), (FolderView::Unfiled, format!("Unfiled ({})", unfiled))] {
+            let response = ui.selectable_label(self.view == view, label);
+            if response.clicked() {
+                self.view = view;
+            }
+            Self::handle_drop(ui, &response, None, actions);
+        }
+
+        // Children of every folder, the tree is drawn from the top level down
+        let mut children: HashMap<Option<i32>, Vec<BookmarkFolder>> = HashMap::new();
+        for folder in &self.folders {
+            children.entry(folder.parent_id).or_default().push(folder.clone());
+        }
+        let mut counts: HashMap<i32, usize> = HashMap::new();
+        for folder_id in self.bookmarks.iter().filter_map(|bookmark| bookmark.folder_id) {
+            *counts.entry(folder_id).or_default() += 1;
+        }
+        self.render_folder_level(ui, &children, &counts, None, 0, actions);
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        // New folder goes inside the selected folder
+        let parent_id = match self.view {
+            FolderView::Folder(id) => Some(id),
+            _ => None,
+        };
+        let hint = match parent_id.and_then(|id| self.folder_paths.get(&id)) {
+            Some(path) => format!("New folder in {}", path),
+            None => "New folder".to_string(),
+        };
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.new_folder_name)
+                    .hint_text(hint)
+                    .desired_width(ui.available_width() - 50.0)
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if (ui.button("Add").clicked() || submitted) && !self.new_folder_name.trim().is_empty() {
+                actions.push(BookmarkAction::CreateFolder(std::mem::take(&mut self.new_folder_name), parent_id));
+            }
+        });
+        ui.label(egui::RichText::new("Drag bookmarks (⠿) and folders onto a folder to move them. Right-click a folder for more.")
+            .size(Theme::FONT_SIZE_SMALL)
+            .color(Theme::TEXT_MUTED));
+
+        if let Some(error) = &self.error {
+            ui.colored_label(Theme::DANGER_COLOR, error);
+        }
+    }
+
+    fn render_folder_level(
+        &mut self,
+        ui: &mut egui::Ui,
+        children: &HashMap<Option<i32>, Vec<BookmarkFolder>>,
+        counts: &HashMap<i32, usize>,
+        parent_id: Option<i32>,
+        depth: usize,
+        actions: &mut Vec<BookmarkAction>,
+    ) {
+        let Some(folders) = children.get(&parent_id) else { return };
+
+        for folder in folders {
+            let has_children = children.contains_key(&Some(folder.id));
+            let expanded = self.expanded.contains(&folder.id);
+
+            ui.horizontal(|ui| {
+                ui.add_space(depth as f32 * 14.0);
+                if has_children {
+                    if ui.add(egui::Button::new(if expanded { "▾" } else { "▸" }).frame(false)).clicked() {
+                        if expanded {
+                            self.expanded.remove(&folder.id);
+                        } else {
+                            self.expanded.insert(folder.id);
+                        }
+                    }
+                } else {
+                    ui.add_space(14.0);
+                }
+
+                // Inline rename
+                if let Some((id, name)) = &mut self.renaming_folder
+                    && *id == folder.id
+                {
+                    let response = ui.add(egui::TextEdit::singleline(name).desired_width(140.0));
+                    response.request_focus();
+                    if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                        self.renaming_folder = None;
+                    } else if response.lost_focus() {
+                        actions.push(BookmarkAction::RenameFolder(*id, name.clone()));
+                        self.renaming_folder = None;
+                    }
+                    return;
+                }
+
+                let selected = self.view == FolderView::Folder(folder.id);
+                let label = format!("📁 {} ({})", folder.name, counts.get(&folder.id).copied().unwrap_or(0));
+                let drag = ui.dnd_drag_source(
+                    egui::Id::new(("bookmark_folder_drag", folder.id)),
+                    DragItem::Folder(folder.id),
+                    |ui| ui.selectable_label(selected, label),
+                );
+                let response = drag.inner;
+                if response.clicked() {
+                    self.view = FolderView::Folder(folder.id);
+                }
+                Self::handle_drop(ui, &drag.response, Some(folder.id), actions);
+
+                response.context_menu(|ui| {
+                    if ui.button("New subfolder here").clicked() {
+                        self.view = FolderView::Folder(folder.id);
+                        self.expanded.insert(folder.id);
+                        ui.close();
+                    }
+                    if ui.button("Rename").clicked() {
+                        self.renaming_folder = Some((folder.id, folder.name.clone()));
+                        ui.close();
+                    }
+                    if folder.parent_id.is_some() && ui.button("Move to top level").clicked() {
+                        actions.push(BookmarkAction::MoveFolder(folder.id, None));
+                        ui.close();
+                    }
+                    if ui.button(egui::RichText::new("Delete (keeps its bookmarks)").color(Theme::DANGER_COLOR)).clicked() {
+                        actions.push(BookmarkAction::DeleteFolder(folder.id));
+                        ui.close();
+                    }
+                });
+            });
+
+            if has_children && expanded {
+                self.render_folder_level(ui, children, counts, Some(folder.id), depth + 1, actions);
+            }
+        }
+    }
+
+    /// Highlight a drop target while something hovers it and queue the move when it is released
+    fn handle_drop(ui: &egui::Ui, response: &egui::Response, folder_id: Option<i32>, actions: &mut Vec<BookmarkAction>) {
+        if response.dnd_hover_payload::<DragItem>().is_some() {
+            ui.painter().rect_stroke(response.rect, 4.0, egui::Stroke::new(1.5, Theme::PRIMARY_COLOR), egui::StrokeKind::Outside);
+        }
+
+        if let Some(item) = response.dnd_release_payload::<DragItem>() {
+            match *item {
+                DragItem::Bookmark(id) => actions.push(BookmarkAction::MoveBookmark(id, folder_id)),
+                DragItem::Folder(id) if Some(id) != folder_id => actions.push(BookmarkAction::MoveFolder(id, folder_id)),
+                DragItem::Folder(_) => {}
+            }
+        }
+    }
+
+    fn render_tags(&mut self, ui: &mut egui::Ui, actions: &mut Vec<BookmarkAction>) {
+        let tags = bookmark_service::count_tags(&self.bookmarks);
+
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new("Tags").size(Theme::FONT_SIZE_BODY).strong());
+            if !self.tag_filter.is_empty() && ui.small_button("Clear").clicked() {
+                self.tag_filter.clear();
+            }
+        });
+        ui.add_space(Theme::SPACING_SMALL);
+
+        if tags.is_empty() {
+            ui.label(egui::RichText::new("No tags yet").size(Theme::FONT_SIZE_SMALL).color(Theme::TEXT_MUTED));
+            return;
+        }
+
+        ui.horizontal_wrapped(|ui| {
+            for (tag, count) in tags {
+                if let Some((old, name)) = &mut self.renaming_tag
+                    && *old == tag
+                {
+                    let response = ui.add(egui::TextEdit::singleline(name).desired_width(100.0));
+                    response.request_focus();
+                    if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                        self.renaming_tag = None;
+                    } else if response.lost_focus() {
+                        actions.push(BookmarkAction::RenameTag(old.clone(), name.clone()));
+                        self.renaming_tag = None;
+                    }
+                    continue;
+                }
+
+                let selected = self.tag_filter.contains(&tag);
+                let response = ui.selectable_label(selected, format!("#{} ({})", tag, count));
+                if response.clicked() {
+                    if selected {
+                        self.tag_filter.remove(&tag);
+                    } else {
+                        self.tag_filter.insert(tag.clone());
+                    }
+                }
+                response.context_menu(|ui| {
+                    if ui.button("Rename").clicked() {
+                        self.renaming_tag = Some((tag.clone(), tag.clone()));
+                        ui.close();
+                    }
+                    if ui.button(egui::RichText::new("Remove from all bookmarks").color(Theme::DANGER_COLOR)).clicked() {
+                        actions.push(BookmarkAction::DeleteTag(tag.clone()));
+                        ui.close();
+                    }
+                });
+            }
+        });
+    }
+
+    /// Last opened bookmarks, click to open again
+    fn render_recently_opened(&mut self, ui: &mut egui::Ui) {
+        let mut recent: Vec<&Bookmark> = self.bookmarks.iter().filter(|bookmark| bookmark.last_opened_at.is_some()).collect();
+        if recent.is_empty() {
+            return;
+        }
+        recent.sort_by_key(|bookmark| std::cmp::Reverse(bookmark.last_opened_at));
+
+        ui.label(egui::RichText::new("Recently opened").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+
+        let mut id_to_open = None;
+        for bookmark in recent.into_iter().take(8) {
+            ui.horizontal(|ui| {
+                if ui.link(&bookmark.name).on_hover_text(&bookmark.path).clicked() {
+                    id_to_open = Some(bookmark.id);
+                }
+                ui.label(egui::RichText::new(Self::format_ago(bookmark.last_opened_at.unwrap_or_default()))
+                    .size(Theme::FONT_SIZE_SMALL)
+                    .color(Theme::TEXT_MUTED));
+            });
+        }
+
+        if let Some(id) = id_to_open {
+            self.open_bookmark(id);
+        }
+    }
+
+    /// Quick access to the bookmarks with the highest frecency, above the full list
+    fn render_most_used(&mut self, ui: &mut egui::Ui) {
+        if self.view != FolderView::All || !self.tag_filter.is_empty() || self.broken_only {
+            return;
+        }
+        let mut most_used: Vec<&Bookmark> = self.bookmarks.iter().filter(|bookmark| bookmark.frecency > 0.0).collect();
+        if most_used.is_empty() {
+            return;
+        }
+        most_used.sort_by(|a, b| b.frecency.total_cmp(&a.frecency));
+
+        let mut id_to_open = None;
+        ui.label(egui::RichText::new("Most used").size(Theme::FONT_SIZE_BODY).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+        ui.horizontal_wrapped(|ui| {
+            for bookmark in most_used.into_iter().take(6) {
+                let kind = *self.kinds
+                    .entry(bookmark.id)
+                    .or_insert_with(|| bookmark_opener_service::resolve_kind(bookmark.kind, &bookmark.path));
+                let text = format!("{} {}", kind.icon(), bookmark.name);
+                if ui.add(Theme::button(&text))
+                    .on_hover_text(format!("{}\nOpened {} time(s)", bookmark.path, bookmark.open_count))
+                    .clicked()
+                {
+                    id_to_open = Some(bookmark.id);
+                }
+            }
+        });
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        if let Some(id) = id_to_open {
+            self.open_bookmark(id);
+        }
+    }
+
+    /// Open a bookmark and reload, so its count and the usage lists are up to date
+    fn open_bookmark(&mut self, id: i32) {
+        if let Some(bookmark) = self.bookmarks.iter().find(|bookmark| bookmark.id == id) {
+            bookmark_service::open_bookmark_path(bookmark.id, &bookmark.path);
+            self.loaded = false;
+        }
+    }
+
+    /// "5m ago", "3h ago", "2d ago", or the date for older opens
+    fn format_ago(timestamp: i64) -> String {
+        let seconds = chrono::Utc::now().timestamp() - timestamp;
+        match seconds {
+            ..60 => "just now".to_string(),
+            60..3600 => format!("{}m ago", seconds / 60),
+            3600..86400 => format!("{}h ago", seconds / 3600),
+            86400..604800 => format!("{}d ago", seconds / 86400),
+            _ => chrono::DateTime::from_timestamp(timestamp, 0)
+                .map(|dt| dt.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn render_bookmarks(&mut self, ui: &mut egui::Ui, modal_opener: &mut dyn FnMut(ActiveModal)) {
+        let visible: Vec<&Bookmark> = self.bookmarks
+            .iter()
+            .filter(|bookmark| match self.view {
+                FolderView::All => true,
+                FolderView::Unfiled => bookmark.folder_id.is_none(),
+                FolderView::Folder(id) => bookmark.folder_id == Some(id),
+            })
+            .filter(|bookmark| self.tag_filter.iter().all(|tag| bookmark.tags.contains(tag)))
+            .filter(|bookmark| !self.broken_only || bookmark.link_check.as_ref().is_some_and(|check| check.is_broken()))
+            .collect();
+
+        if visible.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(Theme::SPACING_XL);
+                ui.label(egui::RichText::new("No bookmarks here").size(Theme::FONT_SIZE_SUBHEADING).color(Theme::TEXT_PRIMARY));
+                ui.add_space(Theme::SPACING_SMALL);
+                ui.label(egui::RichText::new("Pick another folder or clear the filters").color(Theme::TEXT_SECONDARY));
             });
             return;
         }
 
         // Track bookmark to delete
         let mut id_to_delete: Option<i32> = None;
+        // Track bookmark to point at its redirect target
+        let mut id_to_redirect: Option<i32> = None;
+        // Track bookmark to open, opening is counted and reloads the list
+        let mut id_to_open: Option<i32> = None;
+        // Track git quick action and the bookmark location it is for
+        let mut git_action: Option<(GitAction, String)> = None;
+        // Track folder bookmark to expand or collapse into its file tree
+        let mut id_to_browse: Option<i32> = None;
+        // Track file tree pick
+        let mut file_action: Option<FileAction> = None;
 
         // Display bookmarks in cards
         egui::ScrollArea::vertical()
+            .id_salt("bookmarks_scroll")
             .show(ui, |ui| {
-                for bookmark in &self.bookmarks {
+                for bookmark in visible {
+                    let kind = *self.kinds
+                        .entry(bookmark.id)
+                        .or_insert_with(|| bookmark_opener_service::resolve_kind(bookmark.kind, &bookmark.path));
                     Theme::card_frame().show(ui, |ui| {
                         ui.horizontal(|ui| {
+                            // Drag handle, drop it on a folder to move the bookmark
+                            ui.dnd_drag_source(
+                                egui::Id::new(("bookmark_drag", bookmark.id)),
+                                DragItem::Bookmark(bookmark.id),
+                                |ui| ui.label(egui::RichText::new("⠿").size(Theme::FONT_SIZE_SUBHEADING).color(Theme::TEXT_MUTED)),
+                            )
+                            .response
+                            .on_hover_text("Drag onto a folder");
+
                             ui.vertical(|ui| {
                                 ui.set_min_width(ui.available_width() - 280.0);
-                                ui.label(egui::RichText::new(&bookmark.name)
-                                    .size(Theme::FONT_SIZE_SUBHEADING)
-                                    .strong()
-                                    .color(Theme::TEXT_PRIMARY));
+                                ui.horizontal(|ui| {
+                                    let favicon = self.favicons
+                                        .entry(bookmark.path.clone())
+                                        .or_insert_with(|| Self::load_favicon(ui.ctx(), &bookmark.path));
+                                    match favicon {
+                                        Some(texture) => ui.image((texture.id(), egui::vec2(16.0, 16.0))),
+                                        None => ui.label(egui::RichText::new(kind.icon()).size(Theme::FONT_SIZE_BODY)),
+                                    }
+                                    .on_hover_text(kind.label());
+                                    ui.label(egui::RichText::new(&bookmark.name)
+                                        .size(Theme::FONT_SIZE_SUBHEADING)
+                                        .strong()
+                                        .color(Theme::TEXT_PRIMARY));
+                                });
+                                if !bookmark.description.is_empty() {
+                                    ui.add_space(Theme::SPACING_SMALL);
+                                    ui.label(egui::RichText::new(&bookmark.description).color(Theme::TEXT_SECONDARY));
+                                }
                                 ui.add_space(Theme::SPACING_SMALL);
-                                ui.label(egui::RichText::new(format!("Path: {}", &bookmark.path))
+                                ui.label(egui::RichText::new(format!("{}: {}", kind.label(), bookmark.display))
                                     .size(Theme::FONT_SIZE_SMALL)
                                     .color(Theme::TEXT_SECONDARY));
+                                if let Some(status) = self.git_statuses.get(&bookmark.id) {
+                                    ui.add_space(Theme::SPACING_SMALL);
+                                    ui.horizontal_wrapped(|ui| {
+                                        Self::render_git_status(ui, status);
+                                        for (action, label) in [(GitAction::Terminal, "Terminal"), (GitAction::Editor, "Editor"), (GitAction::CopyPath, "Copy path")] {
+                                            if ui.small_button(label).clicked() {
+                                                git_action = Some((action, bookmark.path.clone()));
+                                            }
+                                        }
+                                    });
+                                }
                                 ui.add_space(Theme::SPACING_SMALL);
-                                ui.label(egui::RichText::new(format!("Created: {}", &bookmark.created_at))
-                                    .size(Theme::FONT_SIZE_SMALL)
-                                    .color(Theme::TEXT_MUTED));
+                                ui.horizontal_wrapped(|ui| {
+                                    ui.label(egui::RichText::new(format!("Created: {}", &bookmark.created_at))
+                                        .size(Theme::FONT_SIZE_SMALL)
+                                        .color(Theme::TEXT_MUTED));
+                                    if self.view == FolderView::All
+                                        && let Some(path) = bookmark.folder_id.and_then(|id| self.folder_paths.get(&id))
+                                    {
+                                        ui.label(egui::RichText::new(format!("| 📁 {}", path))
+                                            .size(Theme::FONT_SIZE_SMALL)
+                                            .color(Theme::TEXT_MUTED));
+                                    }
+                                    for tag in &bookmark.tags {
+                                        ui.label(egui::RichText::new(format!("#{}", tag))
+                                            .size(Theme::FONT_SIZE_SMALL)
+                                            .color(Theme::PRIMARY_COLOR));
+                                    }
+                                    Self::render_health_badge(ui, bookmark);
+                                    if let Some(last_opened_at) = bookmark.last_opened_at {
+                                        ui.label(egui::RichText::new(format!(
+                                            "| Opened {} time(s), last {}",
+                                            bookmark.open_count,
+                                            Self::format_ago(last_opened_at)
+                                        ))
+                                        .size(Theme::FONT_SIZE_SMALL)
+                                        .color(Theme::TEXT_MUTED));
+                                    }
+                                });
+
+                                // Offer to follow a permanent move
+                                if let Some(check) = &bookmark.link_check
+                                    && !check.is_broken()
+                                    && let Some(redirect_url) = &check.redirect_url
+                                {
+                                    ui.horizontal_wrapped(|ui| {
+                                        ui.label(egui::RichText::new(format!("↪ Now at {}", redirect_url))
+                                            .size(Theme::FONT_SIZE_SMALL)
+                                            .color(Theme::WARNING_COLOR));
+                                        if ui.small_button("Update to new address").clicked() {
+                                            id_to_redirect = Some(bookmark.id);
+                                        }
+                                    });
+                                }
                             });
-                            
+
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                 // Delete bookmark button
                                 if ui.add(Theme::danger_button("Delete")).clicked() {
                                     id_to_delete = Some(bookmark.id);
                                 }
-                                
+
+                                ui.add_space(Theme::SPACING_SMALL);
+
+                                // Offline copies of web pages
+                                if bookmark.kind == BookmarkKind::Url
+                                    && ui.add(Theme::button("Snapshots")).clicked()
+                                {
+                                    modal_opener(ActiveModal::BookmarkSnapshots(bookmark.id));
+                                }
+
                                 ui.add_space(Theme::SPACING_SMALL);
 
+                                // File tree of folders
+                                if kind == BookmarkKind::Folder {
+                                    let label = if self.browsing.contains(&bookmark.id) { "Hide Files" } else { "Files" };
+                                    if ui.add(Theme::button(label)).clicked() {
+                                        id_to_browse = Some(bookmark.id);
+                                    }
+                                    ui.add_space(Theme::SPACING_SMALL);
+                                }
+
                                 // Update bookmark button
                                 if ui.add(Theme::primary_button("Update")).clicked() {
                                     modal_opener(ActiveModal::UpdateBookmark(bookmark.id));
@@ -120,14 +782,90 @@ impl BookmarksScreen {
 
                                 // Open bookmark button
                                 if ui.add(Theme::success_button("Open")).clicked() {
-                                    bookmark_service::open_bookmark_path(&bookmark.path);
+                                    id_to_open = Some(bookmark.id);
                                 }
                             });
                         });
+
+                        if self.browsing.contains(&bookmark.id) {
+                            ui.add_space(Theme::SPACING_SMALL);
+                            ui.separator();
+                            ui.label(egui::RichText::new("Click a file to preview it, right-click a file or folder to open it or bookmark it")
+                                .size(Theme::FONT_SIZE_SMALL)
+                                .color(Theme::TEXT_MUTED));
+                            ui.add_space(Theme::SPACING_SMALL);
+                            egui::ScrollArea::vertical()
+                                .id_salt(("file_tree", bookmark.id))
+                                .max_height(320.0)
+                                .show(ui, |ui| {
+                                    let root = bookmark_opener_service::expand_home(&bookmark.path);
+                                    Self::render_file_tree(ui, &mut self.listings, &root, bookmark.folder_id, &mut file_action);
+                                });
+                        }
                     });
                 }
             });
 
+        // Open bookmark after iteration
+        if let Some(id) = id_to_open {
+            self.open_bookmark(id);
+        }
+
+        // Expand or collapse file tree after iteration
+        if let Some(id) = id_to_browse
+            && !self.browsing.remove(&id)
+        {
+            self.browsing.insert(id);
+        }
+
+        // Run file tree pick after iteration
+        if let Some(action) = file_action {
+            let result = match action {
+                FileAction::Preview(path) => {
+                    modal_opener(ActiveModal::FilePreview(path));
+                    Ok(())
+                }
+                FileAction::Open(path) => file_browser_service::open_path(&path),
+                FileAction::Bookmark(path, folder_id) => file_browser_service::bookmark_path(&path, folder_id).map(|_| {
+                    self.status = Some(Ok(format!("Bookmarked {}", path.display())));
+                    self.loaded = false;
+                }),
+            };
+            if let Err(e) = result {
+                log_service::add_log_entry("ERROR", &format!("Error in file browser: {}", e));
+                self.status = Some(Err(e));
+            }
+        }
+
+        // Run git quick action after iteration
+        if let Some((action, location)) = git_action {
+            let result = match action {
+                GitAction::Terminal => bookmark_opener_service::open_terminal_at(&location),
+                GitAction::Editor => bookmark_opener_service::open_in_editor(&location),
+                GitAction::CopyPath => {
+                    let path = bookmark_opener_service::expand_home(&location).to_string_lossy().to_string();
+                    log_service::add_log_entry("INFO", &format!("Copied {} to the clipboard.", path));
+                    ui.ctx().copy_text(path);
+                    Ok(())
+                }
+            };
+            if let Err(e) = result {
+                log_service::add_log_entry("ERROR", &format!("Error running bookmark action: {}", e));
+                self.status = Some(Err(e));
+            }
+        }
+
+        // Update to redirect target after iteration
+        if let Some(id) = id_to_redirect {
+            match link_check_service::use_redirect_target(id) {
+                Ok(_) => self.loaded = false,
+                Err(e) => {
+                    log_service::add_log_entry("ERROR", &format!("Error updating bookmark: {}", e));
+                    self.status = Some(Err(e));
+                }
+            }
+        }
+
         // Delete bookmark after iteration
         if let Some(id) = id_to_delete {
             match bookmark_service::delete_bookmark(id) {
@@ -141,4 +879,166 @@ impl BookmarksScreen {
             }
         }
     }
+
+    /// Texture for a bookmark's cached favicon
+    fn load_favicon(ctx: &egui::Context, location: &str) -> Option<egui::TextureHandle> {
+        let path = bookmark_metadata_service::favicon_path(location)?;
+        let image = image::open(&path).ok()?.to_rgba8();
+        let size = [image.width() as usize, image.height() as usize];
+        let color_image = egui::ColorImage::from_rgba_unmultiplied(size, image.as_raw());
+        Some(ctx.load_texture(format!("favicon:{}", path.display()), color_image, egui::TextureOptions::LINEAR))
+    }
+
+    /// One folder of a folder bookmark's file tree, subfolders are only read once they are expanded
+    fn render_file_tree(
+        ui: &mut egui::Ui,
+        listings: &mut HashMap<PathBuf, Result<Vec<FileEntry>, String>>,
+        folder: &Path,
+        folder_id: Option<i32>,
+        file_action: &mut Option<FileAction>,
+    ) {
+        let entries = match listings.entry(folder.to_path_buf()).or_insert_with(|| file_browser_service::list_folder(folder)) {
+            Ok(entries) => entries.clone(),
+            Err(e) => {
+                ui.colored_label(Theme::DANGER_COLOR, e.as_str());
+                return;
+            }
+        };
+
+        if entries.is_empty() {
+            ui.label(egui::RichText::new("Empty folder").size(Theme::FONT_SIZE_SMALL).color(Theme::TEXT_MUTED));
+            return;
+        }
+
+        for entry in entries.iter().take(MAX_TREE_ENTRIES) {
+            let response = if entry.is_dir {
+                egui::CollapsingHeader::new(format!("📁 {}", entry.name))
+                    .id_salt(&entry.path)
+                    .show(ui, |ui| Self::render_file_tree(ui, listings, &entry.path, folder_id, file_action))
+                    .header_response
+            } else {
+                let response = ui.horizontal(|ui| {
+                    let response = ui.selectable_label(false, format!("📄 {}", entry.name));
+                    ui.label(egui::RichText::new(file_browser_service::format_size(entry.size_bytes))
+                        .size(Theme::FONT_SIZE_SMALL)
+                        .color(Theme::TEXT_MUTED));
+                    response
+                })
+                .inner;
+                if response.clicked() {
+                    *file_action = Some(FileAction::Preview(entry.path.clone()));
+                }
+                response
+            };
+
+            response.context_menu(|ui| {
+                if !entry.is_dir && ui.button("Preview").clicked() {
+                    *file_action = Some(FileAction::Preview(entry.path.clone()));
+                    ui.close();
+                }
+                if ui.button("Open").clicked() {
+                    *file_action = Some(FileAction::Open(entry.path.clone()));
+                    ui.close();
+                }
+                if ui.button("Add as bookmark").clicked() {
+                    *file_action = Some(FileAction::Bookmark(entry.path.clone(), folder_id));
+                    ui.close();
+                }
+            });
+        }
+
+        if entries.len() > MAX_TREE_ENTRIES {
+            ui.label(egui::RichText::new(format!("... and {} more", entries.len() - MAX_TREE_ENTRIES))
+                .size(Theme::FONT_SIZE_SMALL)
+                .color(Theme::TEXT_MUTED));
+        }
+    }
+
+    /// "⎇ main ↑2 ↓1 ● 3 changed", branch in the primary color, amber when there are local changes
+    fn render_git_status(ui: &mut egui::Ui, status: &GitStatus) {
+        let head = if status.detached {
+            format!("⎇ detached at {}", status.head)
+        } else {
+            format!("⎇ {}", status.head)
+        };
+        ui.label(egui::RichText::new(head).size(Theme::FONT_SIZE_SMALL).strong().color(Theme::PRIMARY_COLOR));
+
+        match status.ahead_behind {
+            Some((0, 0)) => {
+                ui.label(egui::RichText::new("up to date").size(Theme::FONT_SIZE_SMALL).color(Theme::TEXT_MUTED));
+            }
+            Some((ahead, behind)) => {
+                ui.label(egui::RichText::new(format!("↑{} ↓{}", ahead, behind)).size(Theme::FONT_SIZE_SMALL).color(Theme::TEXT_SECONDARY))
+                    .on_hover_text(format!("{} commit(s) to push, {} to pull (as of the last fetch)", ahead, behind));
+            }
+            None => {
+                ui.label(egui::RichText::new("no upstream").size(Theme::FONT_SIZE_SMALL).color(Theme::TEXT_MUTED));
+            }
+        }
+
+        if status.is_dirty() {
+            ui.label(egui::RichText::new(format!("● {} changed", status.changed)).size(Theme::FONT_SIZE_SMALL).color(Theme::WARNING_COLOR))
+                .on_hover_text("Modified, staged or untracked files");
+        } else {
+            ui.label(egui::RichText::new("✔ clean").size(Theme::FONT_SIZE_SMALL).color(Theme::SUCCESS_COLOR));
+        }
+    }
+
+    /// Result of the last link check: green when fine, amber when redirected, red when broken
+    fn render_health_badge(ui: &mut egui::Ui, bookmark: &Bookmark) {
+        let Some(check) = &bookmark.link_check else {
+            ui.label(egui::RichText::new("| Not checked").size(Theme::FONT_SIZE_SMALL).color(Theme::TEXT_MUTED));
+            return;
+        };
+
+        let (icon, color) = if check.is_broken() {
+            ("✖", Theme::DANGER_COLOR)
+        } else if check.redirect_url.is_some() {
+            ("↪", Theme::WARNING_COLOR)
+        } else {
+            ("✔", Theme::SUCCESS_COLOR)
+        };
+        let checked = chrono::DateTime::from_timestamp(check.checked_at, 0)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+
+        ui.label(egui::RichText::new(format!("| {} {}", icon, check.summary())).size(Theme::FONT_SIZE_SMALL).color(color))
+            .on_hover_text(format!("Last checked {}", checked));
+    }
+
+    /// Run the queued folder and tag changes, then reload
+    fn apply_actions(&mut self, actions: Vec<BookmarkAction>) {
+        self.error = None;
+
+        for action in actions {
+            let result = match action {
+                BookmarkAction::MoveBookmark(id, folder_id) => bookmark_service::move_bookmark(id, folder_id),
+                BookmarkAction::MoveFolder(id, parent_id) => bookmark_service::move_folder(id, parent_id).map(|_| {
+                    if let Some(parent_id) = parent_id {
+                        self.expanded.insert(parent_id);
+                    }
+                }),
+                BookmarkAction::CreateFolder(name, parent_id) => bookmark_service::create_folder(&name, parent_id).map(|_| {
+                    if let Some(parent_id) = parent_id {
+                        self.expanded.insert(parent_id);
+                    }
+                }),
+                BookmarkAction::RenameFolder(id, name) => bookmark_service::rename_folder(id, &name),
+                BookmarkAction::DeleteFolder(id) => bookmark_service::delete_folder(id),
+                BookmarkAction::RenameTag(old, new) => bookmark_service::rename_tag(&old, &new).map(|_| {
+                    if self.tag_filter.remove(&old) {
+                        self.tag_filter.insert(crate::services::tag_service::normalize_tag(&new));
+                    }
+                }),
+                BookmarkAction::DeleteTag(tag) => bookmark_service::delete_tag(&tag),
+            };
+
+            if let Err(e) = result {
+                log_service::add_log_entry("ERROR", &format!("Bookmark folder/tag change failed: {}", e));
+                self.error = Some(e);
+            }
+        }
+
+        self.loaded = false;
+    }
 }
@@ -1,16 +1,33 @@
 // src/ui/screens/feeds_screen.rs
 use eframe::egui;
 use crate::ui::screens::screen_trait::Screen;
-use crate::dal::FeedItemsRepository;
 use crate::services::rss_service;
-use crate::models::FeedItem;
+use crate::models::{Feed, FeedItem, FeedItemFilter};
 use crate::ui::modals::ActiveModal;
 use crate::ui::styles::Theme;
 use crate::services::log_service;
 
+/// Feed items per page
+const PAGE_SIZE: usize = 50;
+
+/// Read and starred changes, applied after the frame is drawn
+enum ItemAction {
+    SetRead(i32, bool),
+    SetStarred(i32, bool),
+}
+
 #[derive(Default)]
 pub struct FeedsScreen {
     feed_items: Vec<FeedItem>,
+    feeds: Vec<Feed>,
+    filter: FeedItemFilter,
+    /// (pub_date, id) of the last item before each page after the first, so the current page is
+    /// page_starts.len() and Previous steps back to where the page before began
+    page_starts: Vec<(i64, i32)>,
+    /// How many items match the filter over all pages
+    total_items: usize,
+    has_more: bool,
+    status: Option<Result<String, String>>,
     loaded: bool,
 }
 
@@ -28,6 +45,7 @@ impl FeedsScreen {
 
     pub fn render(&mut self, ui: &mut egui::Ui, modal_opener: &mut dyn FnMut(ActiveModal)) {
         Theme::apply_body_style(ui);
+        let filter = self.filter;
         
         // Header
         ui.add_space(Theme::SPACING_MEDIUM);
@@ -48,7 +66,7 @@ impl FeedsScreen {
                 // Spawn a thread to run the async refresh
                 std::thread::spawn(|| {
                     let runtime = tokio::runtime::Runtime::new().unwrap();
-                    match runtime.block_on(rss_service::refresh_all_feeds()) {
+                    match runtime.block_on(rss_service::refresh_all_feeds(true)) {
                         Ok(msg) => log_service::add_log_entry("INFO", &msg),
                         Err(e) => log_service::add_log_entry("ERROR", &format!("Error refreshing feeds: {}", e)),
                     }
@@ -62,22 +80,38 @@ impl FeedsScreen {
                 modal_opener(ActiveModal::ManageFeeds);
             }
         });
-        
+
+        ui.add_space(Theme::SPACING_SMALL);
+
+        // Filter row
+        ui.horizontal(|ui| {
+            ui.toggle_value(&mut self.filter.unread_only, "Unread only");
+            ui.toggle_value(&mut self.filter.starred_only, "★ Starred");
+
+            let scope = if self.filter.feed_id.is_some() { "Mark feed read" } else { "Mark all read" };
+            if ui.add(Theme::button(scope)).clicked() {
+                self.status = Some(rss_service::mark_all_read(self.filter.feed_id)
+                    .map(|count| format!("Marked {} item(s) read", count)));
+                self.loaded = false;
+            }
+        });
+
+        if let Some(status) = &self.status {
+            ui.add_space(Theme::SPACING_SMALL);
+            match status {
+                Ok(message) => ui.colored_label(Theme::SUCCESS_COLOR, message),
+                Err(error) => ui.colored_label(Theme::DANGER_COLOR, error),
+            };
+        }
+
         ui.add_space(Theme::SPACING_MEDIUM);
         ui.separator();
         ui.add_space(Theme::SPACING_MEDIUM);
-        
-        // Load feed items only when not yet loaded
+
+        // Load feeds and feed items only when not yet loaded
         if !self.loaded {
-            match FeedItemsRepository::get_latest(10000) {
-                Ok(items) => {
-                    self.feed_items = items.into_iter()
-                        .map(|(id, title, link, description, pub_date)| {
-                            FeedItem::new(id, title, link, description, pub_date)
-                        })
-                        .collect();
-                    self.loaded = true;
-                }
+            match self.load() {
+                Ok(_) => self.loaded = true,
                 Err(e) => {
                     log_service::add_log_entry("ERROR", &format!("Error loading feed items: {}", e));
                     ui.colored_label(Theme::DANGER_COLOR, format!("Error loading feed items: {}", e));
@@ -87,7 +121,7 @@ impl FeedsScreen {
         }
 
         // Show empty state
-        if self.feed_items.is_empty() {
+        if self.feeds.is_empty() {
             ui.vertical_centered(|ui| {
                 ui.add_space(Theme::SPACING_XL);
                 ui.label(egui::RichText::new("RSS Feed Items").size(48.0));
@@ -99,31 +133,183 @@ impl FeedsScreen {
             return;
         }
 
+        // Subscriptions on the left, items of the picked one on the right
+        egui::SidePanel::left("feed_list")
+            .resizable(true)
+            .default_width(240.0)
+            .frame(egui::Frame::NONE)
+            .show_inside(ui, |ui| {
+                egui::ScrollArea::vertical().id_salt("feed_list_scroll").show(ui, |ui| {
+                    self.render_feed_list(ui);
+                });
+            });
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame::NONE.inner_margin(egui::Margin { left: 12, ..Default::default() }))
+            .show_inside(ui, |ui| {
+                self.render_items(ui);
+            });
+
+        // A different feed or filter starts over on the first page
+        if self.filter != filter {
+            self.page_starts.clear();
+            self.loaded = false;
+            ui.ctx().request_repaint();
+        }
+    }
+
+    /// Load the feeds with their unread counts and the current page of items
+    fn load(&mut self) -> Result<(), String> {
+        let feeds = rss_service::get_feeds()?;
+        // Forget a feed filter pointing at a feed that is gone
+        if let Some(id) = self.filter.feed_id
+            && !feeds.iter().any(|feed| feed.id == id)
+        {
+            self.filter.feed_id = None;
+        }
+        self.feeds = feeds;
+
+        let mut page = rss_service::get_feed_items_page(self.filter, self.page_starts.last().copied(), PAGE_SIZE)?;
+        // Items can disappear under us (mark all read in the unread view), step back to a page that has some
+        while page.items.is_empty() && self.page_starts.pop().is_some() {
+            page = rss_service::get_feed_items_page(self.filter, self.page_starts.last().copied(), PAGE_SIZE)?;
+        }
+        self.feed_items = page.items;
+        self.total_items = page.total;
+        self.has_more = page.has_more;
+        Ok(())
+    }
+
+    /// "All feeds" and every subscription with its unread count under its category, right-click to mark a feed read
+    fn render_feed_list(&mut self, ui: &mut egui::Ui) {
+        ui.label(egui::RichText::new("Feeds").size(Theme::FONT_SIZE_BODY).strong().color(Theme::TEXT_PRIMARY));
+        ui.add_space(Theme::SPACING_SMALL);
+
+        let total_unread: i64 = self.feeds.iter().map(|feed| feed.unread_count).sum();
+        ui.selectable_value(&mut self.filter.feed_id, None, Self::feed_label("All feeds", total_unread));
+
+        let mut feed_to_mark: Option<i32> = None;
+        // Feeds come sorted by category, uncategorized ones first
+        let mut category = "";
+        for feed in &self.feeds {
+            if feed.category != category {
+                category = &feed.category;
+                ui.add_space(Theme::SPACING_SMALL);
+                ui.label(egui::RichText::new(format!("📁 {}", category))
+                    .size(Theme::FONT_SIZE_SMALL)
+                    .color(Theme::TEXT_MUTED));
+            }
+            let label = egui::RichText::new(Self::feed_label(&feed.title, feed.unread_count));
+            let label = if feed.unread_count > 0 { label.strong() } else { label.color(Theme::TEXT_SECONDARY) };
+            let response = ui.selectable_value(&mut self.filter.feed_id, Some(feed.id), label)
+                .on_hover_text(&feed.url);
+            response.context_menu(|ui| {
+                if ui.add_enabled(feed.unread_count > 0, egui::Button::new("Mark feed read")).clicked() {
+                    feed_to_mark = Some(feed.id);
+                    ui.close();
+                }
+            });
+        }
+
+        if let Some(id) = feed_to_mark {
+            self.status = Some(rss_service::mark_all_read(Some(id))
+                .map(|count| format!("Marked {} item(s) read", count)));
+            self.loaded = false;
+        }
+    }
+
+    fn render_items(&mut self, ui: &mut egui::Ui) {
+        if self.feed_items.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(Theme::SPACING_XL);
+                let message = if self.filter.starred_only { "No starred items" } else if self.filter.unread_only { "All caught up" } else { "No items yet" };
+                ui.label(egui::RichText::new(message).size(Theme::FONT_SIZE_SUBHEADING).color(Theme::TEXT_PRIMARY));
+                ui.add_space(Theme::SPACING_SMALL);
+                ui.label(egui::RichText::new("Pick another feed, clear the filters or refresh the feeds").color(Theme::TEXT_SECONDARY));
+            });
+            return;
+        }
+
+        let mut actions: Vec<ItemAction> = Vec::new();
+
+        // Pager at the bottom, the items scroll above it
+        let page = self.page_starts.len();
+        let pages = self.total_items.div_ceil(PAGE_SIZE).max(page + 1);
+        egui::TopBottomPanel::bottom("feed_items_pager")
+            .frame(egui::Frame::NONE.inner_margin(egui::Margin::symmetric(0, 8)))
+            .show_inside(ui, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(page > 0, egui::Button::new("‹ Previous")).clicked() {
+                        self.page_starts.pop();
+                        self.loaded = false;
+                    }
+                    ui.label(egui::RichText::new(format!("Page {} of {} ({} items)", page + 1, pages, self.total_items))
+                        .color(Theme::TEXT_SECONDARY));
+                    if ui.add_enabled(self.has_more, egui::Button::new("Next ›")).clicked()
+                        && let Some(last) = self.feed_items.last()
+                    {
+                        self.page_starts.push((last.pub_date, last.id));
+                        self.loaded = false;
+                    }
+                });
+            });
+
         // Display feed items
         egui::ScrollArea::vertical()
+            .id_salt(("feed_items_scroll", self.filter.feed_id, page))
             .show(ui, |ui| {
                 for item in &self.feed_items {
                     Theme::card_frame().show(ui, |ui| {
                         ui.vertical(|ui| {
-                            // Title as clickable link
-                            ui.hyperlink_to(
-                                egui::RichText::new(&item.title)
+                            ui.horizontal(|ui| {
+                                // Unread items get a dot and full strength titles
+                                if !item.is_read {
+                                    ui.label(egui::RichText::new("●").color(Theme::PRIMARY_COLOR))
+                                        .on_hover_text("Unread");
+                                }
+
+                                // Title as clickable link, opening it marks the item read
+                                let title = egui::RichText::new(&item.title)
                                     .size(Theme::FONT_SIZE_SUBHEADING)
                                     .strong()
-                                    .color(Theme::PRIMARY_COLOR),
-                                &item.link
-                            );
-                            
+                                    .color(if item.is_read { Theme::TEXT_SECONDARY } else { Theme::PRIMARY_COLOR });
+                                let response = ui.hyperlink_to(title, &item.link);
+                                if !item.is_read && (response.clicked() || response.middle_clicked()) {
+                                    actions.push(ItemAction::SetRead(item.id, true));
+                                }
+                            });
+
                             ui.add_space(Theme::SPACING_SMALL);
-                            
-                            // Format date
-                            let datetime = chrono::DateTime::from_timestamp(item.pub_date, 0);
-                            if let Some(dt) = datetime {
-                                ui.label(egui::RichText::new(format!("Date: {}", dt.format("%B %d, %Y at %H:%M")))
+
+                            ui.horizontal(|ui| {
+                                // Source feed, handy when all feeds are listed
+                                ui.label(egui::RichText::new(&item.feed_title)
                                     .size(Theme::FONT_SIZE_SMALL)
-                                    .color(Theme::TEXT_MUTED));
-                            }
-                            
+                                    .strong()
+                                    .color(Theme::TEXT_SECONDARY));
+
+                                // Format date
+                                let datetime = chrono::DateTime::from_timestamp(item.pub_date, 0);
+                                if let Some(dt) = datetime {
+                                    ui.label(egui::RichText::new(format!("| {}", dt.format("%B %d, %Y at %H:%M")))
+                                        .size(Theme::FONT_SIZE_SMALL)
+                                        .color(Theme::TEXT_MUTED));
+                                }
+
+                                let star = if item.is_starred {
+                                    egui::RichText::new("★ Starred").color(Theme::WARNING_COLOR)
+                                } else {
+                                    egui::RichText::new("☆ Star")
+                                };
+                                if ui.small_button(star).clicked() {
+                                    actions.push(ItemAction::SetStarred(item.id, !item.is_starred));
+                                }
+                                let read_label = if item.is_read { "Mark unread" } else { "Mark read" };
+                                if ui.small_button(read_label).clicked() {
+                                    actions.push(ItemAction::SetRead(item.id, !item.is_read));
+                                }
+                            });
+
                             ui.add_space(Theme::SPACING_SMALL);
                             
                             // Show truncated description
@@ -144,5 +330,47 @@ impl FeedsScreen {
                     });
                 }
             });
+
+        if !actions.is_empty() {
+            self.apply_actions(actions);
+        }
+    }
+
+    /// "Title (3)" with the unread count, just the title when everything is read
+    fn feed_label(title: &str, unread_count: i64) -> String {
+        if unread_count > 0 {
+            format!("{} ({})", title, unread_count)
+        } else {
+            title.to_string()
+        }
+    }
+
+    /// Save read and starred changes and update the list in place, so an item read from the
+    /// unread view stays visible until the next reload
+    fn apply_actions(&mut self, actions: Vec<ItemAction>) {
+        for action in actions {
+            let (id, result) = match action {
+                ItemAction::SetRead(id, is_read) => (id, rss_service::set_read(id, is_read).map(|_| {
+                    if let Some(item) = self.feed_items.iter_mut().find(|item| item.id == id)
+                        && item.is_read != is_read
+                    {
+                        item.is_read = is_read;
+                        if let Some(feed) = self.feeds.iter_mut().find(|feed| feed.id == item.feed_id) {
+                            feed.unread_count += if is_read { -1 } else { 1 };
+                        }
+                    }
+                })),
+                ItemAction::SetStarred(id, is_starred) => (id, rss_service::set_starred(id, is_starred).map(|_| {
+                    if let Some(item) = self.feed_items.iter_mut().find(|item| item.id == id) {
+                        item.is_starred = is_starred;
+                    }
+                })),
+            };
+
+            if let Err(e) = result {
+                log_service::add_log_entry("ERROR", &format!("Error updating feed item {}: {}", id, e));
+                self.status = Some(Err(e));
+            }
+        }
     }
 }
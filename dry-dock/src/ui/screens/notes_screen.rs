@@ -1,19 +1,49 @@
 // src/ui/screens/notes_screen.rs
 use eframe::egui;
+use std::collections::HashSet;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
 use crate::ui::screens::screen_trait::Screen;
 use crate::services::NoteService;
-use crate::models::Note;
+use crate::models::{Note, NoteDraft, NoteSort, NoteFilter};
 use crate::ui::modals::ActiveModal;
 use crate::ui::styles::Theme;
-use crate::services::log_service;
+use crate::services::{draft_service, log_service, note_mirror_service};
+use crate::services::note_mirror_service::SyncReport;
+
+/// How many notes to pull from the database at a time
+const NOTES_PAGE_SIZE: i64 = 100;
+
+/// Fixed height of a note card so the list can be virtualized with show_rows
+const NOTE_ROW_HEIGHT: f32 = 130.0;
+
+/// Max characters shown in the single line preview of a note
+const NOTE_PREVIEW_CHARS: usize = 150;
 
 #[derive(Default)]
 pub struct NotesScreen {
     notes: Vec<Note>,
+    total_notes: i64,
     search_query: String,
+    sort: NoteSort,
+    filter: NoteFilter,
+    selected: HashSet<i32>,
+    mirror_enabled: bool,
+    mirror_conflicts: i64,
+    /// Result of a "Sync Now" running in the background
+    sync_rx: Option<Receiver<Result<SyncReport, String>>>,
+    drafts: Vec<NoteDraft>,
+    show_drafts: bool,
     loaded: bool,
 }
 
+/// Bulk actions that can be applied to the selected notes
+enum BulkAction {
+    Pin(bool),
+    Archive(bool),
+    Delete,
+}
+
 impl Screen for NotesScreen {
     fn title(&self) -> &str {
         "Notes"
@@ -28,6 +58,7 @@ impl NotesScreen {
 
     pub fn render(&mut self, ui: &mut egui::Ui, modal_opener: &mut dyn FnMut(ActiveModal)) {
         Theme::apply_body_style(ui);
+        self.poll_sync(ui.ctx());
         
         // Header
         ui.add_space(Theme::SPACING_MEDIUM);
@@ -44,55 +75,117 @@ impl NotesScreen {
                 self.search_query.clear();
                 self.loaded = false;
             }
+
+            if ui.add(Theme::button("Export")).clicked() {
+                modal_opener(ActiveModal::ExportNotes(Vec::new()));
+            }
+
+            if ui.add(Theme::button("Publish")).clicked() {
+                modal_opener(ActiveModal::PublishSite);
+            }
+
+            // Unsaved drafts from the note modals
+            if !self.drafts.is_empty() {
+                let label = if self.show_drafts { "Hide Drafts".to_string() } else { format!("Drafts ({})", self.drafts.len()) };
+                if ui.add(Theme::button(&label)).clicked() {
+                    self.show_drafts = !self.show_drafts;
+                }
+            }
+
+            // Markdown mirror actions, only when the mirror is turned on in Settings
+            if self.mirror_enabled {
+                if self.sync_rx.is_some() {
+                    ui.spinner();
+                } else if ui.add(Theme::button("Sync Now")).clicked() {
+                    let (tx, rx) = mpsc::channel();
+                    self.sync_rx = Some(rx);
+                    std::thread::spawn(move || {
+                        let _ = tx.send(note_mirror_service::sync_all());
+                    });
+                }
+
+                if self.mirror_conflicts > 0
+                    && ui.add(Theme::danger_button(&format!("Conflicts ({})", self.mirror_conflicts))).clicked()
+                {
+                    modal_opener(ActiveModal::NoteConflicts);
+                }
+            }
+        });
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+
+        // List filters
+        ui.horizontal(|ui| {
+            for filter in NoteFilter::ALL {
+                if ui.selectable_value(&mut self.filter, filter, filter.label()).changed() {
+                    self.selected.clear();
+                    self.loaded = false;
+                }
+            }
         });
         
         ui.add_space(Theme::SPACING_MEDIUM);
 
-        // Search field
+        // Search field and sort options
         ui.horizontal(|ui| {
             ui.label(egui::RichText::new("Search:").color(Theme::TEXT_PRIMARY));
             let response = ui.add(
                 egui::TextEdit::singleline(&mut self.search_query)
                     .hint_text("Search by title or content...")
-                    .desired_width(ui.available_width())
+                    .desired_width(ui.available_width() - 260.0)
             );
 
             // Trigger search when text changes
             if response.changed() {
-                if self.search_query.trim().is_empty() {
-                    // Show all notes when search is empty - trigger reload
-                    self.loaded = false;
-                } else {
-                    // Search notes
-                    match NoteService::search_notes(&self.search_query) {
-                        Ok(notes) => {
-                            self.notes = notes;
-                        }
-                        Err(e) => {
-                            log_service::add_log_entry("ERROR", &format!("Error searching notes: {}", e));
+                self.loaded = false;
+            }
+
+            ui.label(egui::RichText::new("Sort:").color(Theme::TEXT_PRIMARY));
+            egui::ComboBox::from_id_salt("notes_sort")
+                .selected_text(self.sort.label())
+                .show_ui(ui, |ui| {
+                    for sort in NoteSort::ALL {
+                        if ui.selectable_value(&mut self.sort, sort, sort.label()).changed() {
+                            self.loaded = false;
                         }
                     }
-                }
-            }
+                });
         });
         
         ui.add_space(Theme::SPACING_MEDIUM);
         ui.separator();
         ui.add_space(Theme::SPACING_MEDIUM);
 
-        // Load notes only when not yet loaded and no active search
-        if !self.loaded && self.search_query.trim().is_empty() {
-            match NoteService::get_all_notes() {
-                Ok(notes) => {
-                    self.notes = notes;
-                    self.loaded = true;
-                }
+        // Load the first page of notes (and the total count) only when not yet loaded
+        if !self.loaded {
+            self.notes.clear();
+            self.mirror_enabled = note_mirror_service::mirror_root().is_some();
+            self.mirror_conflicts = if self.mirror_enabled {
+                note_mirror_service::count_conflicts().unwrap_or(0)
+            } else {
+                0
+            };
+            self.drafts = draft_service::get_drafts().unwrap_or_else(|e| {
+                log_service::add_log_entry("ERROR", &format!("Error loading drafts: {}", e));
+                Vec::new()
+            });
+            if self.drafts.is_empty() {
+                self.show_drafts = false;
+            }
+            match NoteService::count_notes(&self.search_query, self.filter) {
+                Ok(total) => self.total_notes = total,
                 Err(e) => {
                     log_service::add_log_entry("ERROR", &format!("Error loading notes: {}", e));
                     ui.colored_label(Theme::DANGER_COLOR, format!("Error loading notes: {}", e));
                     return;
                 }
             }
+            self.loaded = true;
+            self.load_next_page();
+        }
+
+        if self.show_drafts {
+            self.render_drafts(ui, modal_opener);
         }
 
         // Show empty state
@@ -106,6 +199,13 @@ impl NotesScreen {
                     ui.label(egui::RichText::new("No results found").size(Theme::FONT_SIZE_SUBHEADING).color(Theme::TEXT_PRIMARY));
                     ui.add_space(Theme::SPACING_SMALL);
                     ui.label(egui::RichText::new(format!("No notes match \"{}\"", self.search_query)).color(Theme::TEXT_SECONDARY));
+                } else if self.filter != NoteFilter::Active {
+                    // Nothing pinned or archived yet
+                    ui.label(egui::RichText::new(format!("No {} notes", self.filter.label().to_lowercase()))
+                        .size(Theme::FONT_SIZE_SUBHEADING)
+                        .color(Theme::TEXT_PRIMARY));
+                    ui.add_space(Theme::SPACING_SMALL);
+                    ui.label(egui::RichText::new("Select notes from the Active list to pin or archive them").color(Theme::TEXT_SECONDARY));
                 } else {
                     // No notes exist at all
                     ui.label(egui::RichText::new("Notes").size(48.0));
@@ -118,83 +218,322 @@ impl NotesScreen {
             return;
         }
 
+        // Count and bulk actions for the selected notes
+        let mut bulk_action: Option<BulkAction> = None;
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(format!("{} note(s)", self.total_notes))
+                .size(Theme::FONT_SIZE_SMALL)
+                .color(Theme::TEXT_MUTED));
+
+            if self.selected.is_empty() {
+                return;
+            }
+
+            ui.separator();
+            ui.label(egui::RichText::new(format!("{} selected", self.selected.len())).color(Theme::TEXT_PRIMARY));
+            if ui.button("📌 Pin").clicked() {
+                bulk_action = Some(BulkAction::Pin(true));
+            }
+            if ui.button("Unpin").clicked() {
+                bulk_action = Some(BulkAction::Pin(false));
+            }
+            if self.filter == NoteFilter::Archived {
+                if ui.button("Restore").clicked() {
+                    bulk_action = Some(BulkAction::Archive(false));
+                }
+            } else if ui.button("Archive").clicked() {
+                bulk_action = Some(BulkAction::Archive(true));
+            }
+            if ui.button("Export").clicked() {
+                let mut ids: Vec<i32> = self.selected.iter().copied().collect();
+                ids.sort();
+                modal_opener(ActiveModal::ExportNotes(ids));
+            }
+            if ui.button(egui::RichText::new("Delete").color(Theme::DANGER_COLOR)).clicked() {
+                bulk_action = Some(BulkAction::Delete);
+            }
+            if ui.button("Clear selection").clicked() {
+                self.selected.clear();
+            }
+        });
+
         // Track note to delete
         let mut id_to_delete: Option<i32> = None;
+        // Highest row the scroll area wants to show, used to lazy load more pages
+        let mut visible_end = 0;
+        
+        // Only the visible rows are laid out, so large databases stay smooth
+        egui::ScrollArea::vertical()
+            .show_rows(ui, NOTE_ROW_HEIGHT, self.total_notes as usize, |ui, row_range| {
+                visible_end = row_range.end;
+                for index in row_range {
+                    let row_size = egui::vec2(ui.available_width(), NOTE_ROW_HEIGHT);
+                    ui.allocate_ui(row_size, |ui| {
+                        ui.set_min_height(NOTE_ROW_HEIGHT);
+                        match self.notes.get(index) {
+                            Some(note) => Self::render_note_row(ui, note, &mut self.selected, &mut id_to_delete, modal_opener),
+                            None => {
+                                // Not loaded yet, the next page is fetched below
+                                ui.centered_and_justified(|ui| {
+                                    ui.spinner();
+                                });
+                            }
+                        }
+                    });
+                }
+            });
+
+        // Lazy load the next page once the user scrolls near the end of what is loaded.
+        // One page per frame, so dragging the scrollbar to the bottom never stalls a frame.
+        let has_more = (self.notes.len() as i64) < self.total_notes;
+        if has_more && visible_end + (NOTES_PAGE_SIZE as usize / 2) > self.notes.len() {
+            self.load_next_page();
+            ui.ctx().request_repaint();
+        }
         
+        // Apply bulk action after iteration
+        if let Some(action) = bulk_action {
+            self.apply_bulk_action(action);
+        }
+
+        // Delete note after iteration
+        if let Some(id) = id_to_delete {
+            match NoteService::delete_note(id) {
+                Ok(_) => {
+                    log_service::add_log_entry("INFO", "Note deleted successfully.");
+                    self.notes.retain(|note| note.id != id);
+                    self.selected.remove(&id);
+                    self.total_notes -= 1;
+                }
+                Err(e) => {
+                    log_service::add_log_entry("ERROR", &format!("Error deleting note: {}", e));
+                }
+            }
+        }
+    }
+
+    /// Drafts left behind by the create/update note modals
+    fn render_drafts(&mut self, ui: &mut egui::Ui, modal_opener: &mut dyn FnMut(ActiveModal)) {
+        let mut id_to_discard: Option<i32> = None;
+
+        ui.label(egui::RichText::new("Drafts").size(Theme::FONT_SIZE_SUBHEADING).strong());
+        ui.add_space(Theme::SPACING_SMALL);
+
         egui::ScrollArea::vertical()
+            .id_salt("notes_drafts_scroll")
+            .max_height(240.0)
             .show(ui, |ui| {
-                for note in &self.notes {
+                for draft in &self.drafts {
                     Theme::card_frame().show(ui, |ui| {
                         ui.horizontal(|ui| {
                             ui.vertical(|ui| {
-                                ui.set_min_width(ui.available_width() - 160.0);
-                                ui.label(egui::RichText::new(&note.title)
-                                    .size(Theme::FONT_SIZE_SUBHEADING)
-                                    .strong()
-                                    .color(Theme::TEXT_PRIMARY));
-                                ui.add_space(Theme::SPACING_SMALL);
-                                
-                                // Truncate note details to 150 characters maybe more or less 
-                                // down the road but this is fine for now....
-                                let preview = if note.details.len() > 150 {
-                                    format!("{}...", &note.details[..150])
-                                } else {
-                                    note.details.clone()
-                                };
-                                
-                                ui.label(egui::RichText::new(&preview)
+                                ui.set_max_width(ui.available_width() - 180.0);
+                                let title = if draft.title.trim().is_empty() { "Untitled" } else { draft.title.as_str() };
+                                ui.add(egui::Label::new(egui::RichText::new(title)
                                     .size(Theme::FONT_SIZE_BODY)
-                                    .color(Theme::TEXT_SECONDARY));
-                                ui.add_space(Theme::SPACING_SMALL);
-                                ui.horizontal(|ui| {
-                                    ui.label(egui::RichText::new(format!("Created: {}", note.created_at))
-                                        .size(Theme::FONT_SIZE_SMALL)
-                                        .color(Theme::TEXT_MUTED));
-                                    if let Some(updated) = &note.updated_at {
-                                        ui.label(egui::RichText::new(format!(" | Updated: {}", updated))
-                                            .size(Theme::FONT_SIZE_SMALL)
-                                            .color(Theme::TEXT_MUTED));
-                                    }
-                                });
+                                    .strong()
+                                    .color(Theme::TEXT_PRIMARY)).truncate());
+
+                                let kind = if draft.note_id.is_some() { "Edit of an existing note" } else { "New note" };
+                                let saved = chrono::DateTime::from_timestamp(draft.updated_at, 0)
+                                    .map(|dt| dt.with_timezone(&chrono::Local).format("%b %d, %Y %H:%M").to_string())
+                                    .unwrap_or_else(|| "Unknown".to_string());
+                                ui.label(egui::RichText::new(format!("{} | Saved: {}", kind, saved))
+                                    .size(Theme::FONT_SIZE_SMALL)
+                                    .color(Theme::TEXT_MUTED));
                             });
-                            
+
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                // Delete note button
-                                if ui.add(Theme::danger_button("Delete")).clicked() {
-                                    id_to_delete = Some(note.id);
+                                if ui.add(Theme::danger_button("Discard")).clicked() {
+                                    id_to_discard = Some(draft.id);
                                 }
-                                
                                 ui.add_space(Theme::SPACING_SMALL);
-                                
-                                // Update note button
-                                if ui.add(Theme::primary_button("Update")).clicked() {
-                                    modal_opener(ActiveModal::UpdateNote(note.id));
-                                }
-
-                                ui.add_space(Theme::SPACING_SMALL);
-
-                                // View note button (renders markdown)
-                                if ui.add(Theme::success_button("View")).clicked() {
-                                    modal_opener(ActiveModal::ViewNote(note.id));
+                                if ui.add(Theme::primary_button("Open")).clicked() {
+                                    modal_opener(ActiveModal::RestoreDraft(draft.id));
                                 }
-
                             });
                         });
                     });
                 }
             });
-        
-        // Delete note after iteration
-        if let Some(id) = id_to_delete {
-            match NoteService::delete_note(id) {
+
+        // Discard draft after iteration
+        if let Some(id) = id_to_discard {
+            match draft_service::discard_draft(id) {
                 Ok(_) => {
-                    log_service::add_log_entry("INFO", "Note deleted successfully.");
-                    self.notes.retain(|note| note.id != id);
+                    self.drafts.retain(|draft| draft.id != id);
+                    if self.drafts.is_empty() {
+                        self.show_drafts = false;
+                    }
                 }
-                Err(e) => {
-                    log_service::add_log_entry("ERROR", &format!("Error deleting note: {}", e));
+                Err(e) => log_service::add_log_entry("ERROR", &format!("Error discarding draft: {}", e)),
+            }
+        }
+
+        ui.add_space(Theme::SPACING_MEDIUM);
+        ui.separator();
+        ui.add_space(Theme::SPACING_MEDIUM);
+    }
+
+    /// Pick up the result of a background "Sync Now" and reload what it changed
+    fn poll_sync(&mut self, ctx: &egui::Context) {
+        let Some(rx) = &self.sync_rx else { return };
+
+        match rx.try_recv() {
+            Ok(result) => {
+                if let Err(e) = result {
+                    log_service::add_log_entry("ERROR", &format!("Notes mirror sync failed: {}", e));
                 }
+                self.sync_rx = None;
+                self.loaded = false;
+            }
+            Err(mpsc::TryRecvError::Empty) => ctx.request_repaint_after(Duration::from_millis(250)),
+            Err(mpsc::TryRecvError::Disconnected) => self.sync_rx = None,
+        }
+    }
+
+    /// Fetch the page after the last loaded note
+    fn load_next_page(&mut self) {
+        match NoteService::get_notes_page(&self.search_query, self.filter, self.sort, self.notes.last(), NOTES_PAGE_SIZE) {
+            Ok(page) if !page.is_empty() => self.notes.extend(page),
+            Ok(_) => {
+                // Notes were removed underneath us, trust what we have
+                self.total_notes = self.notes.len() as i64;
+            }
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Error loading notes: {}", e));
+                // Stop asking every frame, the next reload tries again
+                self.total_notes = self.notes.len() as i64;
+            }
+        }
+    }
+
+    /// Run a bulk action against every selected note and reload the list
+    fn apply_bulk_action(&mut self, action: BulkAction) {
+        let ids: Vec<i32> = self.selected.iter().copied().collect();
+        let (result, done) = match action {
+            BulkAction::Pin(pinned) => (NoteService::pin_notes(&ids, pinned), if pinned { "pinned" } else { "unpinned" }),
+            BulkAction::Archive(archived) => (NoteService::archive_notes(&ids, archived), if archived { "archived" } else { "restored" }),
+            BulkAction::Delete => (NoteService::delete_notes(&ids), "deleted"),
+        };
+
+        match result {
+            Ok(_) => {
+                log_service::add_log_entry("INFO", &format!("{} note(s) {}.", ids.len(), done));
+                self.selected.clear();
+            }
+            Err(e) => {
+                log_service::add_log_entry("ERROR", &format!("Error updating notes: {}", e));
             }
         }
+        self.loaded = false;
+    }
+
+    fn render_note_row(ui: &mut egui::Ui, note: &Note, selected: &mut HashSet<i32>, id_to_delete: &mut Option<i32>, modal_opener: &mut dyn FnMut(ActiveModal)) {
+        Theme::card_frame().show(ui, |ui| {
+            ui.horizontal(|ui| {
+                // Multi-select checkbox for bulk actions
+                let mut is_selected = selected.contains(&note.id);
+                if ui.checkbox(&mut is_selected, "").changed() {
+                    if is_selected {
+                        selected.insert(note.id);
+                    } else {
+                        selected.remove(&note.id);
+                    }
+                }
+
+                ui.vertical(|ui| {
+                    ui.set_min_width(ui.available_width() - 200.0);
+                    ui.set_max_width(ui.available_width() - 200.0);
+                    ui.horizontal(|ui| {
+                        if note.is_pinned {
+                            ui.label(egui::RichText::new("📌").size(Theme::FONT_SIZE_BODY));
+                        }
+                        if note.is_locked {
+                            ui.label(egui::RichText::new("🔒").size(Theme::FONT_SIZE_BODY));
+                        }
+                        ui.add(egui::Label::new(egui::RichText::new(&note.title)
+                            .size(Theme::FONT_SIZE_SUBHEADING)
+                            .strong()
+                            .color(Theme::TEXT_PRIMARY)).truncate());
+                        if note.is_archived {
+                            ui.label(egui::RichText::new("Archived")
+                                .size(Theme::FONT_SIZE_SMALL)
+                                .color(Theme::TEXT_MUTED)
+                                .background_color(Theme::BG_DARKER));
+                        }
+                    });
+                    
+                    // Single line preview so every card has the same height
+                    // Locked notes only hold ciphertext, never preview it
+                    let preview = if note.is_locked {
+                        "Locked note".to_string()
+                    } else {
+                        note_preview(&note.details)
+                    };
+                    ui.add(egui::Label::new(egui::RichText::new(preview)
+                        .size(Theme::FONT_SIZE_BODY)
+                        .color(Theme::TEXT_SECONDARY)).truncate());
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(format!("{} | ", note.notebook))
+                            .size(Theme::FONT_SIZE_SMALL)
+                            .color(Theme::TEXT_SECONDARY));
+                        for tag in &note.tags {
+                            ui.label(egui::RichText::new(format!("#{}", tag))
+                                .size(Theme::FONT_SIZE_SMALL)
+                                .color(Theme::PRIMARY_COLOR));
+                        }
+                        ui.label(egui::RichText::new(format!("Created: {}", note.created_at))
+                            .size(Theme::FONT_SIZE_SMALL)
+                            .color(Theme::TEXT_MUTED));
+                        if let Some(updated) = &note.updated_at {
+                            ui.label(egui::RichText::new(format!(" | Updated: {}", updated))
+                                .size(Theme::FONT_SIZE_SMALL)
+                                .color(Theme::TEXT_MUTED));
+                        }
+                    });
+                });
+                
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    // Delete note button
+                    if ui.add(Theme::danger_button("Delete")).clicked() {
+                        *id_to_delete = Some(note.id);
+                    }
+                    
+                    ui.add_space(Theme::SPACING_SMALL);
+                    
+                    // Update note button
+                    if ui.add(Theme::primary_button("Update")).clicked() {
+                        modal_opener(ActiveModal::UpdateNote(note.id));
+                    }
+
+                    ui.add_space(Theme::SPACING_SMALL);
+
+                    // View note button (renders markdown)
+                    if ui.add(Theme::success_button("View")).clicked() {
+                        modal_opener(ActiveModal::ViewNote(note.id));
+                    }
+
+                    ui.add_space(Theme::SPACING_SMALL);
+
+                    // Lock / unlock note button
+                    let lock_label = if note.is_locked { "🔓" } else { "🔒" };
+                    let lock_hint = if note.is_locked { "Remove lock" } else { "Lock with a passphrase" };
+                    if ui.button(lock_label).on_hover_text(lock_hint).clicked() {
+                        modal_opener(ActiveModal::LockNote(note.id));
+                    }
+                });
+            });
+        });
+    }
+}
+
+/// First line of a note truncated to NOTE_PREVIEW_CHARS (on a char boundary)
+fn note_preview(details: &str) -> String {
+    let first_line = details.lines().find(|line| !line.trim().is_empty()).unwrap_or("");
+    if first_line.chars().count() > NOTE_PREVIEW_CHARS {
+        format!("{}...", first_line.chars().take(NOTE_PREVIEW_CHARS).collect::<String>())
+    } else {
+        first_line.to_string()
     }
 }
@@ -36,6 +36,7 @@ impl Theme {
     // Accent colors - brighter for dark theme
     pub const PRIMARY_COLOR: egui::Color32 = egui::Color32::from_rgb(96, 165, 250); // Bright Blue
     pub const SUCCESS_COLOR: egui::Color32 = egui::Color32::from_rgb(74, 222, 128); // Bright Green
+    pub const WARNING_COLOR: egui::Color32 = egui::Color32::from_rgb(251, 191, 36); // Amber
     pub const DANGER_COLOR: egui::Color32 = egui::Color32::from_rgb(248, 113, 113); // Bright Red
     
     /// Apply menu bar styling